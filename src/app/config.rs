@@ -1,4 +1,4 @@
-use crate::kafka::{Format, SeekTo};
+use crate::kafka::{AssignmentMode, CommitStrategy, ConsumeUntil, Format, SeekTo};
 
 use anyhow::Context;
 use chrono::Utc;
@@ -27,6 +27,38 @@ const DEFAULT_LOGS_MAX_HISTORY: u16 = 2048;
 /// Name of the configuration file stored in the user's home directory.
 const PERSISTED_CONFIG_FILE_NAME: &str = ".kaftui.json";
 
+/// Configuration properties that only apply to a Kafka consumer. These are removed from the
+/// consumer properties configured by the user before they are applied to the Kafka producer.
+const CONSUMER_ONLY_PROPERTIES: [&str; 27] = [
+    "allow.auto.create.topics",
+    "auto.commit.enable",
+    "auto.commit.interval.ms",
+    "auto.offset.reset",
+    "check.crcs",
+    "consume.callback.max.messages",
+    "coordinator.query.interval.ms",
+    "enable.auto.commit",
+    "enable.auto.offset.store",
+    "enable.partition.eof",
+    "fetch.error.backoff.ms",
+    "fetch.max.bytes",
+    "fetch.message.max.bytes",
+    "fetch.min.bytes",
+    "fetch.queue.backoff.ms",
+    "fetch.wait.max.ms",
+    "group.id",
+    "group.instance.id",
+    "group.protocol",
+    "group.remote.assignor",
+    "heartbeat.interval.ms",
+    "isolation.level",
+    "max.poll.interval.ms",
+    "partition.assignment.strategy",
+    "queued.max.messages.kbytes",
+    "queued.min.messages",
+    "session.timeout.ms",
+];
+
 impl From<SeekTo> for ValueKind {
     /// Converts from an owned [`SeekTo`] to a [`ValueKind`].
     fn from(value: SeekTo) -> Self {
@@ -34,6 +66,27 @@ impl From<SeekTo> for ValueKind {
     }
 }
 
+impl From<AssignmentMode> for ValueKind {
+    /// Converts from an owned [`AssignmentMode`] to a [`ValueKind`].
+    fn from(value: AssignmentMode) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<CommitStrategy> for ValueKind {
+    /// Converts from an owned [`CommitStrategy`] to a [`ValueKind`].
+    fn from(value: CommitStrategy) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<ConsumeUntil> for ValueKind {
+    /// Converts from an owned [`ConsumeUntil`] to a [`ValueKind`].
+    fn from(value: ConsumeUntil) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
 impl From<Format> for ValueKind {
     /// Converts from an owned [`Format`] to a [`ValueKind`].
     fn from(value: Format) -> Self {
@@ -46,7 +99,8 @@ impl From<Format> for ValueKind {
 pub struct Config {
     /// Kafka bootstrap servers host value that the application will connect to.
     pub bootstrap_servers: String,
-    /// Name of the Kafka topic to consume messages from, if configured.
+    /// CSV of Kafka topic names or `^` prefixed regular expressions to consume messages from, if
+    /// configured.
     pub topic: Option<String>,
     /// CSV of partitions numbers that the consumer should be assigned. If none, all of the
     /// partitions which make up the topic will be assigned.
@@ -75,14 +129,25 @@ pub struct Config {
     /// Id of the consumer group that the application will use when consuming messages from the
     /// Kafka topic.
     pub group_id: String,
+    /// Variant of the [`AssignmentMode`] enum that drives whether partitions are assigned to the
+    /// Kafka consumer by the application or by the consumer group coordinator. Defaults to
+    /// [`AssignmentMode::Manual`].
+    pub assignment_mode: AssignmentMode,
     /// Variant of the [`SeekTo`] enum that drives the partitions offsets the Kafka consumer seeks
     /// to before starting to consume records. Defaults to [`SeekTo::None`].
     pub seek_to: SeekTo,
+    /// Variant of the [`CommitStrategy`] enum that drives if and how the Kafka consumer commits
+    /// the offsets of consumed records. Defaults to [`CommitStrategy::None`].
+    pub commit_strategy: CommitStrategy,
+    /// Variant of the [`ConsumeUntil`] enum that drives the point on the partitions where the
+    /// Kafka consumer stops consuming records. Defaults to [`ConsumeUntil::None`].
+    pub until: ConsumeUntil,
     /// Additional configuration properties that will be applied to the Kafka consumer.
     pub consumer_properties: Option<HashMap<String, String>>,
-    /// JSONPath filter that is applied to a [`Record`]. Can be used to filter out any messages
-    /// from the Kafka topic that the end user may not be interested in. A message will only be
-    /// presented to the user if it matches the filter.
+    /// Filter that is applied to a [`Record`], either a JSONPath query or a filter expression
+    /// selected by its prefix. Can be used to filter out any messages from the Kafka topic that the
+    /// end user may not be interested in. A message will only be presented to the user if it
+    /// matches the filter.
     pub filter: Option<String>,
     /// Maximum number of [`Records`] that should be held in memory at any given time after being
     /// consumed from the Kafka topic.
@@ -141,6 +206,25 @@ impl Config {
 
         config.try_deserialize().context("deserialize Config")
     }
+    /// Builds the configuration properties for the Kafka producer. The consumer properties
+    /// configured by the user are shared with the producer, e.g. to configure authentication, with
+    /// the exception of any properties that only apply to a Kafka consumer.
+    pub fn producer_properties(&self) -> HashMap<String, String> {
+        let mut props: HashMap<String, String> = self
+            .consumer_properties
+            .iter()
+            .flatten()
+            .filter(|(k, _)| !CONSUMER_ONLY_PROPERTIES.contains(&k.as_str()))
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+
+        props.insert(
+            String::from("bootstrap.servers"),
+            self.bootstrap_servers.clone(),
+        );
+
+        props
+    }
 }
 
 /// Empty struct that simply implements the [`Source`] trait to integrate the global application
@@ -187,8 +271,20 @@ impl Source for Defaults {
             Value::from(DEFAULT_LOGS_MAX_HISTORY),
         );
 
+        cfg.insert(
+            String::from("assignment_mode"),
+            Value::from(AssignmentMode::default()),
+        );
+
         cfg.insert(String::from("seek_to"), Value::from(SeekTo::default()));
 
+        cfg.insert(
+            String::from("commit_strategy"),
+            Value::from(CommitStrategy::default()),
+        );
+
+        cfg.insert(String::from("until"), Value::from(ConsumeUntil::default()));
+
         Ok(cfg)
     }
 }
@@ -226,6 +322,10 @@ pub struct PersistedConfig {
     pub scroll_factor: Option<u16>,
     /// Directory on the file system where exported files will be saved.
     pub export_directory: Option<String>,
+    /// Drives the partitions offsets the Kafka consumer seeks to before starting to consume
+    /// records, for example `reset`, `0:42,1:10`, `-15m`, `2025-06-01T14:05:00Z` or
+    /// `tail:50`.
+    pub seek_to: Option<String>,
     /// Color configuration for the UI components of the application.
     pub theme: Option<Theme>,
 }
@@ -280,6 +380,11 @@ impl Source for PersistedConfig {
             );
         }
 
+        if let Some(seek_to) = self.seek_to.as_ref() {
+            let seek_to: SeekTo = seek_to.into();
+            cfg.insert(String::from("seek_to"), Value::from(seek_to));
+        }
+
         if let Some(theme) = self.theme.as_ref() {
             cfg.insert(String::from("theme"), Value::from(theme.clone()));
         }
@@ -297,7 +402,7 @@ pub struct Profile {
     pub name: String,
     /// Kafka bootstrap servers host value that the application will connect to.
     pub bootstrap_servers: Option<String>,
-    /// Name of the Kafka topic to consume messages from.
+    /// CSV of Kafka topic names or `^` prefixed regular expressions to consume messages from.
     pub topic: Option<String>,
     /// CSV of partitions numbers that the consumer should be assigned.
     pub partitions: Option<String>,
@@ -323,10 +428,24 @@ pub struct Profile {
     /// Id of the consumer group that the application will use when consuming messages from the
     /// Kafka topic.
     pub group_id: Option<String>,
-    /// JSONPath filter that is applied to a [`Record`]. Can be used to filter out any messages
-    /// from the Kafka topic that the end user may not be interested in. A message will only be
-    /// presented to the user if it matches the filter.
+    /// Drives whether partitions are assigned to the Kafka consumer by the application or by the
+    /// consumer group coordinator, for example `manual` or `subscribe`.
+    pub assignment_mode: Option<String>,
+    /// Filter that is applied to a [`Record`], either a JSONPath query or a filter expression
+    /// selected by its prefix. Can be used to filter out any messages from the Kafka topic that the
+    /// end user may not be interested in. A message will only be presented to the user if it
+    /// matches the filter.
     pub filter: Option<String>,
+    /// Drives the partitions offsets the Kafka consumer seeks to before starting to consume
+    /// records, for example `reset`, `0:42,1:10`, `-15m`, `2025-06-01T14:05:00Z` or
+    /// `tail:50`.
+    pub seek_to: Option<String>,
+    /// Drives if and how the Kafka consumer commits the offsets of consumed records, for example
+    /// `none`, `async` or `batch:100`.
+    pub commit_strategy: Option<String>,
+    /// Drives the point on the partitions where the Kafka consumer stops consuming records, for
+    /// example `end`, `0:100,1:200`, `-5m` or `2025-06-01T15:00:00Z`.
+    pub until: Option<String>,
     /// Additional configuration properties that should be applied to the Kafka consumer.
     pub consumer_properties: Option<HashMap<String, String>>,
 }
@@ -421,6 +540,32 @@ impl Source for Profile {
             cfg.insert(String::from("filter"), Value::from(filter.clone()));
         }
 
+        if let Some(assignment_mode) = self.assignment_mode.as_ref() {
+            let assignment_mode: AssignmentMode = assignment_mode.into();
+            cfg.insert(
+                String::from("assignment_mode"),
+                Value::from(assignment_mode),
+            );
+        }
+
+        if let Some(seek_to) = self.seek_to.as_ref() {
+            let seek_to: SeekTo = seek_to.into();
+            cfg.insert(String::from("seek_to"), Value::from(seek_to));
+        }
+
+        if let Some(commit_strategy) = self.commit_strategy.as_ref() {
+            let commit_strategy: CommitStrategy = commit_strategy.into();
+            cfg.insert(
+                String::from("commit_strategy"),
+                Value::from(commit_strategy),
+            );
+        }
+
+        if let Some(until) = self.until.as_ref() {
+            let until: ConsumeUntil = until.into();
+            cfg.insert(String::from("until"), Value::from(until));
+        }
+
         if let Some(consumer_properties) = self.consumer_properties.as_ref() {
             cfg.insert(
                 String::from("consumer_properties"),
@@ -460,6 +605,8 @@ pub struct Theme {
     pub record_value_text_color: String,
     /// Color used for the text in the record headers. Defaults to white.
     pub record_headers_text_color: String,
+    /// Color used for the text of records that failed to deserialize. Defaults to red.
+    pub record_decode_error_text_color: String,
     /// Color used for the text in the menu items. Defaults to white.
     pub menu_item_text_color: String,
     /// Color used for the text in the currently selected menu item. Defaults to yellow.
@@ -520,6 +667,7 @@ impl Default for Theme {
             record_info_text_color: String::from("#FFFFFF"),
             record_value_text_color: String::from("#FFFFFF"),
             record_headers_text_color: String::from("#FFFFFF"),
+            record_decode_error_text_color: String::from("#FF0000"),
             menu_item_text_color: String::from("#FFFFFF"),
             selected_menu_item_text_color: String::from("#FFFF00"),
             notification_text_color_success: String::from("#FFFFFF"),
@@ -555,6 +703,10 @@ impl Theme {
             ("recordInfoTextColor", &self.record_info_text_color),
             ("recordValueTextColor", &self.record_value_text_color),
             ("recordHeadersTextColor", &self.record_headers_text_color),
+            (
+                "recordDecodeErrorTextColor",
+                &self.record_decode_error_text_color,
+            ),
             ("menuItemTextColor", &self.menu_item_text_color),
             (
                 "selectedMenuItemTextColor",
@@ -641,6 +793,11 @@ impl From<Theme> for ValueKind {
             Value::from(value.record_headers_text_color),
         );
 
+        data.insert(
+            String::from("recordDecodeErrorTextColor"),
+            Value::from(value.record_decode_error_text_color),
+        );
+
         data.insert(
             String::from("recordValueTextColor"),
             Value::from(value.record_value_text_color),
@@ -1,18 +1,57 @@
 use crate::kafka::{
-    Format, Record,
+    DecodeError, Format, Record, RecordHeader, TimestampType,
     admin::{Partition, Topic, TopicConfig, TopicConfigEntry},
     schema::{Schema, SchemaRef, Version},
 };
 
 use anyhow::Context;
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
 use chrono::{DateTime, Local};
 use serde::Serialize;
-use std::collections::HashMap;
 
 /// Default prefix used for the name of the exported file when no partition key is set or it is in
 /// a format that should not be used in the file name.
 const DEFAULT_EXPORT_FILE_PREFIX: &str = "record";
 
+/// Encoding of an [`ExportedHeader`] value that is not valid UTF-8.
+pub const HEADER_ENCODING_BASE64: &str = "base64";
+
+/// View of a [`RecordHeader`] that is saved as part of an [`ExportedRecord`]. Values that are not
+/// valid UTF-8 are written in base64 along with the encoding so that they can be restored exactly.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExportedHeader {
+    /// Key of the header.
+    key: String,
+    /// Value of the header, if one was set.
+    value: Option<String>,
+    /// Encoding of the value when it is not a plain UTF-8 string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    encoding: Option<&'static str>,
+}
+
+impl From<RecordHeader> for ExportedHeader {
+    /// Converts an owned [`RecordHeader`] to an owned [`ExportedHeader`].
+    fn from(header: RecordHeader) -> Self {
+        let (value, encoding) = match header.value {
+            None => (None, None),
+            Some(bytes) => match String::from_utf8(bytes) {
+                Ok(s) => (Some(s), None),
+                Err(e) => (
+                    Some(BASE64.encode(e.as_bytes())),
+                    Some(HEADER_ENCODING_BASE64),
+                ),
+            },
+        };
+
+        Self {
+            key: header.key,
+            value,
+            encoding,
+        }
+    }
+}
+
 /// View of a [`Record`] that is saved to a file in JSON format when the user requests that the
 /// selected record be exported. This allows for better handling of the value field which would
 /// just be rendered as a JSON encoded string otherwise.
@@ -27,12 +66,32 @@ struct ExportedRecord {
     offset: i64,
     /// Partition key for the record if one was set.
     key: Option<String>,
-    /// Contains any headers from the Kafka record.
-    headers: HashMap<String, String>,
+    /// Contains any headers from the Kafka record in the order they were set.
+    headers: Vec<ExportedHeader>,
     /// Value of the Kafka record, if one exists.
     value: Option<serde_json::Value>,
+    /// Error encountered when deserializing the partition key, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    key_error: Option<DecodeError>,
+    /// Error encountered when deserializing the value, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value_error: Option<DecodeError>,
+    /// Size in bytes of the partition key as read from the topic, if one was set.
+    key_size: Option<usize>,
+    /// Size in bytes of the value as read from the topic, if one exists.
+    value_size: Option<usize>,
+    /// Id of the schema the partition key was produced with, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    key_schema_id: Option<u32>,
+    /// Id of the schema the value was produced with, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value_schema_id: Option<u32>,
+    /// Leader epoch of the partition when the record was appended to the log, if known.
+    leader_epoch: Option<i32>,
     /// Local timestamp represeting when the event was created.
     timestamp: DateTime<Local>,
+    /// Type of the timestamp set on the record.
+    timestamp_type: TimestampType,
 }
 
 impl ExportedRecord {
@@ -54,9 +113,17 @@ impl ExportedRecord {
             partition: record.partition,
             offset: record.offset,
             key: record.key,
-            headers: record.headers,
+            headers: record.headers.into_iter().map(Into::into).collect(),
             value: json_value,
+            key_error: record.key_error,
+            value_error: record.value_error,
+            key_size: record.key_size,
+            value_size: record.value_size,
+            key_schema_id: record.key_schema_id,
+            value_schema_id: record.value_schema_id,
+            leader_epoch: record.leader_epoch,
             timestamp: record.timestamp,
+            timestamp_type: record.timestamp_type,
         }
     }
 }
@@ -0,0 +1,284 @@
+use crate::{
+    app::export::HEADER_ENCODING_BASE64,
+    kafka::{
+        Format, RecordHeader,
+        producer::{ProduceRequest, ProducedRecord, Producer},
+        ser::RecordSerializer,
+    },
+};
+
+use anyhow::Context;
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+use chrono::{DateTime, FixedOffset};
+use serde::Deserialize;
+use std::{num::NonZeroU32, sync::Arc, time::Duration};
+use tokio::{
+    fs::File,
+    io::{AsyncBufReadExt, BufReader},
+    time::{Instant, MissedTickBehavior},
+};
+
+/// View of a [`RecordHeader`] that is read from a line of a JSON Lines file. Matches the shape of
+/// the headers written when a record is exported, including base64 encoded values.
+#[derive(Clone, Debug, Deserialize)]
+struct ImportedHeader {
+    /// Key of the header.
+    key: String,
+    /// Value of the header, if one is set.
+    value: Option<String>,
+    /// Encoding of the value when it is not a plain UTF-8 string.
+    encoding: Option<String>,
+}
+
+impl TryFrom<ImportedHeader> for RecordHeader {
+    type Error = anyhow::Error;
+
+    /// Converts an owned [`ImportedHeader`] to an owned [`RecordHeader`], decoding the value if it
+    /// is encoded.
+    fn try_from(header: ImportedHeader) -> Result<Self, Self::Error> {
+        let value = match (header.value, header.encoding.as_deref()) {
+            (None, _) => None,
+            (Some(value), None) => Some(value.into_bytes()),
+            (Some(value), Some(HEADER_ENCODING_BASE64)) => Some(
+                BASE64
+                    .decode(value)
+                    .with_context(|| format!("decode base64 value of header {}", header.key))?,
+            ),
+            (Some(_), Some(encoding)) => {
+                anyhow::bail!(
+                    "unsupported encoding {} for header {}",
+                    encoding,
+                    header.key
+                )
+            }
+        };
+
+        Ok(Self {
+            key: header.key,
+            value,
+        })
+    }
+}
+
+/// View of a record that is read from a line of a JSON Lines file so that it can be produced to a
+/// Kafka topic. Matches the shape of an exported record, and any fields only known once a record
+/// has been consumed, e.g. the offset, are ignored.
+#[derive(Clone, Debug, Deserialize)]
+struct ImportedRecord {
+    /// Name of the topic that the record should be produced to, if any.
+    topic: Option<String>,
+    /// Partition that the record should be produced to, if any.
+    partition: Option<i32>,
+    /// Partition key for the record, if any.
+    key: Option<serde_json::Value>,
+    /// Headers for the record in the order they should be set.
+    #[serde(default)]
+    headers: Vec<ImportedHeader>,
+    /// Value of the record. A record without a value is a tombstone.
+    value: Option<serde_json::Value>,
+    /// Timestamp of the record, if any.
+    timestamp: Option<DateTime<FixedOffset>>,
+}
+
+impl ImportedRecord {
+    /// Converts the [`ImportedRecord`] into a [`ProduceRequest`]. The specified topic takes
+    /// precedence over the one read from the file. String values are used as is when the value
+    /// has no format, otherwise the value is written as JSON text for the serializer to handle.
+    fn into_request(
+        self,
+        topic: Option<&str>,
+        value_format: Format,
+    ) -> anyhow::Result<ProduceRequest> {
+        let topic = match (topic, self.topic) {
+            (Some(topic), _) => String::from(topic),
+            (None, Some(topic)) => topic,
+            (None, None) => anyhow::bail!("no topic specified for record"),
+        };
+
+        let key = self.key.map(|key| match key {
+            serde_json::Value::String(s) => s,
+            json => json.to_string(),
+        });
+
+        let headers = self
+            .headers
+            .into_iter()
+            .map(RecordHeader::try_from)
+            .collect::<anyhow::Result<Vec<RecordHeader>>>()?;
+
+        let value = self.value.map(|value| match (value_format, value) {
+            (Format::None, serde_json::Value::String(s)) => s,
+            (_, json) => json.to_string(),
+        });
+
+        Ok(ProduceRequest {
+            topic,
+            partition: self.partition,
+            key,
+            headers,
+            value,
+            key_schema_version: None,
+            value_schema_version: None,
+            timestamp: self.timestamp.map(|t| t.timestamp_millis()),
+        })
+    }
+}
+
+/// Contains the details of a JSON Lines file whose records should be produced to a Kafka topic.
+#[derive(Clone, Debug)]
+pub struct ImportRequest {
+    /// Path to the JSON Lines file on the file system.
+    pub path: String,
+    /// Topic that the records are produced to. When not specified the topic is read from each
+    /// line of the file.
+    pub topic: Option<String>,
+    /// Maximum number of records produced per second. When not specified the records are produced
+    /// as fast as the broker acknowledges them.
+    pub rate: Option<NonZeroU32>,
+}
+
+/// Running totals for the records produced from a JSON Lines file.
+#[derive(Clone, Debug, Default)]
+pub struct ImportSummary {
+    /// Number of records that were produced successfully.
+    pub produced: usize,
+    /// Number of lines that could not be produced.
+    pub failed: usize,
+    /// Amount of time elapsed since the first line was read.
+    pub elapsed: Duration,
+    /// Message describing why the most recent failed line could not be produced, if any.
+    pub last_error: Option<String>,
+}
+
+/// Progress reported after each non-blank line of a JSON Lines file has been handled.
+pub struct ImportProgress<'a> {
+    /// Result of producing the record read from the line. Errors include the line number.
+    pub result: &'a anyhow::Result<ProducedRecord>,
+    /// Running totals including the line.
+    pub summary: &'a ImportSummary,
+}
+
+/// The [`Importer`] is responsible for producing the records contained in a JSON Lines file, one
+/// record per line, to a Kafka topic. Each record is serialized in the configured formats before
+/// it is produced.
+pub struct Importer {
+    /// [`Producer`] used to produce the records.
+    producer: Arc<Producer>,
+    /// [`RecordSerializer`] used to serialize the key and value of the records.
+    record_serializer: RecordSerializer,
+    /// Format that the values of the records are serialized in.
+    value_format: Format,
+}
+
+impl Importer {
+    /// Creates a new [`Importer`] with the specified dependencies.
+    pub fn new(
+        producer: Arc<Producer>,
+        record_serializer: RecordSerializer,
+        value_format: Format,
+    ) -> Self {
+        Self {
+            producer,
+            record_serializer,
+            value_format,
+        }
+    }
+    /// Produces the records in the file specified by the [`ImportRequest`] one at a time so that
+    /// their order is preserved. Lines that fail are counted and skipped, and the given callback
+    /// is invoked with the [`ImportProgress`] after every line. Returns an error only if the file
+    /// cannot be read.
+    pub async fn import<F>(
+        &self,
+        request: &ImportRequest,
+        mut on_progress: F,
+    ) -> anyhow::Result<ImportSummary>
+    where
+        F: FnMut(ImportProgress<'_>),
+    {
+        let file = File::open(&request.path)
+            .await
+            .with_context(|| format!("open file {}", request.path))?;
+
+        let mut lines = BufReader::new(file).lines();
+
+        let mut interval = request.rate.map(|rate| {
+            let period = (Duration::from_secs(1) / rate.get()).max(Duration::from_nanos(1));
+
+            let mut interval = tokio::time::interval(period);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            interval
+        });
+
+        let started = Instant::now();
+
+        let mut summary = ImportSummary::default();
+        let mut line_number = 0;
+
+        while let Some(line) = lines
+            .next_line()
+            .await
+            .with_context(|| format!("read line from file {}", request.path))?
+        {
+            line_number += 1;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            if let Some(interval) = interval.as_mut() {
+                interval.tick().await;
+            }
+
+            let result = self
+                .produce_line(&line, request.topic.as_deref())
+                .await
+                .with_context(|| format!("line {}", line_number));
+
+            match result.as_ref() {
+                Ok(_) => summary.produced += 1,
+                Err(e) => {
+                    tracing::warn!("failed to produce record from {}: {:#}", request.path, e);
+
+                    summary.failed += 1;
+                    summary.last_error = Some(format!("{:#}", e));
+                }
+            }
+
+            summary.elapsed = started.elapsed();
+
+            on_progress(ImportProgress {
+                result: &result,
+                summary: &summary,
+            });
+        }
+
+        summary.elapsed = started.elapsed();
+
+        tracing::info!(
+            "produced {} records from {} with {} failures",
+            summary.produced,
+            request.path,
+            summary.failed
+        );
+
+        Ok(summary)
+    }
+    /// Parses, serializes and produces the record contained in a single line of the file.
+    async fn produce_line(
+        &self,
+        line: &str,
+        topic: Option<&str>,
+    ) -> anyhow::Result<ProducedRecord> {
+        let imported: ImportedRecord = serde_json::from_str(line).context("parse record JSON")?;
+
+        let request = imported.into_request(topic, self.value_format)?;
+
+        let record = self
+            .record_serializer
+            .serialize(request)
+            .await
+            .context("serialize record")?;
+
+        self.producer.send(record).await
+    }
+}
@@ -1,23 +1,34 @@
 pub mod config;
 pub mod export;
+pub mod import;
 
 use crate::{
     app::{
         config::{Config, PersistedConfig},
         export::Exporter,
+        import::{ImportRequest, Importer},
     },
     event::{Event, EventBus},
     kafka::{
-        ConsumeTopicConfig, Consumer, ConsumerConfig, ConsumerEvent, ConsumerMode, Record,
-        admin::{AdminClient, AdminClientConfig, Topic, TopicConfig},
+        AssignmentMode, ConsumeTopicConfig, ConsumeUntil, Consumer, ConsumerConfig, ConsumerEvent,
+        ConsumerMode, Format, Record, SeekPosition, SeekTo, TopicPartition,
+        admin::{
+            AdminClient, AdminClientConfig, ConsumerGroup, CreateTopicRequest, Topic, TopicConfig,
+            TopicConfigChange,
+        },
         de::{KeyDeserializer, ValueDeserializer},
+        filter::RecordFilter,
+        producer::{ProduceRecord, ProduceRequest, Producer, ProducerConfig, ReplayRecord},
         schema::{DEFAULT_CACHE_TTL, Schema, SchemaClient, Subject, Version},
+        ser::{KeySerializer, RecordSerializer, ValueSerializer},
     },
     trace::Log,
     ui::{
-        Component, Logs, LogsConfig, MappedKeyEvent, Records, RecordsConfig, Schemas,
-        SchemasConfig, Settings, SettingsConfig, Stats, StatsConfig, Topics, TopicsConfig,
+        Component, Groups, GroupsConfig, Logs, LogsConfig, MappedKeyEvent, Produce, ProduceConfig,
+        Records, RecordsConfig, Schemas, SchemasConfig, Settings, SettingsConfig, Stats,
+        StatsConfig, Topics, TopicsConfig,
     },
+    util,
 };
 
 use anyhow::Context;
@@ -25,15 +36,19 @@ use chrono::{DateTime, Duration, Local};
 use crossterm::event::{KeyCode, KeyEvent};
 use futures::{FutureExt, StreamExt};
 use ratatui::{DefaultTerminal, crossterm::event::Event as TerminalEvent};
+use regex::Regex;
 use schema_registry_client::rest::schema_registry_client::Client;
 use std::{
     cell::{Cell, RefCell},
-    collections::HashMap,
+    collections::{BTreeSet, HashMap},
     rc::Rc,
     sync::Arc,
 };
 use tokio::sync::mpsc::{Receiver, Sender, UnboundedReceiver};
 
+/// Prefix that distinguishes a regular expression matching topic names from a topic name.
+const TOPIC_REGEX_PREFIX: char = '^';
+
 /// Size of the buffer that polled application events are placed into.
 const APP_EVENTS_BUFFER_SIZE: usize = 16;
 
@@ -49,6 +64,9 @@ const TERMINAL_EVENT_CHANNEL_SIZE: usize = 64;
 /// Size of the buffer that polled log events are placed into.
 const LOG_EVENT_BUFFER_SIZE: usize = 16;
 
+/// Number of lines handled between each progress event published while importing records.
+const IMPORT_PROGRESS_INTERVAL: usize = 100;
+
 /// Number of notification seconds after a [`Notification`] is created that it should not be
 /// eligible to visible to the user any longer.
 const NOTIFICATION_EXPIRATION_SECS: i64 = 3;
@@ -197,6 +215,13 @@ where
     pub config: Rc<Config>,
     /// Contains the current state of the application.
     pub state: State,
+    /// Topic that the Kafka consumer consumes records from. Initially the topic configured by the
+    /// user, but can be changed by selecting a topic in the [`Topics`] component.
+    topic: Option<String>,
+    /// Filter applied to the records consumed from the topic, parsed from the configured filter
+    /// expression when the application is created. Can be replaced by the user from the
+    /// [`Records`] component.
+    filter: Option<RecordFilter>,
     /// All [`Component`]s available to the user.
     pub components: Vec<Rc<RefCell<dyn Component>>>,
     /// Buffers the valid `char`s that correspond to menu items.
@@ -211,6 +236,10 @@ where
     event_bus: Arc<EventBus>,
     /// Consumer used to read records from a Kafka topic.
     consumer: Arc<Consumer>,
+    /// Producer used to write records to a Kafka topic.
+    producer: Arc<Producer>,
+    /// Serializes the key and value of records entered by the user before they are produced.
+    record_serializer: RecordSerializer,
     /// Responsible for exporting Kafka records to the file system.
     exporter: Exporter,
     /// Client used to interact with the schema registry, if configured.
@@ -229,6 +258,8 @@ where
         config: Config,
         key_deserializer: Arc<dyn KeyDeserializer>,
         value_deserializer: Arc<dyn ValueDeserializer>,
+        key_serializer: Arc<dyn KeySerializer>,
+        value_serializer: Arc<dyn ValueSerializer>,
         schema_registry_client: Option<Arc<C>>,
     ) -> anyhow::Result<Self> {
         config
@@ -236,6 +267,13 @@ where
             .validate()
             .context("invalid theme color in configuration")?;
 
+        let filter = config
+            .filter
+            .as_deref()
+            .map(RecordFilter::parse)
+            .transpose()
+            .context("invalid filter in configuration")?;
+
         let (event_tx, event_rx) = tokio::sync::mpsc::unbounded_channel();
 
         let event_bus = Arc::new(EventBus::new(event_tx));
@@ -270,6 +308,16 @@ where
 
         let consumer_mode = Rc::new(Cell::new(ConsumerMode::Stopped));
 
+        let producer_config = ProducerConfig::builder()
+            .props(config.producer_properties())
+            .build()
+            .expect("valid ProducerConfig");
+
+        let producer = Producer::new(producer_config).context("create Kafka producer")?;
+        let producer = Arc::new(producer);
+
+        let record_serializer = RecordSerializer::new(key_serializer, value_serializer);
+
         let admin_client_config = AdminClientConfig::builder()
             .properties(consumer_props)
             .build()
@@ -290,31 +338,11 @@ where
         let mut components: Vec<Rc<RefCell<dyn Component>>> = vec![topics_component];
 
         if let Some(topic) = config.topic.clone() {
-            let records_component = Rc::new(RefCell::new(Records::from(
-                RecordsConfig::builder()
-                    .consumer_mode(Rc::clone(&consumer_mode))
-                    .topic(topic.clone())
-                    .filter(config.filter.clone())
-                    .theme(&config.theme)
-                    .scroll_factor(config.scroll_factor)
-                    .max_records(config.max_records)
-                    .build()
-                    .expect("valid Records config"),
-            )));
+            let [records_component, stats_component] =
+                Self::create_consumer_components(&config, filter.as_ref(), &consumer_mode, topic);
 
             selected_component = records_component.clone();
             components.push(records_component);
-
-            let stats_component = Rc::new(RefCell::new(Stats::from(
-                StatsConfig::builder()
-                    .consumer_mode(Rc::clone(&consumer_mode))
-                    .topic(topic)
-                    .filter(config.filter.clone())
-                    .theme(&config.theme)
-                    .build()
-                    .expect("valid Stats config"),
-            )));
-
             components.push(stats_component);
         }
 
@@ -363,24 +391,44 @@ where
             components.push(logs_component);
         }
 
-        // cache the valid menu item characters based on the configured component
-        let mut menu_item_chars = Vec::new();
-        for i in 0..components.len() {
-            let index = u8::try_from(i).expect("valid char") + 1;
-            let item = (index + b'0') as char;
+        // the produce and groups components are pushed last so that the menu hotkeys of the other
+        // components are the same as before they were added
+        let produce_component = Rc::new(RefCell::new(Produce::from(
+            ProduceConfig::builder()
+                .topic(config.topic.clone())
+                .theme(&config.theme)
+                .build()
+                .expect("valid Produce config"),
+        )));
 
-            menu_item_chars.push(item);
-        }
+        components.push(produce_component);
+
+        let groups_component = Rc::new(RefCell::new(Groups::from(
+            GroupsConfig::builder()
+                .theme(&config.theme)
+                .build()
+                .expect("valid Groups config"),
+        )));
+
+        components.push(groups_component);
+
+        let menu_item_chars = Self::create_menu_item_chars(&components);
 
         let state = State::new(consumer_mode, selected_component);
 
+        let topic = config.topic.clone();
+
         Ok(Self {
+            topic,
+            filter,
             config,
             state,
             event_rx,
             consumer_rx: Some(consumer_rx),
             event_bus,
             consumer,
+            producer,
+            record_serializer,
             exporter,
             components,
             menu_item_chars,
@@ -389,6 +437,50 @@ where
             admin_client,
         })
     }
+    /// Creates the [`Records`] and [`Stats`] components that display the records consumed from
+    /// the specified topic.
+    fn create_consumer_components(
+        config: &Config,
+        filter: Option<&RecordFilter>,
+        consumer_mode: &Rc<Cell<ConsumerMode>>,
+        topic: String,
+    ) -> [Rc<RefCell<dyn Component>>; 2] {
+        let records_component = Rc::new(RefCell::new(Records::from(
+            RecordsConfig::builder()
+                .consumer_mode(Rc::clone(consumer_mode))
+                .topic(topic.clone())
+                .filter(filter.cloned())
+                .theme(&config.theme)
+                .scroll_factor(config.scroll_factor)
+                .max_records(config.max_records)
+                .build()
+                .expect("valid Records config"),
+        )));
+
+        let stats_component = Rc::new(RefCell::new(Stats::from(
+            StatsConfig::builder()
+                .consumer_mode(Rc::clone(consumer_mode))
+                .topic(topic)
+                .filter(filter.cloned())
+                .theme(&config.theme)
+                .build()
+                .expect("valid Stats config"),
+        )));
+
+        [records_component, stats_component]
+    }
+    /// Creates the valid menu item characters based on the available components.
+    fn create_menu_item_chars(components: &[Rc<RefCell<dyn Component>>]) -> Vec<char> {
+        let mut menu_item_chars = Vec::new();
+        for i in 0..components.len() {
+            let index = u8::try_from(i).expect("valid char") + 1;
+            let item = (index + b'0') as char;
+
+            menu_item_chars.push(item);
+        }
+
+        menu_item_chars
+    }
     /// Run the main loop of the application.
     pub async fn run(
         mut self,
@@ -484,12 +576,70 @@ where
     /// Starts the consumer asynchronously. The result of the consumer startup is sent back to the
     /// application through the [`EventBus`].
     fn start_poll_consumer_async(&self) -> anyhow::Result<()> {
-        let topic = self.config.topic.clone().expect("topic configured");
+        let topic = self.topic.clone().expect("topic configured");
+
+        // offsets for specific partitions given by the user only apply to the configured topic
+        let is_configured_topic = self.config.topic.as_deref() == Some(topic.as_str());
+
+        let seek_to = match self.config.seek_to {
+            SeekTo::Custom(_) if !is_configured_topic => SeekTo::None,
+            ref seek_to => seek_to.clone(),
+        };
+
+        let until = match self.config.until {
+            ConsumeUntil::Custom(_) if !is_configured_topic => ConsumeUntil::None,
+            ref until => until.clone(),
+        };
+
+        let mut builder = ConsumeTopicConfig::builder();
 
+        builder
+            .assignment_mode(self.config.assignment_mode)
+            .seek_to(seek_to)
+            .filter(self.filter.clone())
+            .commit_strategy(self.config.commit_strategy)
+            .until(until);
+
+        match self.config.assignment_mode {
+            AssignmentMode::Manual => {
+                builder.partitions(self.resolve_assigned_partitions(&topic)?);
+            }
+            AssignmentMode::Subscribe => {
+                if self.config.partitions.is_some() {
+                    tracing::warn!("partitions are ignored when subscribing to topics");
+                }
+
+                builder.topics(
+                    util::split_topics(&topic)
+                        .into_iter()
+                        .map(String::from)
+                        .collect(),
+                );
+            }
+        }
+
+        let consume_config = builder.build().expect("valid ConsumeTopicConfig");
+
+        let start_consumer_task = StartConsumerTask {
+            consumer: Arc::clone(&self.consumer),
+            event_bus: Arc::clone(&self.event_bus),
+        };
+
+        tokio::task::spawn_blocking(move || {
+            start_consumer_task.run(consume_config);
+        });
+
+        Ok(())
+    }
+    /// Resolves the partitions of the topics that should be explicitly assigned to the Kafka
+    /// consumer, restricted to any partitions specified by the user.
+    fn resolve_assigned_partitions(&self, topic: &str) -> anyhow::Result<Vec<TopicPartition>> {
+        // partitions specified by the user only apply to the configured topic
         let partitions: Vec<i32> = self
             .config
             .partitions
             .as_ref()
+            .filter(|_| self.config.topic.as_deref() == Some(topic))
             .map(|csv| csv.split(","))
             .map(|ps| {
                 ps.map(|p| p.parse::<i32>().expect("valid partition value"))
@@ -497,44 +647,62 @@ where
             })
             .unwrap_or_default();
 
-        let partitions = if partitions.is_empty() {
-            tracing::info!("fetching metadata for topic {} from broker", topic);
-
-            let topic_metadata = self
-                .consumer
-                .fetch_topic_metadata(Some(topic.as_str()), std::time::Duration::from_secs(10))
-                .context("fetch topic metadata from broker")?;
-
-            topic_metadata
-                .first()
-                .expect("topic metadata exists")
-                .partitions
-                .iter()
-                .map(|mp| mp.id)
-                .collect()
-        } else {
+        let mut topic_partitions = self
+            .resolve_topic_partitions(topic)
+            .context("resolve topic partitions")?;
+
+        if !partitions.is_empty() {
             tracing::info!("partition assignments specified by user");
-            partitions
-        };
 
-        let consume_config = ConsumeTopicConfig::builder()
-            .topic(topic)
-            .partitions(partitions)
-            .seek_to(self.config.seek_to.clone())
-            .filter(self.config.filter.clone())
-            .build()
-            .expect("valid ConsumeTopicConfig");
+            topic_partitions.retain(|tp| partitions.contains(&tp.partition));
+        }
 
-        let start_consumer_task = StartConsumerTask {
-            consumer: Arc::clone(&self.consumer),
-            event_bus: Arc::clone(&self.event_bus),
-        };
+        Ok(topic_partitions)
+    }
+    /// Resolves all partitions of the topics that match the CSV of topic names and regular
+    /// expressions, which are distinguished from topic names by a leading `^`, using the topic
+    /// metadata retrieved from the Kafka cluster.
+    fn resolve_topic_partitions(&self, topics: &str) -> anyhow::Result<Vec<TopicPartition>> {
+        tracing::info!("fetching metadata for topics {} from broker", topics);
 
-        tokio::spawn(async move {
-            start_consumer_task.run(consume_config).await;
-        });
+        let topics_metadata = self
+            .consumer
+            .fetch_topic_metadata(None, std::time::Duration::from_secs(10))
+            .context("fetch topic metadata from broker")?;
 
-        Ok(())
+        let mut partitions = BTreeSet::new();
+
+        for pattern in util::split_topics(topics) {
+            let matched: Vec<&Topic> = if pattern.starts_with(TOPIC_REGEX_PREFIX) {
+                let regex = Regex::new(pattern)
+                    .with_context(|| format!("invalid topic regex: {}", pattern))?;
+
+                topics_metadata
+                    .iter()
+                    .filter(|t| regex.is_match(&t.name))
+                    .collect()
+            } else {
+                topics_metadata
+                    .iter()
+                    .filter(|t| t.name == pattern)
+                    .collect()
+            };
+
+            if matched.is_empty() {
+                anyhow::bail!("no topics found matching {}", pattern);
+            }
+
+            for topic in matched {
+                partitions.extend(
+                    topic
+                        .partitions
+                        .iter()
+                        .map(|p| TopicPartition::new(topic.name.clone(), p.id)),
+                );
+            }
+        }
+
+        Ok(partitions.into_iter().collect())
     }
     /// Spawns a task that will receive [`Log`] messages on the specified [`Receiver`] and then
     /// publish an [`Event::LogEmitted`] application event.
@@ -569,7 +737,14 @@ where
     /// ttempts to map the event to an application level action and then defers to the active
     /// [`Component`].
     fn on_key_event_running(&mut self, key_event: KeyEvent) {
+        let capturing_input = self.state.active_component.borrow().is_capturing_input();
+
         let mapped_event = match key_event.code {
+            _ if capturing_input => self
+                .state
+                .active_component
+                .borrow_mut()
+                .map_key_event(key_event, None),
             KeyCode::Esc => MappedKeyEvent::Dispatch(Event::ConfirmExit),
             KeyCode::Tab => MappedKeyEvent::Dispatch(Event::SelectNextWidget),
             KeyCode::Char(c) => {
@@ -605,7 +780,9 @@ where
                 self.buffered_key_press = None;
             }
             MappedKeyEvent::Unhandled => {
-                if let KeyCode::Char(c) = key_event.code {
+                if let KeyCode::Char(c) = key_event.code
+                    && !capturing_input
+                {
                     self.buffered_key_press = Some(BufferedKeyPress::new(c));
                 }
             }
@@ -633,6 +810,17 @@ where
             }
             Event::SelectComponent(idx) => self.on_select_component(idx),
             Event::ExportRecord(record) => self.on_export_record(record),
+            Event::ConsumerComplete => self.on_consumer_complete(),
+            Event::SeekConsumer(partition, position) => {
+                self.spawn_seek_consumer(partition, position)
+            }
+            Event::ConsumerRepositioned(partition, reopened) => {
+                self.on_consumer_repositioned(partition, reopened)
+            }
+            Event::ProduceRecord(record) => self.spawn_produce_record(*record),
+            Event::ReplayRecords(records) => self.spawn_replay_records(records),
+            Event::ImportRecords(request) => self.spawn_import_records(request),
+            Event::ApplyFilter(filter, records) => self.on_apply_filter(filter, records),
             Event::PauseProcessing => self.on_pause_processing(),
             Event::ResumeProcessing => self.on_resume_processing(),
             Event::DisplayNotification(notification) => self.on_display_notification(notification),
@@ -649,7 +837,14 @@ where
             Event::ExportSchema(schema) => self.on_export_schema(schema),
             Event::LoadTopics => self.spawn_load_topics(),
             Event::LoadTopicConfig(topic) => self.spawn_load_topic_config(topic),
+            Event::ConsumeTopic(topic) => self.on_consume_topic(topic),
             Event::ExportTopic(topic, config) => self.on_export_topic(topic, config),
+            Event::CreateTopic(request) => self.spawn_create_topic(request),
+            Event::DeleteTopic(topic) => self.spawn_delete_topic(topic),
+            Event::CreatePartitions(topic, total) => self.spawn_create_partitions(topic, total),
+            Event::AlterTopicConfig(topic, change) => self.spawn_alter_topic_config(topic, change),
+            Event::LoadConsumerGroups(selected) => self.spawn_load_consumer_groups(selected),
+            Event::LoadConsumerGroupOffsets(group) => self.spawn_load_consumer_group_offsets(group),
             _ => {
                 self.components
                     .iter()
@@ -676,16 +871,18 @@ where
             ConsumerEvent::Received(record) => Event::RecordReceived(record),
             ConsumerEvent::Filtered(record) => Event::RecordFiltered(record),
             ConsumerEvent::Statistics(stats) => Event::StatisticsReceived(stats),
+            ConsumerEvent::Complete => Event::ConsumerComplete,
+            ConsumerEvent::Rebalanced(rebalance) => Event::ConsumerRebalanced(rebalance),
         };
 
         self.on_app_event(app_event);
     }
     /// Handles the [`Event::ExportRecord`] event emitted by the [`EventBus`].
-    fn on_export_record(&mut self, record: Record) {
+    fn on_export_record(&mut self, record: Box<Record>) {
         tracing::debug!("exporting selected record");
 
         let notification = match self.exporter.export_record(
-            record,
+            *record,
             self.config.key_format,
             self.config.value_format,
         ) {
@@ -702,6 +899,61 @@ where
         self.event_bus
             .send(Event::DisplayNotification(notification));
     }
+    /// Spawns a background task to produce a record to a Kafka topic.
+    fn spawn_produce_record(&self, request: ProduceRequest) {
+        let task = ProduceRecordTask {
+            producer: Arc::clone(&self.producer),
+            record_serializer: self.record_serializer.clone(),
+            event_bus: Arc::clone(&self.event_bus),
+            request,
+        };
+
+        tokio::spawn(async move {
+            task.run().await;
+        });
+    }
+    /// Spawns a background task to replay records to a Kafka topic.
+    fn spawn_replay_records(&self, records: Vec<ReplayRecord>) {
+        let task = ReplayRecordsTask {
+            consumer: Arc::clone(&self.consumer),
+            producer: Arc::clone(&self.producer),
+            event_bus: Arc::clone(&self.event_bus),
+            records,
+        };
+
+        tokio::spawn(async move {
+            task.run().await;
+        });
+    }
+    /// Spawns a background task to produce the records contained in a JSON Lines file.
+    fn spawn_import_records(&self, request: ImportRequest) {
+        let task = ImportRecordsTask {
+            importer: Importer::new(
+                Arc::clone(&self.producer),
+                self.record_serializer.clone(),
+                self.config.value_format,
+            ),
+            event_bus: Arc::clone(&self.event_bus),
+            request,
+        };
+
+        tokio::spawn(async move {
+            task.run().await;
+        });
+    }
+    /// Spawns a background task to apply a filter to the records that had already been consumed.
+    fn spawn_reapply_filter(&self, filter: RecordFilter, records: Vec<Record>) {
+        let task = ReapplyFilterTask {
+            event_bus: Arc::clone(&self.event_bus),
+            filter,
+            value_format: self.config.value_format,
+            records,
+        };
+
+        tokio::task::spawn_blocking(move || {
+            task.run();
+        });
+    }
     /// Spawns a background task to load topics from the Kafka cluster.
     fn spawn_load_topics(&self) {
         let task = LoadTopicsTask {
@@ -812,6 +1064,147 @@ where
         self.event_bus
             .send(Event::DisplayNotification(notification));
     }
+    /// Handles the [`Event::ConsumerComplete`] event emitted by the [`EventBus`].
+    fn on_consumer_complete(&mut self) {
+        tracing::info!("Kafka consumer reached end bound on all partitions");
+
+        self.state.consumer_mode.set(ConsumerMode::Complete);
+
+        self.event_bus
+            .send(Event::DisplayNotification(Notification::success(
+                "Consumer Reached End Bound",
+            )));
+    }
+    /// Handles the [`Event::ConsumeTopic`] event emitted by the [`EventBus`]. Stops the Kafka
+    /// consumer if it is running, replaces the [`Records`] and [`Stats`] components with new ones
+    /// for the topic and then activates the [`Records`] component which starts the consumer.
+    fn on_consume_topic(&mut self, topic: String) {
+        tracing::info!("switching Kafka consumer to topic {}", topic);
+
+        if self.state.consumer_mode.get() != ConsumerMode::Stopped {
+            if let Err(e) = self.consumer.stop() {
+                tracing::error!("failed to stop consumer: {}", e);
+
+                self.event_bus
+                    .send(Event::DisplayNotification(Notification::failure(
+                        "Stop Consumer Failed",
+                    )));
+
+                return;
+            }
+
+            // discard any events produced for the previous topic that have not been handled yet
+            if let Some(rx) = self.consumer_rx.as_mut() {
+                while rx.try_recv().is_ok() {}
+            }
+
+            self.state.consumer_mode.set(ConsumerMode::Stopped);
+        }
+
+        self.topic = Some(topic.clone());
+
+        let [records_component, stats_component] = Self::create_consumer_components(
+            &self.config,
+            self.filter.as_ref(),
+            &self.state.consumer_mode,
+            topic,
+        );
+
+        let existing_idx = self
+            .components
+            .iter()
+            .position(|c| c.borrow().name() == records_component.borrow().name());
+
+        match existing_idx {
+            Some(idx) => {
+                self.components[idx] = Rc::clone(&records_component);
+                self.components[idx + 1] = stats_component;
+            }
+            None => {
+                // the consumer components always directly follow the topics component
+                self.components.insert(1, Rc::clone(&records_component));
+                self.components.insert(2, stats_component);
+
+                self.menu_item_chars = Self::create_menu_item_chars(&self.components);
+            }
+        }
+
+        if let Some(event) = self.state.activate_component(records_component) {
+            self.event_bus.send(event);
+        }
+    }
+    /// Handles the [`Event::ConsumerRepositioned`] event emitted by the [`EventBus`]. Discards the
+    /// records of the repositioned partitions that were consumed before the seek and have not been
+    /// handled yet, so that they do not refill the cleared records and stats, and resumes any
+    /// partitions that had reached their end bound unless the consumer is paused.
+    fn on_consumer_repositioned(&mut self, partition: Option<TopicPartition>, reopened: bool) {
+        let mut pending = Vec::new();
+
+        if let Some(rx) = self.consumer_rx.as_mut() {
+            while let Ok(consumer_event) = rx.try_recv() {
+                let stale = match &consumer_event {
+                    ConsumerEvent::Received(record) | ConsumerEvent::Filtered(record) => {
+                        partition.as_ref().is_none_or(|tp| {
+                            tp.topic == record.topic && tp.partition == record.partition
+                        })
+                    }
+                    _ => false,
+                };
+
+                if !stale {
+                    pending.push(consumer_event);
+                }
+            }
+        }
+
+        if reopened && self.state.consumer_mode.get() != ConsumerMode::Paused {
+            self.state.consumer_mode.set(ConsumerMode::Processing);
+
+            if let Err(e) = self.consumer.resume() {
+                tracing::error!("failed to resume consumer: {}", e);
+            }
+        }
+
+        let event = Event::ConsumerRepositioned(partition, reopened);
+
+        self.components
+            .iter()
+            .for_each(|c| c.borrow_mut().on_app_event(&event));
+
+        for consumer_event in pending {
+            self.on_consumer_event(consumer_event);
+        }
+
+        self.event_bus
+            .send(Event::DisplayNotification(Notification::success(
+                "Consumer Repositioned Successfully",
+            )));
+    }
+    /// Handles the [`Event::ApplyFilter`] event emitted by the [`EventBus`]. The filter is kept so
+    /// that it is also applied when the user switches to consuming a different topic. Any records
+    /// that had already been consumed are filtered in a background task.
+    fn on_apply_filter(&mut self, filter: Option<RecordFilter>, records: Vec<Record>) {
+        self.consumer.set_filter(filter.clone());
+
+        let notification = if filter.is_some() {
+            Notification::success("Filter Applied Successfully")
+        } else {
+            Notification::success("Filter Cleared Successfully")
+        };
+
+        self.filter = filter.clone();
+
+        if let Some(filter) = filter.as_ref()
+            && !records.is_empty()
+        {
+            self.spawn_reapply_filter(filter.clone(), records);
+        }
+
+        self.on_app_event(Event::FilterApplied(filter));
+
+        self.event_bus
+            .send(Event::DisplayNotification(notification));
+    }
     /// Handles the [`Event::PauseProcessing`] event emitted by the [`EventBus`].
     fn on_pause_processing(&mut self) {
         if self.state.consumer_mode.get() == ConsumerMode::Processing {
@@ -863,6 +1256,103 @@ where
         tracing::info!("received exit application");
         self.state.mode = ExecutionMode::Exited;
     }
+    /// Spawns a background task to reposition the Kafka consumer to the [`SeekPosition`] on the
+    /// topic partition, or on all assigned partitions if no partition is specified.
+    fn spawn_seek_consumer(&self, partition: Option<TopicPartition>, position: SeekPosition) {
+        if !matches!(
+            self.state.consumer_mode.get(),
+            ConsumerMode::Processing | ConsumerMode::Paused | ConsumerMode::Complete
+        ) {
+            return;
+        }
+
+        let task = SeekConsumerTask {
+            consumer: Arc::clone(&self.consumer),
+            event_bus: Arc::clone(&self.event_bus),
+            partition,
+            position,
+        };
+
+        tokio::task::spawn_blocking(move || {
+            task.run();
+        });
+    }
+    /// Spawns a background task to create a topic on the Kafka cluster.
+    fn spawn_create_topic(&self, request: CreateTopicRequest) {
+        let task = CreateTopicTask {
+            admin_client: Arc::clone(&self.admin_client),
+            event_bus: Arc::clone(&self.event_bus),
+            request,
+        };
+
+        tokio::spawn(async move {
+            task.run().await;
+        });
+    }
+    /// Spawns a background task to delete a topic from the Kafka cluster.
+    fn spawn_delete_topic(&self, topic: String) {
+        let task = DeleteTopicTask {
+            admin_client: Arc::clone(&self.admin_client),
+            event_bus: Arc::clone(&self.event_bus),
+            topic,
+        };
+
+        tokio::spawn(async move {
+            task.run().await;
+        });
+    }
+    /// Spawns a background task to increase the number of partitions of a topic on the Kafka
+    /// cluster.
+    fn spawn_create_partitions(&self, topic: String, total: usize) {
+        let task = CreatePartitionsTask {
+            admin_client: Arc::clone(&self.admin_client),
+            event_bus: Arc::clone(&self.event_bus),
+            topic,
+            total,
+        };
+
+        tokio::spawn(async move {
+            task.run().await;
+        });
+    }
+    /// Spawns a background task to load the consumer groups, and the offsets of the selected
+    /// consumer group if specified, from the Kafka cluster.
+    fn spawn_load_consumer_groups(&self, selected: Option<String>) {
+        let task = LoadConsumerGroupsTask {
+            admin_client: Arc::clone(&self.admin_client),
+            event_bus: Arc::clone(&self.event_bus),
+            selected,
+        };
+
+        tokio::task::spawn_blocking(move || {
+            task.run();
+        });
+    }
+    /// Spawns a background task to load the offsets of a consumer group from the Kafka cluster.
+    fn spawn_load_consumer_group_offsets(&self, group: ConsumerGroup) {
+        let task = LoadConsumerGroupOffsetsTask {
+            admin_client: Arc::clone(&self.admin_client),
+            event_bus: Arc::clone(&self.event_bus),
+            group,
+        };
+
+        tokio::task::spawn_blocking(move || {
+            task.run();
+        });
+    }
+    /// Spawns a background task to change a configuration entry of a topic on the Kafka cluster.
+    fn spawn_alter_topic_config(&self, topic: String, change: TopicConfigChange) {
+        let task = AlterTopicConfigTask {
+            admin_client: Arc::clone(&self.admin_client),
+            event_bus: Arc::clone(&self.event_bus),
+            topic,
+            change,
+        };
+
+        tokio::spawn(async move {
+            task.run().await;
+        });
+    }
     /// Handles the [`Event::SelectComponent`] event emitted by the [`EventBus`].
     fn on_select_component(&mut self, idx: usize) {
         tracing::debug!("attemping to select component {}", idx);
@@ -876,7 +1366,7 @@ where
     }
 }
 
-/// Asynchronous task that starts the Kafka consumer.
+/// Blocking task that starts the Kafka consumer.
 struct StartConsumerTask {
     /// Kafka consumer to start.
     consumer: Arc<Consumer>,
@@ -887,7 +1377,7 @@ struct StartConsumerTask {
 impl StartConsumerTask {
     /// Runs the task. Starts the consumer and send the appropriate [`Event`] based on the result
     /// of startup on the [`EventBus`].
-    async fn run(self, consume_config: ConsumeTopicConfig) {
+    fn run(self, consume_config: ConsumeTopicConfig) {
         match self.consumer.start(consume_config) {
             Ok(_) => self.event_bus.send(Event::ConsumerStarted),
             Err(e) => self.event_bus.send(Event::ConsumerStartFailure(e)),
@@ -895,6 +1385,225 @@ impl StartConsumerTask {
     }
 }
 
+/// Blocking task that repositions the Kafka consumer and publishes the result as an application
+/// event.
+struct SeekConsumerTask {
+    /// Kafka consumer to reposition.
+    consumer: Arc<Consumer>,
+    /// [`EventBus`] on which the result of the seek will be published.
+    event_bus: Arc<EventBus>,
+    /// Topic partition to reposition, or all assigned partitions if not specified.
+    partition: Option<TopicPartition>,
+    /// Position to move the consumer to.
+    position: SeekPosition,
+}
+
+impl SeekConsumerTask {
+    /// Runs the task. Seeks the consumer and sends an [`Event::ConsumerRepositioned`] event on the
+    /// [`EventBus`] if successful, otherwise a failure [`Notification`].
+    fn run(self) {
+        match self.consumer.seek(self.partition.as_ref(), self.position) {
+            Ok(reopened) => self
+                .event_bus
+                .send(Event::ConsumerRepositioned(self.partition, reopened)),
+            Err(e) => {
+                tracing::error!("failed to reposition consumer: {}", e);
+
+                self.event_bus
+                    .send(Event::DisplayNotification(Notification::failure(
+                        "Reposition Consumer Failed",
+                    )));
+            }
+        }
+    }
+}
+
+/// Asynchronous task that produces a record to a Kafka topic and publishes the result as an
+/// application event.
+struct ProduceRecordTask {
+    /// [`Producer`] used to produce the record.
+    producer: Arc<Producer>,
+    /// [`RecordSerializer`] used to serialize the key and value of the record.
+    record_serializer: RecordSerializer,
+    /// [`EventBus`] on which the result of producing the record will be published.
+    event_bus: Arc<EventBus>,
+    /// Record to serialize and produce.
+    request: ProduceRequest,
+}
+
+impl ProduceRecordTask {
+    /// Runs the task. Serializes and produces the record and sends the appropriate [`Event`] along
+    /// with a [`Notification`] based on the result on the [`EventBus`].
+    async fn run(self) {
+        let result = match self.record_serializer.serialize(self.request).await {
+            Ok(record) => self.producer.send(record).await,
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(produced) => {
+                let notification = Notification::success(format!(
+                    "Record Produced To Partition {} At Offset {}",
+                    produced.partition, produced.offset
+                ));
+
+                self.event_bus.send(Event::RecordProduced(produced));
+                self.event_bus
+                    .send(Event::DisplayNotification(notification));
+            }
+            Err(e) => {
+                tracing::error!("failed to produce record: {:#}", e);
+
+                let notification =
+                    Notification::failure(format!("Produce Record Failed: {}", e.root_cause()));
+
+                self.event_bus.send(Event::ProduceRecordFailure(e));
+                self.event_bus
+                    .send(Event::DisplayNotification(notification));
+            }
+        }
+    }
+}
+
+/// Asynchronous task that replays previously consumed records to a Kafka topic.
+struct ReplayRecordsTask {
+    /// [`Consumer`] used to fetch the records that were truncated when they were consumed.
+    consumer: Arc<Consumer>,
+    /// [`Producer`] used to produce the records.
+    producer: Arc<Producer>,
+    /// [`EventBus`] on which the result of replaying the records will be published.
+    event_bus: Arc<EventBus>,
+    /// Records to replay in the order they should be produced.
+    records: Vec<ReplayRecord>,
+}
+
+impl ReplayRecordsTask {
+    /// Runs the task. Produces the records one at a time so that their order is preserved and
+    /// stops at the first failure. Sends a [`Notification`] with the result on the [`EventBus`].
+    async fn run(self) {
+        let total = self.records.len();
+        let topic = self
+            .records
+            .first()
+            .map(|r| r.record.topic.clone())
+            .unwrap_or_default();
+
+        let mut replayed = 0;
+
+        for replay in self.records {
+            let result = match Self::load_record(&self.consumer, replay).await {
+                Ok(record) => self.producer.send(record).await,
+                Err(e) => Err(e),
+            };
+
+            if let Err(e) = result {
+                tracing::error!("failed to replay record: {:#}", e);
+
+                let notification = Notification::failure(format!(
+                    "Replay Failed After {} Of {} Records: {}",
+                    replayed,
+                    total,
+                    e.root_cause()
+                ));
+
+                self.event_bus
+                    .send(Event::DisplayNotification(notification));
+
+                return;
+            }
+
+            replayed += 1;
+        }
+
+        tracing::info!("replayed {} records to topic {}", replayed, topic);
+
+        let notification =
+            Notification::success(format!("Replayed {} Records To Topic {}", replayed, topic));
+
+        self.event_bus
+            .send(Event::DisplayNotification(notification));
+    }
+    /// Returns the [`ProduceRecord`] to replay, with the original key and value fetched from the
+    /// topic again if they were truncated when the record was consumed.
+    async fn load_record(
+        consumer: &Arc<Consumer>,
+        replay: ReplayRecord,
+    ) -> anyhow::Result<ProduceRecord> {
+        let mut record = replay.record;
+
+        if let Some((topic_partition, offset)) = replay.refetch {
+            let consumer = Arc::clone(consumer);
+
+            let (key, value) = tokio::task::spawn_blocking(move || {
+                consumer.fetch_record_data(&topic_partition, offset)
+            })
+            .await
+            .context("join record fetch task")??;
+
+            record.key = key;
+            record.value = value;
+        }
+
+        Ok(record)
+    }
+}
+
+/// Asynchronous task that produces the records contained in a JSON Lines file to a Kafka topic and
+/// publishes its progress as application events.
+struct ImportRecordsTask {
+    /// [`Importer`] used to produce the records.
+    importer: Importer,
+    /// [`EventBus`] on which the progress of the import will be published.
+    event_bus: Arc<EventBus>,
+    /// Details of the file to import.
+    request: ImportRequest,
+}
+
+impl ImportRecordsTask {
+    /// Runs the task. Produces the records and periodically sends an [`Event`] with the progress
+    /// on the [`EventBus`], followed by the summary and a [`Notification`] once complete.
+    async fn run(self) {
+        let result = self
+            .importer
+            .import(&self.request, |progress| {
+                let handled = progress.summary.produced + progress.summary.failed;
+
+                if handled % IMPORT_PROGRESS_INTERVAL == 0 {
+                    self.event_bus
+                        .send(Event::ImportProgressed(progress.summary.clone()));
+                }
+            })
+            .await;
+
+        match result {
+            Ok(summary) => {
+                let notification = if summary.failed == 0 {
+                    Notification::success(format!("Imported {} Records", summary.produced))
+                } else {
+                    Notification::failure(format!(
+                        "Imported {} Records With {} Failures",
+                        summary.produced, summary.failed
+                    ))
+                };
+
+                self.event_bus.send(Event::RecordsImported(summary));
+                self.event_bus
+                    .send(Event::DisplayNotification(notification));
+            }
+            Err(e) => {
+                tracing::error!("failed to import records: {:#}", e);
+
+                let notification =
+                    Notification::failure(format!("Import Failed: {}", e.root_cause()));
+
+                self.event_bus.send(Event::ImportRecordsFailure(e));
+                self.event_bus
+                    .send(Event::DisplayNotification(notification));
+            }
+        }
+    }
+}
+
 /// Asynchronous task that polls the terminal backend for events for the application to handle.
 struct PollTerminalTask {
     /// Channel [`Sender`] that is used to send [`TerminalEvent`]s as they are polled.
@@ -973,6 +1682,50 @@ impl PollLogsTask {
     }
 }
 
+/// Asynchronous task that applies a filter to the records that had already been consumed and
+/// publishes the records that do not match as an application event.
+struct ReapplyFilterTask {
+    /// [`EventBus`] on which the records that do not match the filter will be published.
+    event_bus: Arc<EventBus>,
+    /// Filter to apply to the records.
+    filter: RecordFilter,
+    /// Format of the value of the records.
+    value_format: Format,
+    /// Records that had already been consumed when the filter was replaced.
+    records: Vec<Record>,
+}
+
+impl ReapplyFilterTask {
+    /// Runs the task. Evaluates the filter against each of the records and sends an
+    /// [`Event::FilterReapplied`] event along with a [`Notification`] on the [`EventBus`].
+    fn run(self) {
+        let total = self.records.len();
+
+        let removed: Vec<Record> = self
+            .records
+            .into_iter()
+            .filter(|r| !self.filter.matches(r, self.value_format))
+            .collect();
+
+        tracing::info!(
+            "filter removed {} of {} consumed records",
+            removed.len(),
+            total
+        );
+
+        let notification = Notification::success(format!(
+            "Filter Removed {} Of {} Records",
+            removed.len(),
+            total
+        ));
+
+        self.event_bus.send(Event::FilterReapplied(removed));
+
+        self.event_bus
+            .send(Event::DisplayNotification(notification));
+    }
+}
+
 /// Asynchronous task that loads topic metadata from the Kafka cluster and emits the results as an
 /// application event.
 struct LoadTopicsTask {
@@ -1042,6 +1795,292 @@ impl LoadTopicConfigTask {
     }
 }
 
+/// Asynchronous task that creates a topic on the Kafka cluster and publishes the result as an
+/// application event.
+struct CreateTopicTask {
+    /// [`AdminClient`] used to create the topic.
+    admin_client: Arc<AdminClient>,
+    /// [`EventBus`] on which the result of creating the topic will be published.
+    event_bus: Arc<EventBus>,
+    /// Details of the topic to create.
+    request: CreateTopicRequest,
+}
+
+impl CreateTopicTask {
+    /// Runs the task. Creates the topic and sends the appropriate [`Event`] along with a
+    /// [`Notification`] based on the result on the [`EventBus`]. The list of topics is reloaded
+    /// once the topic has been created.
+    async fn run(self) {
+        match self.admin_client.create_topic(&self.request).await {
+            Ok(_) => {
+                let notification =
+                    Notification::success(format!("Topic {} Created", self.request.name));
+
+                self.event_bus.send(Event::TopicCreated);
+                self.event_bus
+                    .send(Event::DisplayNotification(notification));
+                self.event_bus.send(Event::LoadTopics);
+            }
+            Err(e) => {
+                tracing::error!("failed to create topic: {:#}", e);
+
+                let notification =
+                    Notification::failure(format!("Create Topic Failed: {}", e.root_cause()));
+
+                self.event_bus.send(Event::CreateTopicFailure(e));
+                self.event_bus
+                    .send(Event::DisplayNotification(notification));
+            }
+        }
+    }
+}
+
+/// Asynchronous task that deletes a topic from the Kafka cluster and publishes the result as an
+/// application event.
+struct DeleteTopicTask {
+    /// [`AdminClient`] used to delete the topic.
+    admin_client: Arc<AdminClient>,
+    /// [`EventBus`] on which the result of deleting the topic will be published.
+    event_bus: Arc<EventBus>,
+    /// Name of the topic to delete.
+    topic: String,
+}
+
+impl DeleteTopicTask {
+    /// Runs the task. Deletes the topic and sends the appropriate [`Event`] along with a
+    /// [`Notification`] based on the result on the [`EventBus`]. The list of topics is reloaded
+    /// once the topic has been deleted.
+    async fn run(self) {
+        match self.admin_client.delete_topic(&self.topic).await {
+            Ok(_) => {
+                let notification = Notification::success(format!("Topic {} Deleted", self.topic));
+
+                self.event_bus.send(Event::TopicDeleted(self.topic));
+                self.event_bus
+                    .send(Event::DisplayNotification(notification));
+                self.event_bus.send(Event::LoadTopics);
+            }
+            Err(e) => {
+                tracing::error!("failed to delete topic: {:#}", e);
+
+                let notification =
+                    Notification::failure(format!("Delete Topic Failed: {}", e.root_cause()));
+
+                self.event_bus.send(Event::DeleteTopicFailure(e));
+                self.event_bus
+                    .send(Event::DisplayNotification(notification));
+            }
+        }
+    }
+}
+
+/// Blocking task that loads the consumer groups from the Kafka cluster and publishes the results
+/// as an application event.
+struct LoadConsumerGroupsTask {
+    /// [`AdminClient`] used to fetch the consumer groups from the cluster.
+    admin_client: Arc<AdminClient>,
+    /// [`EventBus`] on which the results of the consumer groups fetch will be published.
+    event_bus: Arc<EventBus>,
+    /// Name of the consumer group whose offsets should be reloaded along with the groups, if any.
+    selected: Option<String>,
+}
+
+impl LoadConsumerGroupsTask {
+    /// Runs the task. Fetches the consumer groups from the Kafka cluster and emits an
+    /// [`Event::ConsumerGroupsLoaded`] event on the [`EventBus`] with the results. The offsets of
+    /// the selected consumer group are then reloaded if it still exists.
+    fn run(self) {
+        let groups = match self
+            .admin_client
+            .fetch_consumer_groups(std::time::Duration::from_secs(30))
+        {
+            Ok(groups) => {
+                tracing::info!("loaded {} consumer groups from Kafka cluster", groups.len());
+                groups
+            }
+            Err(e) => {
+                tracing::error!("error loading consumer groups from Kafka cluster: {:#}", e);
+                Vec::default()
+            }
+        };
+
+        let selected = self
+            .selected
+            .as_ref()
+            .and_then(|name| groups.iter().find(|g| g.name == *name))
+            .cloned();
+
+        self.event_bus.send(Event::ConsumerGroupsLoaded(groups));
+
+        if let Some(group) = selected {
+            let task = LoadConsumerGroupOffsetsTask {
+                admin_client: self.admin_client,
+                event_bus: self.event_bus,
+                group,
+            };
+
+            task.run();
+        }
+    }
+}
+
+/// Blocking task that loads the offsets of a consumer group from the Kafka cluster and publishes
+/// the results as an application event.
+struct LoadConsumerGroupOffsetsTask {
+    /// [`AdminClient`] used to fetch the offsets from the cluster.
+    admin_client: Arc<AdminClient>,
+    /// [`EventBus`] on which the results of the offsets fetch will be published.
+    event_bus: Arc<EventBus>,
+    /// [`ConsumerGroup`] for which the offsets should be loaded.
+    group: ConsumerGroup,
+}
+
+impl LoadConsumerGroupOffsetsTask {
+    /// Runs the task. Fetches the committed and log end offsets for the consumer group from the
+    /// Kafka cluster and emits an [`Event::ConsumerGroupOffsetsLoaded`] event on the [`EventBus`]
+    /// with the results.
+    fn run(self) {
+        let offsets = match self
+            .admin_client
+            .fetch_consumer_group_offsets(&self.group, std::time::Duration::from_secs(30))
+        {
+            Ok(offsets) => {
+                tracing::info!(
+                    "loaded offsets for {} partitions of consumer group {}",
+                    offsets.len(),
+                    self.group.name
+                );
+                Some(offsets)
+            }
+            Err(e) => {
+                tracing::error!(
+                    "error loading offsets for consumer group {}: {:#}",
+                    self.group.name,
+                    e
+                );
+                None
+            }
+        };
+
+        self.event_bus
+            .send(Event::ConsumerGroupOffsetsLoaded(self.group.name, offsets));
+    }
+}
+
+/// Asynchronous task that increases the number of partitions of a topic on the Kafka cluster and
+/// publishes the result as an application event.
+struct CreatePartitionsTask {
+    /// [`AdminClient`] used to create the partitions.
+    admin_client: Arc<AdminClient>,
+    /// [`EventBus`] on which the result of creating the partitions will be published.
+    event_bus: Arc<EventBus>,
+    /// Name of the topic to create the partitions for.
+    topic: String,
+    /// New total number of partitions for the topic.
+    total: usize,
+}
+
+impl CreatePartitionsTask {
+    /// Runs the task. Creates the partitions and sends the appropriate [`Event`] along with a
+    /// [`Notification`] based on the result on the [`EventBus`]. The list of topics is reloaded
+    /// once the partitions have been created so that the new partitions are displayed.
+    async fn run(self) {
+        match self
+            .admin_client
+            .create_partitions(&self.topic, self.total)
+            .await
+        {
+            Ok(_) => {
+                let notification = Notification::success(format!(
+                    "Topic {} Now Has {} Partitions",
+                    self.topic, self.total
+                ));
+
+                self.event_bus.send(Event::PartitionsCreated);
+                self.event_bus
+                    .send(Event::DisplayNotification(notification));
+                self.event_bus.send(Event::LoadTopics);
+            }
+            Err(e) => {
+                tracing::error!("failed to create partitions: {:#}", e);
+
+                let notification =
+                    Notification::failure(format!("Add Partitions Failed: {}", e.root_cause()));
+
+                self.event_bus.send(Event::CreatePartitionsFailure(e));
+                self.event_bus
+                    .send(Event::DisplayNotification(notification));
+            }
+        }
+    }
+}
+
+/// Asynchronous task that changes a configuration entry of a topic on the Kafka cluster and
+/// publishes the result as an application event.
+struct AlterTopicConfigTask {
+    /// [`AdminClient`] used to change the topic configuration.
+    admin_client: Arc<AdminClient>,
+    /// [`EventBus`] on which the result of changing the topic configuration will be published.
+    event_bus: Arc<EventBus>,
+    /// Name of the topic whose configuration is changed.
+    topic: String,
+    /// Change to apply to the topic configuration.
+    change: TopicConfigChange,
+}
+
+impl AlterTopicConfigTask {
+    /// Runs the task. Changes the topic configuration and sends the appropriate [`Event`] along
+    /// with a [`Notification`] based on the result on the [`EventBus`]. The topic configuration is
+    /// reloaded once the change has been applied so that it is reflected immediately.
+    async fn run(self) {
+        match self
+            .admin_client
+            .alter_topic_config(&self.topic, &self.change)
+            .await
+        {
+            Ok(_) => {
+                let notification = match &self.change {
+                    TopicConfigChange::Set { key, .. } => {
+                        Notification::success(format!("Config {} Updated", key))
+                    }
+                    TopicConfigChange::Reset { key } => {
+                        Notification::success(format!("Config {} Reset To Default", key))
+                    }
+                };
+
+                let topic_config = self
+                    .admin_client
+                    .load_topic_config(&self.topic)
+                    .await
+                    .inspect_err(|e| {
+                        tracing::error!(
+                            "error reloading configuration for topic {}: {:#}",
+                            self.topic,
+                            e
+                        )
+                    })
+                    .ok()
+                    .flatten();
+
+                self.event_bus
+                    .send(Event::TopicConfigAltered(self.topic, topic_config));
+                self.event_bus
+                    .send(Event::DisplayNotification(notification));
+            }
+            Err(e) => {
+                tracing::error!("failed to alter topic config: {:#}", e);
+
+                let notification =
+                    Notification::failure(format!("Update Config Failed: {}", e.root_cause()));
+
+                self.event_bus.send(Event::AlterTopicConfigFailure(e));
+                self.event_bus
+                    .send(Event::DisplayNotification(notification));
+            }
+        }
+    }
+}
+
 /// Asynchronous task that loads subjects from the schema registry and publishes the results as an
 /// application event.
 struct LoadSubjectsTask<C>
@@ -1,9 +1,17 @@
 use crate::{
-    app::Notification,
+    app::{
+        import::{ImportRequest, ImportSummary},
+        Notification,
+    },
     kafka::{
-        admin::{Topic, TopicConfig},
+        admin::{
+            ConsumerGroup, ConsumerGroupOffset, CreateTopicRequest, Topic, TopicConfig,
+            TopicConfigChange,
+        },
+        filter::RecordFilter,
+        producer::{ProduceRequest, ProducedRecord, ReplayRecord},
         schema::{Schema, Subject, Version},
-        Record,
+        GroupRebalance, Record, SeekPosition, TopicPartition,
     },
     trace::Log,
 };
@@ -19,21 +27,62 @@ pub enum Event {
     ConfirmExit,
     /// Fires when the user exits the application.
     Exit,
+    /// Fires when the user selects a topic that the Kafka consumer should start consuming records
+    /// from, replacing any topic currently being consumed.
+    ConsumeTopic(String),
     /// Fires when the Records component is activated and the Kafka consumer needs to be started.
     StartConsumer,
     /// Fires when the Kafka consumer was started successfully.
     ConsumerStarted,
     /// Fires when the Kafka consumer startup failed.
     ConsumerStartFailure(anyhow::Error),
+    /// Fires when the Kafka consumer has reached the configured end bound on all partitions.
+    ConsumerComplete,
+    /// Fires when the consumer group was rebalanced and the partitions assigned to the Kafka
+    /// consumer changed.
+    ConsumerRebalanced(GroupRebalance),
     /// Fires when the Kafka consumer receives a new [`Record`].
-    RecordReceived(Record),
-    /// Fires when the Kafka consumer receives a new [`Record`] but it does not match the
-    /// configured JSONPath filter.
-    RecordFiltered(Record),
+    RecordReceived(Box<Record>),
+    /// Fires when the Kafka consumer receives a new [`Record`] but it does not match the current
+    /// filter.
+    RecordFiltered(Box<Record>),
     /// Fires when the Kafka consumer receives updated [`Statistics`] from the librdkafka library.
     StatisticsReceived(Box<Statistics>),
     /// Fires when the user wants to export a [`Record`] to a file.
-    ExportRecord(Record),
+    ExportRecord(Box<Record>),
+    /// Fires when the user wants to reposition the running Kafka consumer to a [`SeekPosition`] on
+    /// a single topic partition or, when no partition is specified, on all assigned partitions.
+    SeekConsumer(Option<TopicPartition>, SeekPosition),
+    /// Fires when the Kafka consumer was repositioned on a single topic partition or, when no
+    /// partition is specified, on all assigned partitions. Indicates whether any of the partitions
+    /// had already reached its end bound and is consumed again.
+    ConsumerRepositioned(Option<TopicPartition>, bool),
+    /// Fires when the user wants to replace the filter applied to the records consumed, or clear it
+    /// when no filter is specified. Contains the records that have already been consumed that the
+    /// filter should also be applied to, which is empty unless requested by the user.
+    ApplyFilter(Option<RecordFilter>, Vec<Record>),
+    /// Fires when the filter applied to the records consumed was replaced.
+    FilterApplied(Option<RecordFilter>),
+    /// Fires when the filter was applied to the records that had already been consumed. Contains
+    /// the records that do not match the filter.
+    FilterReapplied(Vec<Record>),
+    /// Fires when the user wants to produce a record to a Kafka topic.
+    ProduceRecord(Box<ProduceRequest>),
+    /// Fires when a record was produced to a Kafka topic and acknowledged by the broker.
+    RecordProduced(ProducedRecord),
+    /// Fires when a record failed to be produced to a Kafka topic.
+    ProduceRecordFailure(anyhow::Error),
+    /// Fires when the user wants to replay previously consumed records to a Kafka topic.
+    ReplayRecords(Vec<ReplayRecord>),
+    /// Fires when the user wants to produce the records contained in a JSON Lines file to a Kafka
+    /// topic.
+    ImportRecords(ImportRequest),
+    /// Fires periodically while the records contained in a JSON Lines file are being produced.
+    ImportProgressed(ImportSummary),
+    /// Fires when all of the lines in a JSON Lines file have been handled.
+    RecordsImported(ImportSummary),
+    /// Fires when a JSON Lines file could not be read.
+    ImportRecordsFailure(anyhow::Error),
     /// Fires when the user wants to continue processing records.
     ResumeProcessing,
     /// Fires when the user wants to pause record consumption.
@@ -70,6 +119,42 @@ pub enum Event {
     TopicConfigLoaded(Option<TopicConfig>),
     /// Fires when the user wants to export a [`Topic`] and it's configuration to a file.
     ExportTopic(Topic, TopicConfig),
+    /// Fires when the user wants to create a new topic on the Kafka cluster.
+    CreateTopic(CreateTopicRequest),
+    /// Fires when a topic was created on the Kafka cluster.
+    TopicCreated,
+    /// Fires when a topic failed to be created on the Kafka cluster.
+    CreateTopicFailure(anyhow::Error),
+    /// Fires when the user wants to delete the topic with the given name from the Kafka cluster.
+    DeleteTopic(String),
+    /// Fires when the topic with the given name was deleted from the Kafka cluster.
+    TopicDeleted(String),
+    /// Fires when a topic failed to be deleted from the Kafka cluster.
+    DeleteTopicFailure(anyhow::Error),
+    /// Fires when the user wants to increase the number of partitions of the topic with the given
+    /// name to the new total.
+    CreatePartitions(String, usize),
+    /// Fires when the number of partitions of a topic was increased.
+    PartitionsCreated,
+    /// Fires when the number of partitions of a topic failed to be increased.
+    CreatePartitionsFailure(anyhow::Error),
+    /// Fires when the user wants to change a configuration entry of the topic with the given name.
+    AlterTopicConfig(String, TopicConfigChange),
+    /// Fires when a configuration entry of the topic with the given name was changed, along with
+    /// the topic configuration reloaded from the Kafka cluster.
+    TopicConfigAltered(String, Option<TopicConfig>),
+    /// Fires when a configuration entry of a topic failed to be changed.
+    AlterTopicConfigFailure(anyhow::Error),
+    /// Fires when the list of consumer groups needs to be loaded from the Kafka cluster, along with
+    /// the offsets of the consumer group with the given name, if any.
+    LoadConsumerGroups(Option<String>),
+    /// Fires when the list of consumer groups has been loaded from the Kafka cluster.
+    ConsumerGroupsLoaded(Vec<ConsumerGroup>),
+    /// Fires when the offsets of a consumer group need to be loaded from the Kafka cluster.
+    LoadConsumerGroupOffsets(ConsumerGroup),
+    /// Fires when the offsets of the consumer group with the given name have been loaded from the
+    /// Kafka cluster.
+    ConsumerGroupOffsetsLoaded(String, Option<Vec<ConsumerGroupOffset>>),
 }
 
 /// The bus over which [`Event`]s are published.
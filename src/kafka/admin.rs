@@ -1,13 +1,26 @@
+use crate::kafka::TopicPartition;
+
 use anyhow::Context;
 use derive_builder::Builder;
 use rdkafka::{
-    ClientConfig, ClientContext,
-    admin::{AdminClient as RDAdminClient, AdminOptions, ConfigEntry, ResourceSpecifier},
+    ClientConfig, ClientContext, Offset, TopicPartitionList,
+    admin::{
+        AdminClient as RDAdminClient, AdminOptions, ConfigEntry, NewPartitions, NewTopic,
+        ResourceSpecifier, TopicReplication,
+    },
+    bindings,
     config::{FromClientConfigAndContext, RDKafkaLogLevel},
+    groups::{GroupInfo, GroupMemberInfo},
     metadata::{MetadataPartition, MetadataTopic},
+    types::RDKafkaErrorCode,
 };
 use serde::Serialize;
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{
+    collections::{BTreeMap, HashMap},
+    ffi::{CStr, CString, c_char},
+    sync::Arc,
+    time::Duration,
+};
 use tokio::{sync::RwLock, time::Instant};
 
 /// Represents a partition of a Kafka topic including the IDs of the current leader and replica
@@ -97,6 +110,9 @@ pub struct TopicConfigEntry {
     pub value: Option<String>,
     /// Indicates if the configuration entry is a default value.
     pub default: bool,
+    /// Indicates if the configuration entry cannot be changed.
+    #[serde(skip)]
+    pub read_only: bool,
 }
 
 impl From<ConfigEntry> for TopicConfigEntry {
@@ -106,6 +122,187 @@ impl From<ConfigEntry> for TopicConfigEntry {
             key: value.name,
             value: value.value,
             default: value.is_default,
+            read_only: value.is_read_only,
+        }
+    }
+}
+
+/// Contains the details of a topic that should be created on the Kafka cluster.
+#[derive(Clone, Debug)]
+pub struct CreateTopicRequest {
+    /// Name of the topic.
+    pub name: String,
+    /// Number of partitions for the topic. When not specified the broker default is used.
+    pub partitions: Option<i32>,
+    /// Replication factor for the topic. When not specified the broker default is used.
+    pub replication_factor: Option<i32>,
+    /// Configuration entries that override the broker defaults for the topic.
+    pub config: Vec<(String, String)>,
+}
+
+/// Enumerates the changes that can be applied to a single configuration entry of a Kafka topic.
+#[derive(Clone, Debug)]
+pub enum TopicConfigChange {
+    /// Sets the configuration entry with the key to the value.
+    Set { key: String, value: String },
+    /// Removes the value set for the configuration entry with the key so that the default applies.
+    Reset { key: String },
+}
+
+impl TopicConfigChange {
+    /// Returns the key of the configuration entry that is changed.
+    pub fn key(&self) -> &str {
+        match self {
+            Self::Set { key, .. } | Self::Reset { key } => key,
+        }
+    }
+}
+
+/// Protocol type of consumer groups whose members use the Kafka consumer protocol, which defines
+/// how the partitions assigned to the members are encoded.
+const CONSUMER_PROTOCOL_TYPE: &str = "consumer";
+
+/// Represents a consumer group on the Kafka cluster including its current members.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConsumerGroup {
+    /// Name of the consumer group.
+    pub name: String,
+    /// State of the consumer group, e.g. `Stable` or `Empty`.
+    pub state: String,
+    /// Name of the protocol used to assign partitions to the members, e.g. `range`.
+    pub protocol: String,
+    /// Type of protocol used by the members of the consumer group, e.g. `consumer`.
+    pub protocol_type: String,
+    /// Current members of the consumer group.
+    pub members: Vec<ConsumerGroupMember>,
+}
+
+impl PartialOrd for ConsumerGroup {
+    /// Compares two [`ConsumerGroup`] instances for ordering.
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ConsumerGroup {
+    /// Compares two [`ConsumerGroup`] instances based on their names for ordering.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.name.cmp(&other.name)
+    }
+}
+
+impl From<&GroupInfo> for ConsumerGroup {
+    /// Converts from a reference to a [`GroupInfo`] to an owned [`ConsumerGroup`]. The partitions
+    /// assigned to the members are only decoded when the group uses the consumer protocol.
+    fn from(value: &GroupInfo) -> Self {
+        let decode_assignments = value.protocol_type() == CONSUMER_PROTOCOL_TYPE;
+
+        let members = value
+            .members()
+            .iter()
+            .map(|m| ConsumerGroupMember::new(m, decode_assignments))
+            .collect();
+
+        Self {
+            name: String::from(value.name()),
+            state: String::from(value.state()),
+            protocol: String::from(value.protocol()),
+            protocol_type: String::from(value.protocol_type()),
+            members,
+        }
+    }
+}
+
+/// Represents a member of a consumer group including the partitions assigned to it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConsumerGroupMember {
+    /// ID assigned to the member by the group coordinator.
+    pub id: String,
+    /// Client ID configured for the member.
+    pub client_id: String,
+    /// Host that the member is connected from.
+    pub client_host: String,
+    /// Partitions currently assigned to the member.
+    pub assignment: Vec<TopicPartition>,
+}
+
+impl ConsumerGroupMember {
+    /// Creates a new [`ConsumerGroupMember`] from a reference to a [`GroupMemberInfo`], decoding
+    /// the assigned partitions if specified.
+    fn new(value: &GroupMemberInfo, decode_assignment: bool) -> Self {
+        let assignment = value
+            .assignment()
+            .filter(|_| decode_assignment)
+            .and_then(decode_consumer_assignment)
+            .unwrap_or_default();
+
+        Self {
+            id: String::from(value.id()),
+            client_id: String::from(value.client_id()),
+            client_host: String::from(value.client_host()),
+            assignment,
+        }
+    }
+}
+
+/// Decodes the partitions from the assignment of a member of a consumer group that uses the
+/// consumer protocol. Returns [`None`] if the assignment is malformed.
+fn decode_consumer_assignment(data: &[u8]) -> Option<Vec<TopicPartition>> {
+    let mut data = data;
+
+    let _version = read_i16(&mut data)?;
+
+    let mut assignment = Vec::new();
+
+    for _ in 0..read_i32(&mut data)? {
+        let len = usize::try_from(read_i16(&mut data)?).ok()?;
+        let topic = std::str::from_utf8(take(&mut data, len)?).ok()?;
+
+        for _ in 0..read_i32(&mut data)? {
+            assignment.push(TopicPartition::new(topic, read_i32(&mut data)?));
+        }
+    }
+
+    assignment.sort();
+
+    Some(assignment)
+}
+
+/// Takes the specified number of bytes from the front of the data, advancing it past them.
+fn take<'a>(data: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+    let (head, tail) = data.split_at_checked(len)?;
+    *data = tail;
+    Some(head)
+}
+
+/// Reads a big endian [`i16`] from the front of the data, advancing it past the value.
+fn read_i16(data: &mut &[u8]) -> Option<i16> {
+    take(data, 2)?.try_into().ok().map(i16::from_be_bytes)
+}
+
+/// Reads a big endian [`i32`] from the front of the data, advancing it past the value.
+fn read_i32(data: &mut &[u8]) -> Option<i32> {
+    take(data, 4)?.try_into().ok().map(i32::from_be_bytes)
+}
+
+/// Represents the progress of a consumer group on a single partition of a Kafka topic.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConsumerGroupOffset {
+    /// Topic partition that the offsets are for.
+    pub topic_partition: TopicPartition,
+    /// Offset committed by the consumer group, if any.
+    pub committed: Option<i64>,
+    /// Offset of the next record that will be appended to the partition, if it could be fetched.
+    pub log_end: Option<i64>,
+}
+
+impl ConsumerGroupOffset {
+    /// Returns the number of records in the partition that the consumer group has not committed,
+    /// if both offsets are known.
+    pub fn lag(&self) -> Option<i64> {
+        match (self.committed, self.log_end) {
+            (Some(committed), Some(log_end)) => Some((log_end - committed).max(0)),
+            _ => None,
         }
     }
 }
@@ -199,6 +396,218 @@ impl AdminClientConfig {
 /// Default cache TTL of 5 minutes.
 const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(300);
 
+/// Maximum amount of time to wait for the result of an incremental alter configs request. Longer
+/// than the default request timeout of librdkafka so that a timeout reported by the library is
+/// preferred.
+const ALTER_CONFIGS_RESULT_TIMEOUT: Duration = Duration::from_secs(90);
+
+/// Native librdkafka queue that receives the result of an admin operation which rdkafka does not
+/// provide a wrapper for. The queue is destroyed when it is dropped.
+struct NativeResultQueue(*mut bindings::rd_kafka_queue_t);
+
+// SAFETY: librdkafka queues are thread safe and the queue is only ever polled by its owner.
+unsafe impl Send for NativeResultQueue {}
+
+impl NativeResultQueue {
+    /// Blocks until the result of an admin operation is delivered to the queue and reads it using
+    /// the specified function. Returns an error if the operation failed as a whole.
+    fn wait<T>(
+        self,
+        timeout: Duration,
+        read: unsafe fn(*mut bindings::rd_kafka_event_t) -> anyhow::Result<T>,
+    ) -> anyhow::Result<T> {
+        let timeout_ms = i32::try_from(timeout.as_millis()).unwrap_or(i32::MAX);
+
+        // SAFETY: the queue pointer is valid until the queue is dropped and the event is only read
+        // before it is destroyed.
+        unsafe {
+            let event = bindings::rd_kafka_queue_poll(self.0, timeout_ms);
+
+            if event.is_null() {
+                anyhow::bail!("timed out waiting for result");
+            }
+
+            let result = match RDKafkaErrorCode::from(bindings::rd_kafka_event_error(event)) {
+                RDKafkaErrorCode::NoError => read(event),
+                code => Err(anyhow::anyhow!(
+                    "{}",
+                    native_string(bindings::rd_kafka_event_error_string(event))
+                        .unwrap_or_else(|| code.to_string())
+                )),
+            };
+
+            bindings::rd_kafka_event_destroy(event);
+
+            result
+        }
+    }
+}
+
+impl Drop for NativeResultQueue {
+    /// Destroys the underlying librdkafka queue.
+    fn drop(&mut self) {
+        // SAFETY: the queue was created by librdkafka and is not used once dropped.
+        unsafe { bindings::rd_kafka_queue_destroy(self.0) };
+    }
+}
+
+/// Reads the outcome of an incremental alter configs request from the librdkafka event that it
+/// was delivered in, returning an error if any of its resources failed.
+///
+/// # Safety
+///
+/// The event must be a valid pointer that has not been destroyed.
+unsafe fn incremental_alter_configs_result(
+    event: *mut bindings::rd_kafka_event_t,
+) -> anyhow::Result<()> {
+    unsafe {
+        let result = bindings::rd_kafka_event_IncrementalAlterConfigs_result(event);
+        if result.is_null() {
+            anyhow::bail!("unexpected event received for incremental alter configs request");
+        }
+
+        let mut count = 0;
+        let resources =
+            bindings::rd_kafka_IncrementalAlterConfigs_result_resources(result, &mut count);
+
+        for i in 0..count {
+            let resource = *resources.add(i);
+
+            let code = RDKafkaErrorCode::from(bindings::rd_kafka_ConfigResource_error(resource));
+            if code != RDKafkaErrorCode::NoError {
+                anyhow::bail!(
+                    "{}",
+                    native_string(bindings::rd_kafka_ConfigResource_error_string(resource))
+                        .unwrap_or_else(|| code.to_string())
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the offsets committed by a consumer group from the librdkafka event that the result of a
+/// list consumer group offsets request was delivered in. Partitions without a valid committed
+/// offset are returned without one.
+///
+/// # Safety
+///
+/// The event must be a valid pointer that has not been destroyed.
+unsafe fn list_consumer_group_offsets_result(
+    event: *mut bindings::rd_kafka_event_t,
+) -> anyhow::Result<Vec<(TopicPartition, Option<i64>)>> {
+    unsafe {
+        let result = bindings::rd_kafka_event_ListConsumerGroupOffsets_result(event);
+        if result.is_null() {
+            anyhow::bail!("unexpected event received for list consumer group offsets request");
+        }
+
+        let mut count = 0;
+        let groups = bindings::rd_kafka_ListConsumerGroupOffsets_result_groups(result, &mut count);
+
+        let mut offsets = Vec::new();
+
+        for i in 0..count {
+            let group = *groups.add(i);
+
+            let error = bindings::rd_kafka_group_result_error(group);
+            if !error.is_null() {
+                anyhow::bail!(
+                    "{}",
+                    native_string(bindings::rd_kafka_error_string(error))
+                        .unwrap_or_else(|| String::from("unknown error"))
+                );
+            }
+
+            let partitions = bindings::rd_kafka_group_result_partitions(group);
+            if partitions.is_null() {
+                continue;
+            }
+
+            let partitions = &*partitions;
+
+            for j in 0..usize::try_from(partitions.cnt).unwrap_or_default() {
+                let partition = &*partitions.elems.add(j);
+
+                let Some(topic) = native_string(partition.topic) else {
+                    continue;
+                };
+
+                let committed = Some(partition.offset).filter(|o| {
+                    *o >= 0 && RDKafkaErrorCode::from(partition.err) == RDKafkaErrorCode::NoError
+                });
+
+                offsets.push((TopicPartition::new(topic, partition.partition), committed));
+            }
+        }
+
+        Ok(offsets)
+    }
+}
+
+/// Reads the offsets of the topic partitions from the librdkafka event that the result of a list
+/// offsets request was delivered in. Partitions whose offset could not be listed are left out.
+///
+/// # Safety
+///
+/// The event must be a valid pointer that has not been destroyed.
+unsafe fn list_offsets_result(
+    event: *mut bindings::rd_kafka_event_t,
+) -> anyhow::Result<HashMap<TopicPartition, i64>> {
+    unsafe {
+        let result = bindings::rd_kafka_event_ListOffsets_result(event);
+        if result.is_null() {
+            anyhow::bail!("unexpected event received for list offsets request");
+        }
+
+        let mut count = 0;
+        let infos = bindings::rd_kafka_ListOffsets_result_infos(result, &mut count);
+
+        let mut offsets = HashMap::new();
+
+        for i in 0..count {
+            let partition = bindings::rd_kafka_ListOffsetsResultInfo_topic_partition(*infos.add(i));
+            if partition.is_null() {
+                continue;
+            }
+
+            let partition = &*partition;
+
+            let Some(topic) = native_string(partition.topic) else {
+                continue;
+            };
+
+            let topic_partition = TopicPartition::new(topic, partition.partition);
+
+            match RDKafkaErrorCode::from(partition.err) {
+                RDKafkaErrorCode::NoError => {
+                    offsets.insert(topic_partition, partition.offset);
+                }
+                code => tracing::warn!("failed to list offset for {}: {}", topic_partition, code),
+            }
+        }
+
+        Ok(offsets)
+    }
+}
+
+/// Copies a nullable C string owned by librdkafka into an owned [`String`].
+///
+/// # Safety
+///
+/// The pointer must either be null or point to a valid nul terminated string.
+unsafe fn native_string(ptr: *const c_char) -> Option<String> {
+    if ptr.is_null() {
+        return None;
+    }
+
+    // SAFETY: the caller guarantees that the pointer is a valid nul terminated string.
+    let s = unsafe { CStr::from_ptr(ptr) };
+
+    Some(s.to_string_lossy().into_owned()).filter(|s| !s.is_empty())
+}
+
 /// The Kafka admin client used to perform administrative operations on the Kafka cluster. Caches
 /// responses with a configurable time-to-live (TTL) to reduce redundant network calls.
 pub struct AdminClient {
@@ -294,4 +703,350 @@ impl AdminClient {
             }
         }
     }
+    /// Creates a new topic on the Kafka cluster using the details in the [`CreateTopicRequest`].
+    /// Any validation error returned by the broker, e.g. the topic already exists, is returned as
+    /// an error.
+    pub async fn create_topic(&self, request: &CreateTopicRequest) -> anyhow::Result<()> {
+        // -1 instructs the broker to apply its default value
+        let new_topic = request.config.iter().fold(
+            NewTopic::new(
+                &request.name,
+                request.partitions.unwrap_or(-1),
+                TopicReplication::Fixed(request.replication_factor.unwrap_or(-1)),
+            ),
+            |t, (k, v)| t.set(k, v),
+        );
+
+        let result = self
+            .client
+            .create_topics(&[new_topic], &self.admin_options)
+            .await
+            .context("create topic")?
+            .into_iter()
+            .next();
+
+        match result {
+            Some(Ok(_)) => {
+                tracing::info!("created topic {}", request.name);
+                Ok(())
+            }
+            Some(Err((name, code))) => {
+                Err(anyhow::anyhow!("{}", code)).with_context(|| format!("create topic {}", name))
+            }
+            None => anyhow::bail!("no result returned when creating topic {}", request.name),
+        }
+    }
+    /// Deletes the specified topic from the Kafka cluster and removes any cached data for it.
+    pub async fn delete_topic(&self, topic: impl AsRef<str>) -> anyhow::Result<()> {
+        let topic = topic.as_ref();
+
+        let result = self
+            .client
+            .delete_topics(&[topic], &self.admin_options)
+            .await
+            .context("delete topic")?
+            .into_iter()
+            .next();
+
+        match result {
+            Some(Ok(_)) => {
+                tracing::info!("deleted topic {}", topic);
+
+                self.invalidate_topic_config(topic).await;
+
+                Ok(())
+            }
+            Some(Err((name, code))) => {
+                Err(anyhow::anyhow!("{}", code)).with_context(|| format!("delete topic {}", name))
+            }
+            None => anyhow::bail!("no result returned when deleting topic {}", topic),
+        }
+    }
+    /// Increases the number of partitions of the specified topic to the new total. Kafka does not
+    /// support removing partitions so the total must be greater than the current partition count,
+    /// otherwise the error returned by the broker is returned.
+    pub async fn create_partitions(
+        &self,
+        topic: impl AsRef<str>,
+        total: usize,
+    ) -> anyhow::Result<()> {
+        let topic = topic.as_ref();
+
+        let new_partitions = NewPartitions::new(topic, total);
+
+        let result = self
+            .client
+            .create_partitions(&[new_partitions], &self.admin_options)
+            .await
+            .context("create partitions")?
+            .into_iter()
+            .next();
+
+        match result {
+            Some(Ok(_)) => {
+                tracing::info!("increased partitions of topic {} to {}", topic, total);
+                Ok(())
+            }
+            Some(Err((name, code))) => Err(anyhow::anyhow!("{}", code))
+                .with_context(|| format!("create partitions for topic {}", name)),
+            None => anyhow::bail!(
+                "no result returned when creating partitions for topic {}",
+                topic
+            ),
+        }
+    }
+    /// Lists the consumer groups on the Kafka cluster along with their members, sorted by name.
+    /// Blocks until every broker has described the groups it coordinates.
+    pub fn fetch_consumer_groups(
+        &self,
+        timeout: impl Into<Duration>,
+    ) -> anyhow::Result<Vec<ConsumerGroup>> {
+        let mut groups: Vec<ConsumerGroup> = self
+            .client
+            .inner()
+            .fetch_group_list(None, timeout.into())
+            .context("fetch consumer groups")?
+            .groups()
+            .iter()
+            .map(Into::into)
+            .collect();
+
+        groups.sort();
+
+        Ok(groups)
+    }
+    /// Fetches the offsets of the [`ConsumerGroup`] for every partition that it has committed an
+    /// offset for or that is assigned to one of its members, along with the log end offset of the
+    /// partition. Blocks until the offsets have been fetched or the timeout elapses, which applies
+    /// to all of the requests made together.
+    pub fn fetch_consumer_group_offsets(
+        &self,
+        group: &ConsumerGroup,
+        timeout: impl Into<Duration>,
+    ) -> anyhow::Result<Vec<ConsumerGroupOffset>> {
+        let deadline = Instant::now() + timeout.into();
+
+        let committed = self
+            .send_list_consumer_group_offsets(&group.name)
+            .and_then(|queue| {
+                queue.wait(
+                    deadline.saturating_duration_since(Instant::now()),
+                    list_consumer_group_offsets_result,
+                )
+            })
+            .with_context(|| format!("list offsets of consumer group {}", group.name))?;
+
+        let mut partitions: BTreeMap<TopicPartition, Option<i64>> = group
+            .members
+            .iter()
+            .flat_map(|m| m.assignment.iter().cloned())
+            .map(|tp| (tp, None))
+            .collect();
+
+        partitions.extend(committed);
+
+        if partitions.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // the log end offsets of all partitions are listed in a single request so that the
+        // partitions led by an unresponsive broker cannot delay the others
+        let log_end_offsets = self
+            .send_list_log_end_offsets(partitions.keys())
+            .and_then(|queue| {
+                queue.wait(
+                    deadline.saturating_duration_since(Instant::now()),
+                    list_offsets_result,
+                )
+            })
+            .inspect_err(|e| {
+                tracing::warn!(
+                    "failed to list log end offsets for consumer group {}: {:#}",
+                    group.name,
+                    e
+                )
+            })
+            .unwrap_or_default();
+
+        let offsets = partitions
+            .into_iter()
+            .map(|(topic_partition, committed)| {
+                let log_end = log_end_offsets.get(&topic_partition).copied();
+
+                ConsumerGroupOffset {
+                    topic_partition,
+                    committed,
+                    log_end,
+                }
+            })
+            .collect();
+
+        Ok(offsets)
+    }
+    /// Sends a list offsets request for the log end offset of every one of the topic partitions
+    /// and returns the [`NativeResultQueue`] that its result is delivered to. The request is made
+    /// through librdkafka directly because rdkafka does not provide a wrapper for it.
+    fn send_list_log_end_offsets<'a>(
+        &self,
+        partitions: impl IntoIterator<Item = &'a TopicPartition>,
+    ) -> anyhow::Result<NativeResultQueue> {
+        let mut partitions_list = TopicPartitionList::new();
+
+        // the end offset has the same value as the latest offset spec of a list offsets request
+        for tp in partitions {
+            partitions_list
+                .add_partition_offset(&tp.topic, tp.partition, Offset::End)
+                .context("add topic partition offset")?;
+        }
+
+        // SAFETY: the native client pointer is valid for as long as the client is borrowed and
+        // librdkafka copies the partitions list when the request is sent, so it is dropped
+        // straight after.
+        unsafe {
+            let rk = self.client.inner().native_ptr();
+
+            let queue = NativeResultQueue(bindings::rd_kafka_queue_new(rk));
+
+            bindings::rd_kafka_ListOffsets(rk, partitions_list.ptr(), std::ptr::null(), queue.0);
+
+            Ok(queue)
+        }
+    }
+    /// Sends a list consumer group offsets request for every partition that the consumer group
+    /// has committed an offset for and returns the [`NativeResultQueue`] that its result is
+    /// delivered to. The request is made through librdkafka directly because rdkafka does not
+    /// provide a wrapper for it.
+    fn send_list_consumer_group_offsets(&self, group: &str) -> anyhow::Result<NativeResultQueue> {
+        let group_id = CString::new(group).context("convert group name")?;
+
+        // SAFETY: the native client pointer is valid for as long as the client is borrowed, the
+        // group name outlives the calls that read it and librdkafka copies the request when it is
+        // sent, so it is destroyed straight after.
+        unsafe {
+            let rk = self.client.inner().native_ptr();
+
+            let queue = NativeResultQueue(bindings::rd_kafka_queue_new(rk));
+
+            let mut request = bindings::rd_kafka_ListConsumerGroupOffsets_new(
+                group_id.as_ptr(),
+                std::ptr::null(),
+            );
+
+            bindings::rd_kafka_ListConsumerGroupOffsets(
+                rk,
+                &mut request,
+                1,
+                std::ptr::null(),
+                queue.0,
+            );
+
+            bindings::rd_kafka_ListConsumerGroupOffsets_destroy(request);
+
+            Ok(queue)
+        }
+    }
+    /// Applies the [`TopicConfigChange`] to the specified topic with an incremental alter configs
+    /// request, which leaves every other configuration entry of the topic untouched. The cached
+    /// [`TopicConfig`] for the topic is invalidated once the change has been applied.
+    pub async fn alter_topic_config(
+        &self,
+        topic: impl AsRef<str>,
+        change: &TopicConfigChange,
+    ) -> anyhow::Result<()> {
+        let topic = topic.as_ref();
+
+        let queue = self
+            .send_incremental_alter_configs(topic, change)
+            .with_context(|| format!("alter config {} of topic {}", change.key(), topic))?;
+
+        tokio::task::spawn_blocking(move || {
+            queue.wait(
+                ALTER_CONFIGS_RESULT_TIMEOUT,
+                incremental_alter_configs_result,
+            )
+        })
+        .await
+        .context("wait for alter config result")?
+        .with_context(|| format!("alter config {} of topic {}", change.key(), topic))?;
+
+        tracing::info!("altered config {} of topic {}", change.key(), topic);
+
+        self.invalidate_topic_config(topic).await;
+
+        Ok(())
+    }
+    /// Sends an incremental alter configs request for the [`TopicConfigChange`] and returns the
+    /// [`NativeResultQueue`] that its result is delivered to. The request is made through
+    /// librdkafka directly because rdkafka only wraps the legacy alter configs request, which
+    /// resets every configuration entry that is not specified.
+    fn send_incremental_alter_configs(
+        &self,
+        topic: &str,
+        change: &TopicConfigChange,
+    ) -> anyhow::Result<NativeResultQueue> {
+        let name = CString::new(topic).context("convert topic name")?;
+        let key = CString::new(change.key()).context("convert config key")?;
+
+        let (op_type, value) = match change {
+            TopicConfigChange::Set { value, .. } => (
+                bindings::rd_kafka_AlterConfigOpType_t::RD_KAFKA_ALTER_CONFIG_OP_TYPE_SET,
+                Some(CString::new(value.as_str()).context("convert config value")?),
+            ),
+            TopicConfigChange::Reset { .. } => (
+                bindings::rd_kafka_AlterConfigOpType_t::RD_KAFKA_ALTER_CONFIG_OP_TYPE_DELETE,
+                None,
+            ),
+        };
+
+        // SAFETY: the native client pointer is valid for as long as the client is borrowed, the
+        // strings outlive the calls that read them and librdkafka copies the config resource when
+        // the request is sent, so it is destroyed straight after.
+        unsafe {
+            let rk = self.client.inner().native_ptr();
+
+            let queue = NativeResultQueue(bindings::rd_kafka_queue_new(rk));
+
+            let mut resource = bindings::rd_kafka_ConfigResource_new(
+                bindings::rd_kafka_ResourceType_t::RD_KAFKA_RESOURCE_TOPIC,
+                name.as_ptr(),
+            );
+
+            let error = bindings::rd_kafka_ConfigResource_add_incremental_config(
+                resource,
+                key.as_ptr(),
+                op_type,
+                value.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+            );
+
+            if !error.is_null() {
+                let message = native_string(bindings::rd_kafka_error_string(error));
+
+                bindings::rd_kafka_error_destroy(error);
+                bindings::rd_kafka_ConfigResource_destroy(resource);
+
+                anyhow::bail!(
+                    "{}",
+                    message.unwrap_or_else(|| String::from("invalid config"))
+                );
+            }
+
+            bindings::rd_kafka_IncrementalAlterConfigs(
+                rk,
+                &mut resource,
+                1,
+                std::ptr::null(),
+                queue.0,
+            );
+
+            bindings::rd_kafka_ConfigResource_destroy(resource);
+
+            Ok(queue)
+        }
+    }
+    /// Removes the cached [`TopicConfig`] for the specified topic, if any, so that it is loaded
+    /// from the Kafka cluster the next time it is requested.
+    async fn invalidate_topic_config(&self, topic: &str) {
+        let mut cache = self.cache.write().await;
+        cache.topic_configs.remove(topic);
+    }
 }
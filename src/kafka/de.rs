@@ -1,4 +1,4 @@
-use crate::util;
+use crate::{kafka::Format, util};
 
 use anyhow::Context;
 use async_trait::async_trait;
@@ -22,7 +22,7 @@ use schema_registry_client::{
 use std::collections::HashMap;
 
 /// The file extension for Protobuf schema files.
-const PROTO_FILE_EXTENSION: &str = "proto";
+pub const PROTO_FILE_EXTENSION: &str = "proto";
 
 /// The offset to start reading Kafka record data serialized in Protobuf format when using the
 /// schema registry.
@@ -39,6 +39,8 @@ pub trait KeyDeserializer: Send + Sync {
         headers: Option<&BorrowedHeaders>,
         data: &[u8],
     ) -> anyhow::Result<String>;
+    /// Returns the [`Format`] that the key is expected to be in.
+    fn format(&self) -> Format;
 }
 
 /// A trait which defines the behavior required to deserialize the value of a Kafka message to a
@@ -52,6 +54,8 @@ pub trait ValueDeserializer: Send + Sync {
         headers: Option<&BorrowedHeaders>,
         data: &[u8],
     ) -> anyhow::Result<String>;
+    /// Returns the [`Format`] that the value is expected to be in.
+    fn format(&self) -> Format;
 }
 
 /// Deserializer implementation that converts the Kafka message value directly to a UTF-8 string.
@@ -69,6 +73,10 @@ impl KeyDeserializer for StringDeserializer {
     ) -> anyhow::Result<String> {
         Ok(String::from_utf8_lossy(data).to_string())
     }
+    /// Returns the [`Format`] that the key is expected to be in.
+    fn format(&self) -> Format {
+        Format::None
+    }
 }
 
 #[async_trait]
@@ -83,6 +91,10 @@ impl ValueDeserializer for StringDeserializer {
     ) -> anyhow::Result<String> {
         Ok(String::from_utf8_lossy(data).to_string())
     }
+    /// Returns the [`Format`] that the value is expected to be in.
+    fn format(&self) -> Format {
+        Format::None
+    }
 }
 
 /// Deserializer implementation that parses the data to a JSON string without schema validation.
@@ -112,6 +124,10 @@ impl KeyDeserializer for JsonStringDeserializer {
             .context("deserialize JSON data")
             .and_then(|v| serde_json::to_string(&v).context("create JSON string"))
     }
+    /// Returns the [`Format`] that the key is expected to be in.
+    fn format(&self) -> Format {
+        Format::Json
+    }
 }
 
 #[async_trait]
@@ -127,6 +143,10 @@ impl ValueDeserializer for JsonStringDeserializer {
             .context("deserialize JSON data")
             .and_then(|v| serde_json::to_string_pretty(&v).context("prettify JSON string"))
     }
+    /// Returns the [`Format`] that the value is expected to be in.
+    fn format(&self) -> Format {
+        Format::Json
+    }
 }
 
 /// Deserializer implementation that converts that uses the Confluent Schema Registry to safely
@@ -178,6 +198,10 @@ where
             .map(|v| v.to_string())
             .map_err(|e| anyhow::anyhow!("unable to deserialize JSON key: {}", e))
     }
+    /// Returns the [`Format`] that the key is expected to be in.
+    fn format(&self) -> Format {
+        Format::Json
+    }
 }
 
 #[async_trait]
@@ -204,6 +228,10 @@ where
             Err(e) => anyhow::bail!("unable to deserialize JSON value: {}", e),
         }
     }
+    /// Returns the [`Format`] that the value is expected to be in.
+    fn format(&self) -> Format {
+        Format::Json
+    }
 }
 
 /// Deserializer implementation that converts that uses the Confluent Schema Registry to safely
@@ -261,6 +289,10 @@ where
             Err(e) => anyhow::bail!("unable to deserialize Avro key: {}", e),
         }
     }
+    /// Returns the [`Format`] that the key is expected to be in.
+    fn format(&self) -> Format {
+        Format::Avro
+    }
 }
 
 #[async_trait]
@@ -294,6 +326,10 @@ where
             Err(e) => anyhow::bail!("unable to deserialize Avro value: {}", e),
         }
     }
+    /// Returns the [`Format`] that the value is expected to be in.
+    fn format(&self) -> Format {
+        Format::Avro
+    }
 }
 
 /// Deserializer implementation that converts that uses the Confluent Schema Registry to safely
@@ -341,7 +377,13 @@ impl ProtobufSchemaDeserializer {
         // indexes which can be a common case in protobuf serialiazation. This does indeed work
         // when testing against the confluent schema registry protobuf serializer but may need to
         // revisit in the future.
-        let data = &data[PROTOBUF_START_OFFSET..];
+        let Some(data) = data.get(PROTOBUF_START_OFFSET..) else {
+            anyhow::bail!(
+                "protobuf message data has {} bytes, expected at least {}",
+                data.len(),
+                PROTOBUF_START_OFFSET
+            );
+        };
 
         let msg_info = match self.context.get_message(message_type) {
             Some(msg_info) => msg_info,
@@ -477,6 +519,10 @@ impl KeyDeserializer for ProtobufSchemaDeserializer {
             .context("decode key protobuf message")
             .map(|(msg_info, msg_value)| self.message_to_json(msg_info, &msg_value))
     }
+    /// Returns the [`Format`] that the key is expected to be in.
+    fn format(&self) -> Format {
+        Format::Protobuf
+    }
 }
 
 #[async_trait]
@@ -503,6 +549,10 @@ impl ValueDeserializer for ProtobufSchemaDeserializer {
 
         serde_json::to_string_pretty(&json_value).context("pretty print JSON string")
     }
+    /// Returns the [`Format`] that the value is expected to be in.
+    fn format(&self) -> Format {
+        Format::Protobuf
+    }
 }
 
 /// Creates a new [`SerdeHeaders`] from the given [`BorrowedHeaders`] which can be used in the
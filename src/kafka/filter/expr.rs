@@ -0,0 +1,874 @@
+use crate::{
+    kafka::{Record, SeekTimestamp},
+    util,
+};
+
+use anyhow::Context;
+use regex::Regex;
+use serde_json::Value;
+use std::{borrow::Cow, cmp::Ordering};
+
+/// Token produced when splitting a filter expression into its parts.
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    /// Name of a field or an operator keyword, e.g. `header.trace-id` or `contains`.
+    Ident(String),
+    /// Quoted string literal.
+    Str(String),
+    /// Numeric literal.
+    Num(f64),
+    /// The `==` operator.
+    Eq,
+    /// The `!=` operator.
+    Ne,
+    /// The `<` operator.
+    Lt,
+    /// The `<=` operator.
+    Le,
+    /// The `>` operator.
+    Gt,
+    /// The `>=` operator.
+    Ge,
+    /// The `and` or `&&` operator.
+    And,
+    /// The `or` or `||` operator.
+    Or,
+    /// The `not` or `!` operator.
+    Not,
+    /// Opening parenthesis.
+    LParen,
+    /// Closing parenthesis.
+    RParen,
+    /// Opening square bracket.
+    LBracket,
+    /// Closing square bracket.
+    RBracket,
+}
+
+impl Token {
+    /// Returns a description of the [`Token`] for use in error messages.
+    fn describe(&self) -> String {
+        match self {
+            Self::Ident(s) => format!("'{}'", s),
+            Self::Str(s) => format!("string '{}'", s),
+            Self::Num(n) => format!("number {}", n),
+            Self::Eq => String::from("'=='"),
+            Self::Ne => String::from("'!='"),
+            Self::Lt => String::from("'<'"),
+            Self::Le => String::from("'<='"),
+            Self::Gt => String::from("'>'"),
+            Self::Ge => String::from("'>='"),
+            Self::And => String::from("'and'"),
+            Self::Or => String::from("'or'"),
+            Self::Not => String::from("'not'"),
+            Self::LParen => String::from("'('"),
+            Self::RParen => String::from("')'"),
+            Self::LBracket => String::from("'['"),
+            Self::RBracket => String::from("']'"),
+        }
+    }
+}
+
+/// Splits the filter expression into [`Token`]s, each paired with the position in the expression
+/// that it starts at.
+fn tokenize(input: &str) -> anyhow::Result<Vec<(usize, Token)>> {
+    let chars: Vec<char> = input.chars().collect();
+
+    let mut tokens = Vec::new();
+
+    let mut i = 0;
+    while i < chars.len() {
+        let start = i;
+        let next = chars.get(i + 1).copied();
+
+        let token = match chars[i] {
+            c if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '(' => {
+                i += 1;
+                Token::LParen
+            }
+            ')' => {
+                i += 1;
+                Token::RParen
+            }
+            '[' => {
+                i += 1;
+                Token::LBracket
+            }
+            ']' => {
+                i += 1;
+                Token::RBracket
+            }
+            '=' if next == Some('=') => {
+                i += 2;
+                Token::Eq
+            }
+            '!' if next == Some('=') => {
+                i += 2;
+                Token::Ne
+            }
+            '!' => {
+                i += 1;
+                Token::Not
+            }
+            '<' if next == Some('=') => {
+                i += 2;
+                Token::Le
+            }
+            '<' => {
+                i += 1;
+                Token::Lt
+            }
+            '>' if next == Some('=') => {
+                i += 2;
+                Token::Ge
+            }
+            '>' => {
+                i += 1;
+                Token::Gt
+            }
+            '&' if next == Some('&') => {
+                i += 2;
+                Token::And
+            }
+            '|' if next == Some('|') => {
+                i += 2;
+                Token::Or
+            }
+            quote @ ('\'' | '"') => {
+                let mut s = String::new();
+
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        None => anyhow::bail!("unterminated string starting at position {}", start),
+                        // only the quote and the backslash itself are escaped so that regular
+                        // expressions such as '\d+' can be written without doubling backslashes
+                        Some('\\')
+                            if chars.get(i + 1).is_some_and(|c| *c == quote || *c == '\\') =>
+                        {
+                            s.push(chars[i + 1]);
+                            i += 2;
+                        }
+                        Some(c) if *c == quote => {
+                            i += 1;
+                            break;
+                        }
+                        Some(c) => {
+                            s.push(*c);
+                            i += 1;
+                        }
+                    }
+                }
+
+                Token::Str(s)
+            }
+            c if c.is_ascii_digit() || (c == '-' && next.is_some_and(|n| n.is_ascii_digit())) => {
+                i += 1;
+                while chars
+                    .get(i)
+                    .is_some_and(|c| c.is_ascii_digit() || *c == '.')
+                {
+                    i += 1;
+                }
+
+                let s: String = chars[start..i].iter().collect();
+
+                let n = s
+                    .parse::<f64>()
+                    .with_context(|| format!("invalid number {} at position {}", s, start))?;
+
+                Token::Num(n)
+            }
+            c if c.is_alphabetic()
+                || c == '_'
+                || (c == '.' && next.is_some_and(|n| n.is_alphanumeric() || n == '_')) =>
+            {
+                while chars
+                    .get(i)
+                    .is_some_and(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'))
+                {
+                    i += 1;
+                }
+
+                let s: String = chars[start..i].iter().collect();
+
+                match s.to_lowercase().as_str() {
+                    "and" => Token::And,
+                    "or" => Token::Or,
+                    "not" => Token::Not,
+                    _ => Token::Ident(s),
+                }
+            }
+            c => anyhow::bail!("unexpected character '{}' at position {}", c, start),
+        };
+
+        tokens.push((start, token));
+    }
+
+    Ok(tokens)
+}
+
+/// Fields of a [`Record`] that can be referenced in a filter expression.
+#[derive(Clone, Debug)]
+enum Field {
+    /// Name of the topic the record was consumed from.
+    Topic,
+    /// Partition number of the record.
+    Partition,
+    /// Offset of the record.
+    Offset,
+    /// Partition key of the record.
+    Key,
+    /// Timestamp of the record.
+    Timestamp,
+    /// Value of the header with the given key. When the key is repeated the last value is used.
+    Header(String),
+    /// Value of the record, or the field of the value at the given path when it is a JSON tree.
+    Value(Vec<String>),
+}
+
+impl Field {
+    /// Resolves the value of the [`Field`] for the [`Record`]. Returns [`None`] if the record does
+    /// not have a value for the field.
+    fn resolve<'a>(&self, record: &'a Record, value: &'a Value) -> Option<Operand<'a>> {
+        match self {
+            Self::Topic => Some(Operand::Str(Cow::Borrowed(&record.topic))),
+            Self::Partition => Some(Operand::Num(record.partition as f64)),
+            Self::Offset => Some(Operand::Num(record.offset as f64)),
+            Self::Key => record
+                .key
+                .as_deref()
+                .map(|k| Operand::Str(Cow::Borrowed(k))),
+            Self::Timestamp => Some(Operand::Time(record.timestamp.timestamp_millis())),
+            Self::Header(key) => {
+                let header = record.headers.iter().rev().find(|h| h.key == *key)?;
+
+                match header.value_str() {
+                    Some(s) => Some(Operand::Str(Cow::Borrowed(s))),
+                    None => header
+                        .value
+                        .as_deref()
+                        .map(|v| Operand::Str(Cow::Owned(util::to_hex(v)))),
+                }
+            }
+            Self::Value(path) => {
+                let mut node = value;
+
+                for segment in path {
+                    node = match node {
+                        Value::Object(map) => map.get(segment)?,
+                        Value::Array(items) => items.get(segment.parse::<usize>().ok()?)?,
+                        _ => return None,
+                    };
+                }
+
+                match node {
+                    Value::Null => None,
+                    Value::String(s) => Some(Operand::Str(Cow::Borrowed(s))),
+                    Value::Number(n) => n.as_f64().map(Operand::Num),
+                    other => Some(Operand::Str(Cow::Owned(other.to_string()))),
+                }
+            }
+        }
+    }
+}
+
+/// Value of a [`Field`] resolved from a [`Record`].
+#[derive(Clone, Debug)]
+enum Operand<'a> {
+    /// Text value.
+    Str(Cow<'a, str>),
+    /// Numeric value.
+    Num(f64),
+    /// Timestamp as the number of milliseconds since the Unix epoch.
+    Time(i64),
+}
+
+impl Operand<'_> {
+    /// Returns the text representation of the [`Operand`].
+    fn as_text(&self) -> Cow<'_, str> {
+        match self {
+            Self::Str(s) => Cow::Borrowed(s.as_ref()),
+            Self::Num(n) => Cow::Owned(n.to_string()),
+            Self::Time(t) => Cow::Owned(t.to_string()),
+        }
+    }
+    /// Compares the [`Operand`] to the [`Literal`]. Numbers are compared numerically, timestamps
+    /// are compared chronologically and everything else is compared by its text. Returns [`None`]
+    /// if the two cannot be compared, e.g. text that is not numeric compared to a number.
+    fn compare(&self, literal: &Literal) -> Option<Ordering> {
+        match (self, literal) {
+            (Self::Time(t), Literal::Time(ts)) => Some(t.cmp(&ts.resolve_millis())),
+            (Self::Time(t), Literal::Num(n)) => (*t as f64).partial_cmp(n),
+            (Self::Time(_), Literal::Str(_)) | (_, Literal::Time(_)) => None,
+            (Self::Num(n), Literal::Num(m)) => n.partial_cmp(m),
+            (Self::Str(s), Literal::Num(m)) => s.trim().parse::<f64>().ok()?.partial_cmp(m),
+            (_, Literal::Str(l)) => Some(self.as_text().as_ref().cmp(l.as_str())),
+        }
+    }
+}
+
+/// Literal value that a [`Field`] is compared to.
+#[derive(Clone, Debug)]
+enum Literal {
+    /// Text value.
+    Str(String),
+    /// Numeric value.
+    Num(f64),
+    /// Point in time that a timestamp is compared to.
+    Time(SeekTimestamp),
+}
+
+/// Operators used to compare a [`Field`] to a [`Literal`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum CompareOp {
+    /// Field is equal to the literal.
+    Eq,
+    /// Field is not equal to the literal.
+    Ne,
+    /// Field is less than the literal.
+    Lt,
+    /// Field is less than or equal to the literal.
+    Le,
+    /// Field is greater than the literal.
+    Gt,
+    /// Field is greater than or equal to the literal.
+    Ge,
+}
+
+impl CompareOp {
+    /// Determines if the [`Ordering`] of the field relative to the literal satisfies the operator.
+    fn test(self, ordering: Ordering) -> bool {
+        match self {
+            Self::Eq => ordering.is_eq(),
+            Self::Ne => ordering.is_ne(),
+            Self::Lt => ordering.is_lt(),
+            Self::Le => ordering.is_le(),
+            Self::Gt => ordering.is_gt(),
+            Self::Ge => ordering.is_ge(),
+        }
+    }
+}
+
+/// Check that is applied to the value of a [`Field`].
+#[derive(Clone, Debug)]
+enum Predicate {
+    /// Field compares to the literal using the operator.
+    Compare(CompareOp, Literal),
+    /// Text of the field contains the string.
+    Contains(String),
+    /// Text of the field matches the regular expression.
+    Matches(Regex),
+    /// Field is within the inclusive range.
+    Between(Literal, Literal),
+    /// Record has a value for the field.
+    Exists,
+}
+
+/// Node of a parsed filter expression.
+#[derive(Clone, Debug)]
+enum Node {
+    /// Both nodes are true.
+    And(Box<Node>, Box<Node>),
+    /// Either node is true.
+    Or(Box<Node>, Box<Node>),
+    /// Node is false.
+    Not(Box<Node>),
+    /// Value of the field satisfies the predicate.
+    Test(Field, Predicate),
+}
+
+impl Node {
+    /// Determines if the node or any of its children reference the value of the record.
+    fn references_value(&self) -> bool {
+        match self {
+            Self::And(lhs, rhs) | Self::Or(lhs, rhs) => {
+                lhs.references_value() || rhs.references_value()
+            }
+            Self::Not(node) => node.references_value(),
+            Self::Test(field, _) => matches!(field, Field::Value(_)),
+        }
+    }
+    /// Evaluates the node against the [`Record`] and the JSON view of its value.
+    fn evaluate(&self, record: &Record, value: &Value) -> bool {
+        match self {
+            Self::And(lhs, rhs) => lhs.evaluate(record, value) && rhs.evaluate(record, value),
+            Self::Or(lhs, rhs) => lhs.evaluate(record, value) || rhs.evaluate(record, value),
+            Self::Not(node) => !node.evaluate(record, value),
+            Self::Test(field, predicate) => {
+                let operand = field.resolve(record, value);
+
+                match (predicate, operand) {
+                    (Predicate::Exists, operand) => operand.is_some(),
+                    (_, None) => false,
+                    (Predicate::Compare(op, literal), Some(operand)) => {
+                        operand.compare(literal).is_some_and(|o| op.test(o))
+                    }
+                    (Predicate::Contains(s), Some(operand)) => operand.as_text().contains(s),
+                    (Predicate::Matches(regex), Some(operand)) => {
+                        regex.is_match(&operand.as_text())
+                    }
+                    (Predicate::Between(lower, upper), Some(operand)) => {
+                        operand.compare(lower).is_some_and(Ordering::is_ge)
+                            && operand.compare(upper).is_some_and(Ordering::is_le)
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Parsed filter expression that can be evaluated against a [`Record`]. A filter expression is
+/// made up of field comparisons, e.g. `key == 'abc'` or `header.trace-id contains '42'`, which are
+/// combined using the `and`, `or` and `not` operators and parentheses.
+#[derive(Clone, Debug)]
+pub struct Expr {
+    /// Root node of the parsed expression.
+    root: Node,
+    /// Whether any node of the expression references the value of the record.
+    references_value: bool,
+}
+
+impl Expr {
+    /// Parses the filter expression. Returns an error describing the problem if the expression is
+    /// not valid.
+    pub fn parse(input: &str) -> anyhow::Result<Self> {
+        let tokens = tokenize(input)?;
+
+        let mut parser = Parser {
+            tokens,
+            pos: 0,
+            end: input.chars().count(),
+        };
+
+        let root = parser.parse_or()?;
+
+        if let Some((pos, token)) = parser.tokens.get(parser.pos) {
+            anyhow::bail!(
+                "unexpected {} at position {}, expected 'and', 'or' or the end of the expression",
+                token.describe(),
+                pos
+            );
+        }
+
+        let references_value = root.references_value();
+
+        Ok(Self {
+            root,
+            references_value,
+        })
+    }
+    /// Determines if the expression references the value of the record, in which case the value
+    /// needs to be converted to a JSON tree before the expression is evaluated.
+    pub fn references_value(&self) -> bool {
+        self.references_value
+    }
+    /// Evaluates the expression against the [`Record`] and the JSON view of its value. A field
+    /// that the record has no value for only satisfies the `exists` check when negated.
+    pub fn evaluate(&self, record: &Record, value: &Value) -> bool {
+        self.root.evaluate(record, value)
+    }
+}
+
+/// Recursive descent parser which converts the [`Token`]s of a filter expression into an
+/// [`Expr`]. The `not` operator binds tightest, followed by `and` and then `or`.
+struct Parser {
+    /// Tokens of the filter expression paired with their positions.
+    tokens: Vec<(usize, Token)>,
+    /// Index of the next token to consume.
+    pos: usize,
+    /// Position of the end of the filter expression, used in error messages.
+    end: usize,
+}
+
+impl Parser {
+    /// Returns the next [`Token`] without consuming it.
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(_, t)| t)
+    }
+    /// Consumes and returns the next [`Token`] along with its position. Returns an error naming
+    /// what was expected if there are no tokens left.
+    fn next(&mut self, expected: &str) -> anyhow::Result<(usize, Token)> {
+        match self.tokens.get(self.pos).cloned() {
+            Some(token) => {
+                self.pos += 1;
+                Ok(token)
+            }
+            None => anyhow::bail!(
+                "expected {} at position {} but found the end of the expression",
+                expected,
+                self.end
+            ),
+        }
+    }
+    /// Parses expressions joined by the `or` operator.
+    fn parse_or(&mut self) -> anyhow::Result<Node> {
+        let mut expr = self.parse_and()?;
+
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            expr = Node::Or(Box::new(expr), Box::new(self.parse_and()?));
+        }
+
+        Ok(expr)
+    }
+    /// Parses expressions joined by the `and` operator.
+    fn parse_and(&mut self) -> anyhow::Result<Node> {
+        let mut expr = self.parse_not()?;
+
+        while self.peek() == Some(&Token::And) {
+            self.pos += 1;
+            expr = Node::And(Box::new(expr), Box::new(self.parse_not()?));
+        }
+
+        Ok(expr)
+    }
+    /// Parses an expression that is optionally negated by the `not` operator.
+    fn parse_not(&mut self) -> anyhow::Result<Node> {
+        if self.peek() == Some(&Token::Not) {
+            self.pos += 1;
+            return Ok(Node::Not(Box::new(self.parse_not()?)));
+        }
+
+        self.parse_primary()
+    }
+    /// Parses either an expression wrapped in parentheses or a single field test.
+    fn parse_primary(&mut self) -> anyhow::Result<Node> {
+        if self.peek() == Some(&Token::LParen) {
+            self.pos += 1;
+
+            let expr = self.parse_or()?;
+
+            match self.next("')'")? {
+                (_, Token::RParen) => return Ok(expr),
+                (pos, token) => {
+                    anyhow::bail!(
+                        "expected ')' at position {} but found {}",
+                        pos,
+                        token.describe()
+                    )
+                }
+            }
+        }
+
+        let field = self.parse_field()?;
+
+        let predicate = match self.next("an operator")? {
+            (_, Token::Eq) => Predicate::Compare(CompareOp::Eq, self.parse_literal(&field)?),
+            (_, Token::Ne) => Predicate::Compare(CompareOp::Ne, self.parse_literal(&field)?),
+            (_, Token::Lt) => Predicate::Compare(CompareOp::Lt, self.parse_literal(&field)?),
+            (_, Token::Le) => Predicate::Compare(CompareOp::Le, self.parse_literal(&field)?),
+            (_, Token::Gt) => Predicate::Compare(CompareOp::Gt, self.parse_literal(&field)?),
+            (_, Token::Ge) => Predicate::Compare(CompareOp::Ge, self.parse_literal(&field)?),
+            (pos, Token::Ident(op)) => match op.to_lowercase().as_str() {
+                "contains" => Predicate::Contains(self.parse_string()?),
+                "matches" => {
+                    let pattern = self.parse_string()?;
+
+                    let regex = Regex::new(&pattern)
+                        .with_context(|| format!("invalid regular expression {}", pattern))?;
+
+                    Predicate::Matches(regex)
+                }
+                "between" => {
+                    let lower = self.parse_literal(&field)?;
+
+                    match self.next("'and'")? {
+                        (_, Token::And) => {}
+                        (pos, token) => anyhow::bail!(
+                            "expected 'and' at position {} but found {}",
+                            pos,
+                            token.describe()
+                        ),
+                    }
+
+                    let upper = self.parse_literal(&field)?;
+
+                    Predicate::Between(lower, upper)
+                }
+                "exists" => Predicate::Exists,
+                _ => anyhow::bail!("unknown operator '{}' at position {}", op, pos),
+            },
+            (pos, token) => anyhow::bail!(
+                "expected an operator at position {} but found {}",
+                pos,
+                token.describe()
+            ),
+        };
+
+        Ok(Node::Test(field, predicate))
+    }
+    /// Parses a reference to a [`Field`] of the record. Headers and nested fields of the value are
+    /// referenced using dots, e.g. `header.trace-id` or `value.customer.id`, or with quoted names
+    /// in square brackets, e.g. `header['trace id']`.
+    fn parse_field(&mut self) -> anyhow::Result<Field> {
+        let (pos, name) = match self.next("a field")? {
+            (pos, Token::Ident(name)) => (pos, name),
+            (pos, token) => anyhow::bail!(
+                "expected a field at position {} but found {}",
+                pos,
+                token.describe()
+            ),
+        };
+
+        let mut segments: Vec<String> = name.split('.').map(String::from).collect();
+
+        loop {
+            match self.peek() {
+                Some(Token::LBracket) => self.pos += 1,
+                // a name following a bracketed segment, e.g. the `.sku` in `value.items[0].sku`
+                Some(Token::Ident(next)) if next.starts_with('.') => {
+                    segments.extend(next.split('.').skip(1).map(String::from));
+                    self.pos += 1;
+                    continue;
+                }
+                _ => break,
+            }
+
+            let segment = match self.next("a quoted name or index")? {
+                (_, Token::Str(s)) => s,
+                (_, Token::Num(n)) if n >= 0.0 && n.fract() == 0.0 => n.to_string(),
+                (pos, token) => anyhow::bail!(
+                    "expected a quoted name or index at position {} but found {}",
+                    pos,
+                    token.describe()
+                ),
+            };
+
+            match self.next("']'")? {
+                (_, Token::RBracket) => segments.push(segment),
+                (pos, token) => {
+                    anyhow::bail!(
+                        "expected ']' at position {} but found {}",
+                        pos,
+                        token.describe()
+                    )
+                }
+            }
+        }
+
+        let root = segments.remove(0);
+
+        let field = match (root.to_lowercase().as_str(), segments.len()) {
+            ("topic", 0) => Field::Topic,
+            ("partition", 0) => Field::Partition,
+            ("offset", 0) => Field::Offset,
+            ("key", 0) => Field::Key,
+            ("timestamp", 0) => Field::Timestamp,
+            ("header" | "headers", n) if n > 0 => Field::Header(segments.join(".")),
+            ("value", _) => Field::Value(segments),
+            _ => anyhow::bail!("unknown field '{}' at position {}", name, pos),
+        };
+
+        Ok(field)
+    }
+    /// Parses the [`Literal`] that the [`Field`] is compared to. Timestamps are compared to either
+    /// a number of milliseconds since the Unix epoch or a quoted ISO-8601 or relative timestamp.
+    fn parse_literal(&mut self, field: &Field) -> anyhow::Result<Literal> {
+        let literal = match self.next("a value")? {
+            (_, Token::Num(n)) => Literal::Num(n),
+            (pos, Token::Str(s)) if matches!(field, Field::Timestamp) => {
+                match SeekTimestamp::parse(&s) {
+                    Some(ts) => Literal::Time(ts),
+                    None => anyhow::bail!("invalid timestamp '{}' at position {}", s, pos),
+                }
+            }
+            (_, Token::Str(s)) => Literal::Str(s),
+            (pos, token) => anyhow::bail!(
+                "expected a quoted string or number at position {} but found {}",
+                pos,
+                token.describe()
+            ),
+        };
+
+        Ok(literal)
+    }
+    /// Parses a quoted string.
+    fn parse_string(&mut self) -> anyhow::Result<String> {
+        match self.next("a quoted string")? {
+            (_, Token::Str(s)) => Ok(s),
+            (pos, token) => anyhow::bail!(
+                "expected a quoted string at position {} but found {}",
+                pos,
+                token.describe()
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kafka::RecordHeader;
+
+    use chrono::{Local, TimeZone};
+    use serde_json::json;
+
+    /// Creates the [`Record`] that the expressions in these tests are evaluated against.
+    fn record() -> Record {
+        let header = |key: &str, value: &str| RecordHeader {
+            key: String::from(key),
+            value: Some(value.as_bytes().to_vec()),
+        };
+
+        Record {
+            topic: String::from("orders"),
+            partition: 2,
+            offset: 42,
+            key: Some(String::from("order-1")),
+            headers: vec![
+                header("trace-id", "abc"),
+                header("trace id", "def"),
+                header("quote", "it's"),
+                header("path", "a\\b"),
+            ],
+            timestamp: Local.timestamp_millis_opt(1_700_000_000_000).unwrap(),
+            ..Default::default()
+        }
+    }
+    /// Parses the expression and evaluates it against the test [`Record`] and the value.
+    fn eval(expr: &str, value: Value) -> bool {
+        Expr::parse(expr)
+            .unwrap_or_else(|e| panic!("failed to parse {}: {}", expr, e))
+            .evaluate(&record(), &value)
+    }
+    /// Returns the error message produced when parsing the invalid expression.
+    fn parse_error(expr: &str) -> String {
+        Expr::parse(expr)
+            .expect_err("expression should not parse")
+            .to_string()
+    }
+
+    #[test]
+    fn and_binds_tighter_than_or() {
+        assert!(eval(
+            "partition == 2 or partition == 1 and offset == 0",
+            Value::Null
+        ));
+        assert!(eval(
+            "partition == 2 || partition == 1 && offset == 0",
+            Value::Null
+        ));
+        assert!(!eval(
+            "(partition == 2 or partition == 1) and offset == 0",
+            Value::Null
+        ));
+    }
+
+    #[test]
+    fn not_binds_tighter_than_and_and_or() {
+        assert!(eval("not partition == 2 or offset == 42", Value::Null));
+        assert!(eval("!partition == 1 and offset == 42", Value::Null));
+        assert!(!eval("not (partition == 2 or offset == 42)", Value::Null));
+        assert!(eval("not not partition == 2", Value::Null));
+    }
+
+    #[test]
+    fn operator_keywords_are_case_insensitive() {
+        assert!(eval("partition == 2 AND NOT offset == 0", Value::Null));
+        assert!(eval("key CONTAINS 'order'", Value::Null));
+    }
+
+    #[test]
+    fn strings_are_quoted_with_single_or_double_quotes() {
+        assert!(eval("key == 'order-1'", Value::Null));
+        assert!(eval("key == \"order-1\"", Value::Null));
+        assert!(eval("header['trace id'] == \"def\"", Value::Null));
+        assert!(eval("header.trace-id == 'abc'", Value::Null));
+    }
+
+    #[test]
+    fn quotes_and_backslashes_are_escaped() {
+        assert!(eval(r"header.quote == 'it\'s'", Value::Null));
+        assert!(eval(r#"header.quote == "it's""#, Value::Null));
+        assert!(eval(r"header.path == 'a\\b'", Value::Null));
+        // any other backslash is kept so regular expressions don't need doubled backslashes
+        assert!(eval(r"key matches '^order-\d+$'", Value::Null));
+    }
+
+    #[test]
+    fn numbers_are_compared_numerically() {
+        let value = json!({ "amount": 12.5, "delta": -4, "code": "9", "items": [{ "qty": 3 }] });
+
+        assert!(eval("value.amount == 12.5", value.clone()));
+        assert!(eval("value.delta < -3", value.clone()));
+        assert!(eval("value.items[0].qty between 1 and 3", value.clone()));
+        assert!(eval("offset > 9", Value::Null));
+        assert!(eval("value.code < 10", value.clone()));
+        assert!(!eval("value.code > 10", value));
+    }
+
+    #[test]
+    fn text_that_is_not_numeric_never_matches_a_number() {
+        let value = json!({ "name": "abc", "code": "9x" });
+
+        for op in ["==", "!=", "<", "<=", ">", ">="] {
+            assert!(!eval(&format!("value.name {} 10", op), value.clone()));
+            assert!(!eval(&format!("value.code {} 10", op), value.clone()));
+        }
+
+        assert!(!eval("key between 0 and 100", Value::Null));
+    }
+
+    #[test]
+    fn timestamps_are_compared_to_time_literals() {
+        assert!(eval("timestamp == 1700000000000", Value::Null));
+        assert!(eval("timestamp >= '2023-11-14T22:13:20Z'", Value::Null));
+        assert!(!eval("timestamp > '2023-11-14T22:13:20Z'", Value::Null));
+        assert!(eval("timestamp < '-1h'", Value::Null));
+        assert!(!eval("timestamp >= '-1d'", Value::Null));
+        assert!(eval(
+            "timestamp between '2023-11-14T00:00:00Z' and '2023-11-15T00:00:00Z'",
+            Value::Null
+        ));
+    }
+
+    #[test]
+    fn missing_fields_only_match_a_negated_exists() {
+        assert!(!eval("value.missing == 1", json!({})));
+        assert!(!eval("header.missing exists", Value::Null));
+        assert!(eval("not header.missing exists", Value::Null));
+        assert!(eval("header.trace-id exists", Value::Null));
+    }
+
+    #[test]
+    fn errors_report_the_position_of_the_problem() {
+        assert_eq!(
+            parse_error("key == 'abc"),
+            "unterminated string starting at position 7"
+        );
+        assert_eq!(
+            parse_error("key = 'a'"),
+            "unexpected character '=' at position 4"
+        );
+        assert_eq!(
+            parse_error("key =="),
+            "expected a value at position 6 but found the end of the expression"
+        );
+        assert_eq!(
+            parse_error("(key == 'a'"),
+            "expected ')' at position 11 but found the end of the expression"
+        );
+        assert_eq!(parse_error("foo == 1"), "unknown field 'foo' at position 0");
+        assert_eq!(
+            parse_error("key like 'a'"),
+            "unknown operator 'like' at position 4"
+        );
+        assert_eq!(
+            parse_error("key == 'a' key"),
+            "unexpected 'key' at position 11, expected 'and', 'or' or the end of the expression"
+        );
+        assert_eq!(
+            parse_error("partition between 1 or 2"),
+            "expected 'and' at position 20 but found 'or'"
+        );
+        assert_eq!(
+            parse_error("timestamp == 'abc'"),
+            "invalid timestamp 'abc' at position 13"
+        );
+    }
+}
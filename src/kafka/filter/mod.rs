@@ -0,0 +1,194 @@
+mod expr;
+
+use crate::{
+    kafka::{Format, Record, filter::expr::Expr},
+    util,
+};
+
+use anyhow::Context;
+use serde_json::{Map, Value, json};
+use serde_json_path::JsonPath;
+use std::fmt::Display;
+
+/// Prefix of a filter that is written as a JSONPath expression. Filters without a recognized prefix
+/// are also treated as JSONPath.
+pub const FILTER_PREFIX_JSONPATH: &str = "jsonpath:";
+
+/// Prefix of a filter that is written in the filter expression language.
+pub const FILTER_PREFIX_EXPR: &str = "expr:";
+
+/// Enumerates the languages that a filter can be written in.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum FilterLanguage {
+    /// JSONPath query evaluated against the filterable JSON view of the record.
+    #[default]
+    JsonPath,
+    /// Filter expression language made up of field comparisons combined with boolean operators.
+    Expression,
+}
+
+impl Display for FilterLanguage {
+    /// Writes a string representation of the [`FilterLanguage`] value to the
+    /// [`std::fmt::Formatter`].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::JsonPath => "JSONPath",
+            Self::Expression => "Expression",
+        };
+
+        f.write_str(s)
+    }
+}
+
+/// Parsed form of a filter that is evaluated against each [`Record`].
+#[derive(Clone, Debug)]
+enum Matcher {
+    /// JSONPath query which matches a record when it returns any results.
+    JsonPath(JsonPath),
+    /// Filter expression which matches a record when it evaluates to true.
+    Expression(Expr),
+}
+
+/// Filter that is applied to the [`Record`]s consumed from a Kafka topic. The filter is parsed
+/// once when it is created so that it can be cheaply evaluated against each [`Record`]. The
+/// language of the filter is selected by a prefix, see [`FILTER_PREFIX_JSONPATH`] and
+/// [`FILTER_PREFIX_EXPR`].
+#[derive(Clone, Debug)]
+pub struct RecordFilter {
+    /// Expression that the filter was parsed from, including any language prefix.
+    expression: String,
+    /// Language that the filter is written in.
+    language: FilterLanguage,
+    /// Parsed form of the filter.
+    matcher: Matcher,
+}
+
+impl RecordFilter {
+    /// Creates a new [`RecordFilter`] by parsing the expression in the language selected by its
+    /// prefix. Returns an error if the expression is not valid syntax for the language.
+    pub fn parse(expression: impl Into<String>) -> anyhow::Result<Self> {
+        let expression = expression.into();
+
+        let (language, matcher) = if let Some(e) = expression.strip_prefix(FILTER_PREFIX_EXPR) {
+            let expr =
+                Expr::parse(e).with_context(|| format!("parse filter expression {}", e.trim()))?;
+
+            (FilterLanguage::Expression, Matcher::Expression(expr))
+        } else {
+            let e = expression
+                .strip_prefix(FILTER_PREFIX_JSONPATH)
+                .unwrap_or(&expression)
+                .trim();
+
+            let json_path = JsonPath::parse(e)
+                .with_context(|| format!("parse JSONPath filter expression {}", e))?;
+
+            (FilterLanguage::JsonPath, Matcher::JsonPath(json_path))
+        };
+
+        Ok(Self {
+            expression,
+            language,
+            matcher,
+        })
+    }
+    /// Returns the expression that the filter was parsed from, including any language prefix.
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+    /// Returns the [`FilterLanguage`] that the filter is written in.
+    pub fn language(&self) -> FilterLanguage {
+        self.language
+    }
+    /// Determines if the [`Record`] matches this filter. The [`Format`] of the value drives
+    /// whether the value can be queried as a JSON tree.
+    pub fn matches(&self, record: &Record, value_format: Format) -> bool {
+        match &self.matcher {
+            Matcher::JsonPath(json_path) => {
+                let json = filterable_json(record, value_format);
+
+                !json_path.query(&json).is_empty()
+            }
+            Matcher::Expression(expr) => {
+                // the value is only converted to a JSON tree when the expression references it
+                let value = if expr.references_value() {
+                    filterable_value(record, value_format)
+                } else {
+                    Value::Null
+                };
+
+                expr.evaluate(record, &value)
+            }
+        }
+    }
+}
+
+impl Display for RecordFilter {
+    /// Writes the expression that the [`RecordFilter`] was parsed from to the
+    /// [`std::fmt::Formatter`].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.expression)
+    }
+}
+
+/// Creates the view of a [`Record`] that a JSONPath query is evaluated against. The JSON is built
+/// directly instead of serializing an intermediate struct to keep the cost of filtering each
+/// record low. The view contains the following fields.
+///
+/// * `info` - object containing the topic, partition, offset, key and any decode errors of the
+///   record.
+/// * `headers` - object containing the headers of the record. When a header key is repeated the
+///   last value wins. Values that are not valid UTF-8 are encoded as hex.
+/// * `value` - value of the record as a JSON tree when the [`Format`] is structured and the value
+///   is valid JSON, otherwise as a string.
+fn filterable_json(record: &Record, value_format: Format) -> Value {
+    let mut info = Map::with_capacity(6);
+
+    info.insert(String::from("topic"), json!(record.topic));
+    info.insert(String::from("partition"), json!(record.partition));
+    info.insert(String::from("offset"), json!(record.offset));
+
+    if let Some(key) = record.key.as_ref() {
+        info.insert(String::from("key"), json!(key));
+    }
+
+    if let Some(key_error) = record.key_error.as_ref() {
+        info.insert(String::from("keyDecodeError"), json!(key_error.to_string()));
+    }
+
+    if let Some(value_error) = record.value_error.as_ref() {
+        info.insert(
+            String::from("valueDecodeError"),
+            json!(value_error.to_string()),
+        );
+    }
+
+    let mut headers = Map::with_capacity(record.headers.len());
+
+    for header in record.headers.iter() {
+        let value = match header.value_str() {
+            Some(s) => Some(String::from(s)),
+            None => header.value.as_deref().map(util::to_hex),
+        };
+
+        headers.insert(header.key.clone(), json!(value));
+    }
+
+    json!({
+        "info": info,
+        "headers": headers,
+        "value": filterable_value(record, value_format),
+    })
+}
+
+/// Creates the view of the value of a [`Record`] that a filter is evaluated against. The value is
+/// a JSON tree when the [`Format`] is structured and the value is valid JSON, otherwise a string.
+fn filterable_value(record: &Record, value_format: Format) -> Value {
+    match (value_format, record.value.as_ref()) {
+        (_, None) => Value::Null,
+        (Format::None, Some(v)) => json!(v),
+        (Format::Json | Format::Avro | Format::Protobuf, Some(v)) => {
+            serde_json::from_str(v).unwrap_or_else(|_| json!(v))
+        }
+    }
+}
@@ -1,29 +1,44 @@
 pub mod admin;
 pub mod de;
+pub mod filter;
+pub mod producer;
 pub mod schema;
+pub mod ser;
 
 use crate::kafka::{
     admin::Topic,
     de::{KeyDeserializer, ValueDeserializer},
+    filter::RecordFilter,
 };
 
 use anyhow::Context;
-use chrono::{DateTime, Local};
+use chrono::{DateTime, Local, NaiveDateTime, TimeDelta};
 use derive_builder::Builder;
 use futures::TryStreamExt;
 use rdkafka::{
-    ClientConfig, ClientContext, Message, Offset, Statistics, TopicPartitionList,
+    ClientConfig, ClientContext, Message, Offset, Statistics, TopicPartitionList, bindings,
+    client::Client,
     config::RDKafkaLogLevel,
     consumer::{
         BaseConsumer, CommitMode, Consumer as RDConsumer, ConsumerContext as RDConsumerContext,
         Rebalance, StreamConsumer, stream_consumer::StreamPartitionQueue,
     },
     error::KafkaResult,
-    message::{BorrowedMessage, Headers},
+    message::{BorrowedMessage, Headers, Timestamp},
 };
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, fmt::Display, marker::PhantomData, sync::Arc, time::Duration};
-use tokio::sync::mpsc::Sender;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    marker::PhantomData,
+    str::FromStr,
+    sync::{
+        Arc, Mutex, RwLock,
+        atomic::{AtomicU32, AtomicUsize, Ordering},
+    },
+    time::{Duration, Instant},
+};
+use tokio::{sync::mpsc::Sender, task::JoinHandle};
 
 /// String representation of the [`SeekTo::None`] enum variant. Used in serialization and
 /// deserialization operations.
@@ -33,6 +48,63 @@ const SEEK_TO_NONE: &str = "none";
 /// deserialization operations.
 const SEEK_TO_RESET: &str = "reset";
 
+/// Prefix of the string representation of the [`SeekTo::Tail`] enum variant, e.g. `tail:50`. Used
+/// in serialization and deserialization operations.
+const SEEK_TO_TAIL_PREFIX: &str = "tail:";
+
+/// String representation of the [`SeekPosition::Beginning`] enum variant.
+const SEEK_POSITION_BEGINNING: &str = "beginning";
+
+/// String representation of the [`SeekPosition::End`] enum variant.
+const SEEK_POSITION_END: &str = "end";
+
+/// String representation of the [`ConsumeUntil::None`] enum variant. Used in serialization and
+/// deserialization operations.
+const CONSUME_UNTIL_NONE: &str = "none";
+
+/// String representation of the [`ConsumeUntil::End`] enum variant. Used in serialization and
+/// deserialization operations.
+const CONSUME_UNTIL_END: &str = "end";
+
+/// String representation of the [`CommitStrategy::None`] enum variant. Used in serialization and
+/// deserialization operations.
+const COMMIT_STRATEGY_NONE: &str = "none";
+
+/// String representation of the [`CommitStrategy::Async`] enum variant. Used in serialization and
+/// deserialization operations.
+const COMMIT_STRATEGY_ASYNC: &str = "async";
+
+/// Prefix of the string representation of the [`CommitStrategy::Batch`] enum variant, e.g.
+/// `batch:100`. Used in serialization and deserialization operations.
+const COMMIT_STRATEGY_BATCH_PREFIX: &str = "batch:";
+
+/// String representation of the [`AssignmentMode::Manual`] enum variant. Used in serialization
+/// and deserialization operations.
+const ASSIGNMENT_MODE_MANUAL: &str = "manual";
+
+/// String representation of the [`AssignmentMode::Subscribe`] enum variant. Used in serialization
+/// and deserialization operations.
+const ASSIGNMENT_MODE_SUBSCRIBE: &str = "subscribe";
+
+/// Prefix which denotes that a [`SeekTimestamp`] is relative to the current time, e.g. `-15m`.
+const SEEK_TIMESTAMP_RELATIVE_PREFIX: char = '-';
+
+/// Formats, other than RFC 3339, which are accepted when parsing an absolute [`SeekTimestamp`].
+/// Values parsed using these formats are interpreted in the local timezone.
+const SEEK_TIMESTAMP_LOCAL_FORMATS: [&str; 2] = ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%dT%H:%M"];
+
+/// Maximum amount of time to wait for the Kafka cluster to respond to an offset lookup, e.g.
+/// resolving the offsets for a timestamp or fetching the watermarks of a partition.
+const OFFSET_LOOKUP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Maximum number of bytes of the raw key and value read from the Kafka topic that are kept on a
+/// [`Record`]. Anything past this limit is discarded to bound the memory used by large records.
+pub const MAX_RAW_BYTES: usize = 64 * 1024;
+
+/// Maximum amount of time to wait for a single record to be fetched again from a Kafka topic, e.g.
+/// to recover the bytes that were discarded beyond [`MAX_RAW_BYTES`].
+const RECORD_FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
 /// String representation of the [`Format::None`] enum variant. Used in serialization and
 /// deserialization operations.
 const RECORD_FORMAT_NONE: &str = "none";
@@ -59,6 +131,9 @@ pub enum ConsumerMode {
     Paused,
     /// Consumer is processing records from the topic.
     Processing,
+    /// Consumer has reached the configured bound on all partitions and is no longer processing
+    /// records from the topic.
+    Complete,
 }
 
 /// Enumerates the well-known formats for the data in a Kafka topic.
@@ -126,15 +201,74 @@ impl serde::Serialize for Format {
     }
 }
 
-/// A tuple struct that contains a partition and an offset.
-#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// A single partition of a Kafka topic.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TopicPartition {
+    /// Name of the topic.
+    pub topic: String,
+    /// Partition number.
+    pub partition: i32,
+}
+
+impl TopicPartition {
+    /// Creates a new [`TopicPartition`] for the topic and partition.
+    pub fn new(topic: impl Into<String>, partition: i32) -> Self {
+        Self {
+            topic: topic.into(),
+            partition,
+        }
+    }
+}
+
+impl Display for TopicPartition {
+    /// Writes a string representation of the [`TopicPartition`] value to the
+    /// [`std::fmt::Formatter`].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{}:{}", self.topic, self.partition))
+    }
+}
+
+/// A struct that contains a partition and an offset, optionally qualified by the topic that the
+/// partition belongs to.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct PartitionOffset {
+    /// Topic that the partition belongs to. When not specified the offset applies to the
+    /// partition of the only topic being consumed.
+    topic: Option<String>,
     /// Partition number.
     partition: i32,
     /// Offset on the partition.
     offset: i64,
 }
 
+impl PartitionOffset {
+    /// Determines if the offset applies to the [`TopicPartition`].
+    fn applies_to(&self, tp: &TopicPartition) -> bool {
+        self.partition == tp.partition && self.topic.as_ref().is_none_or(|t| *t == tp.topic)
+    }
+    /// Finds the [`PartitionOffset`] that applies to the [`TopicPartition`]. Fails if any of the
+    /// offsets does not specify its topic while the partitions of more than one topic are being
+    /// consumed, because the partition number alone is then ambiguous.
+    fn find<'a>(
+        partition_offsets: &'a [PartitionOffset],
+        tp: &TopicPartition,
+        partitions: &[TopicPartition],
+    ) -> anyhow::Result<Option<&'a PartitionOffset>> {
+        let multiple_topics = partitions.iter().any(|p| p.topic != tp.topic);
+
+        if multiple_topics && let Some(po) = partition_offsets.iter().find(|po| po.topic.is_none())
+        {
+            anyhow::bail!(
+                "offset {} must specify its topic as topic:partition:offset when consuming more \
+                 than one topic",
+                po
+            );
+        }
+
+        Ok(partition_offsets.iter().find(|po| po.applies_to(tp)))
+    }
+}
+
 impl<T> From<T> for PartitionOffset
 where
     T: AsRef<str>,
@@ -145,7 +279,15 @@ where
     ///
     /// This function will panic if the string is not in the correct format.
     fn from(value: T) -> Self {
-        let mut pair_itr = value.as_ref().split(":");
+        // topic names cannot contain a colon, so a third value is always the leading topic
+        let mut values: Vec<&str> = value.as_ref().split(":").collect();
+
+        let topic = match values.len() {
+            3 => Some(String::from(values.remove(0))),
+            _ => None,
+        };
+
+        let mut pair_itr = values.into_iter();
 
         let partition = pair_itr
             .next()
@@ -157,7 +299,11 @@ where
             .map(|o| o.parse::<i64>().expect("valid offset value"))
             .expect("offset value set");
 
-        Self { partition, offset }
+        Self {
+            topic,
+            partition,
+            offset,
+        }
     }
 }
 
@@ -165,7 +311,12 @@ impl Display for PartitionOffset {
     /// Writes a string representation of the [`PartitionOffset`] value to the
     /// [`std::fmt::Formatter`].
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_fmt(format_args!("{}:{}", self.partition, self.offset))
+        match self.topic.as_ref() {
+            Some(topic) => {
+                f.write_fmt(format_args!("{}:{}:{}", topic, self.partition, self.offset))
+            }
+            None => f.write_fmt(format_args!("{}:{}", self.partition, self.offset)),
+        }
     }
 }
 
@@ -180,6 +331,12 @@ pub enum SeekTo {
     Reset,
     /// Reset offsets to the values for partitions on the topic specified by the user.
     Custom(Vec<PartitionOffset>),
+    /// Reset offsets on ALL partitions for the topic to the earliest offset whose timestamp is
+    /// greater than or equal to the specified [`SeekTimestamp`].
+    Timestamp(SeekTimestamp),
+    /// Reset offsets on ALL partitions for the topic so that the last N records which currently
+    /// exist on each partition are consumed.
+    Tail(u64),
 }
 
 impl<T> From<T> for SeekTo
@@ -191,7 +348,7 @@ where
     /// # Panics
     ///
     /// This function will panic if the string is not in the correct format for parsing the
-    /// partition and offset pairs.
+    /// partition and offset pairs, a relative timestamp or the tail record count.
     fn from(value: T) -> Self {
         let s = value.as_ref();
 
@@ -199,6 +356,13 @@ where
             Self::None
         } else if s.eq_ignore_ascii_case(SEEK_TO_RESET) {
             Self::Reset
+        } else if let Some(count) = s.strip_prefix(SEEK_TO_TAIL_PREFIX) {
+            let count = count.trim().parse().expect("valid tail record count");
+            Self::Tail(count)
+        } else if s.starts_with(SEEK_TIMESTAMP_RELATIVE_PREFIX) {
+            Self::Timestamp(SeekTimestamp::parse(s).expect("valid relative timestamp"))
+        } else if let Some(timestamp) = SeekTimestamp::parse(s) {
+            Self::Timestamp(timestamp)
         } else {
             let partitions = s.split(",").map(Into::into).collect();
             Self::Custom(partitions)
@@ -220,6 +384,8 @@ impl Display for SeekTo {
 
                 f.write_str(&csv)
             }
+            Self::Timestamp(timestamp) => timestamp.fmt(f),
+            Self::Tail(count) => f.write_fmt(format_args!("{}{}", SEEK_TO_TAIL_PREFIX, count)),
         }
     }
 }
@@ -245,187 +411,851 @@ impl serde::Serialize for SeekTo {
     }
 }
 
-/// A simple [`serde::de::Visitor`] implementation that is capable of deserializing any value as
-/// long as it has a [`From`] implementation for a [`str`] reference.
-#[derive(Debug, Default)]
-struct FromStrVisitor<T>
-where
-    T: for<'a> From<&'a str>,
-{
-    _data: PhantomData<T>,
+/// Enumerates the available ways which the user can configure the point on the partitions that
+/// make up the topic where the consumer should stop consuming records.
+#[derive(Clone, Debug, Default)]
+pub enum ConsumeUntil {
+    /// Consume records indefinitely.
+    #[default]
+    None,
+    /// Stop consuming records on ALL partitions once the high watermark of the partition at the
+    /// time the consumer was started has been reached.
+    End,
+    /// Stop consuming records on the partitions specified by the user once the offset has been
+    /// consumed. Any partition not specified is consumed indefinitely.
+    Custom(Vec<PartitionOffset>),
+    /// Stop consuming records on ALL partitions once a record with a timestamp greater than or
+    /// equal to the specified [`SeekTimestamp`] is reached.
+    Timestamp(SeekTimestamp),
 }
 
-impl<'de, T> serde::de::Visitor<'de> for FromStrVisitor<T>
+impl<T> From<T> for ConsumeUntil
 where
-    T: for<'a> From<&'a str>,
+    T: AsRef<str>,
 {
-    type Value = T;
+    /// Converts the value to the corresponding [`ConsumeUntil`].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the string is not in the correct format for parsing the
+    /// partition and offset pairs or a relative timestamp.
+    fn from(value: T) -> Self {
+        let s = value.as_ref();
 
-    /// Format a message stating what data this [`serde::de::Visitor`] expects to receive.
-    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-        formatter.write_str("a valid string representation")
+        if s.is_empty() || s.eq_ignore_ascii_case(CONSUME_UNTIL_NONE) {
+            Self::None
+        } else if s.eq_ignore_ascii_case(CONSUME_UNTIL_END) {
+            Self::End
+        } else if s.starts_with(SEEK_TIMESTAMP_RELATIVE_PREFIX) {
+            Self::Timestamp(SeekTimestamp::parse(s).expect("valid relative timestamp"))
+        } else if let Some(timestamp) = SeekTimestamp::parse(s) {
+            Self::Timestamp(timestamp)
+        } else {
+            let partitions = s.split(",").map(Into::into).collect();
+            Self::Custom(partitions)
+        }
     }
-    /// Attempts to convert the [`str`] reference to a valid `T` based on its contents.
-    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+}
+
+impl Display for ConsumeUntil {
+    /// Writes a string representation of the [`ConsumeUntil`] value to the
+    /// [`std::fmt::Formatter`].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => f.write_str(CONSUME_UNTIL_NONE),
+            Self::End => f.write_str(CONSUME_UNTIL_END),
+            Self::Custom(partition_offsets) => {
+                let po_strs: Vec<String> =
+                    partition_offsets.iter().map(ToString::to_string).collect();
+
+                let csv = po_strs.join(",");
+
+                f.write_str(&csv)
+            }
+            Self::Timestamp(timestamp) => timestamp.fmt(f),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ConsumeUntil {
+    /// Deserialize this value into the given [`serde::Deserializer`].
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
-        E: serde::de::Error,
+        D: serde::Deserializer<'de>,
     {
-        Ok(v.into())
+        deserializer.deserialize_str(FromStrVisitor::default())
     }
 }
 
-/// Contains the data in the record consumed from a Kafka topic.
-#[derive(Clone, Debug, Default, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Record {
-    /// Name of the topic that the record was consumed from.
-    pub topic: String,
-    /// Partition number the record was assigned in the topic.
-    pub partition: i32,
-    /// Offset of the record in the topic.
-    pub offset: i64,
-    /// Partition key for the record if one was set.
-    pub key: Option<String>,
-    /// Contains any headers from the Kafka record.
-    pub headers: HashMap<String, String>,
-    /// Value of the Kafka record, if one exists.
-    pub value: Option<String>,
-    /// Local timestamp representing when the event was created.
-    pub timestamp: DateTime<Local>,
+impl serde::Serialize for ConsumeUntil {
+    /// Serialize this value into the given [`serde::Serializer`].
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let str = self.to_string();
+        serializer.serialize_str(&str)
+    }
 }
 
-impl Record {
-    /// Determines if this [`Record`] matches the specified JSONPath filter.
-    fn matches(&self, filter: impl AsRef<str>) -> bool {
-        let filterable_record = FilterableRecord::from(self);
+/// The point on a single partition where the consumer should stop consuming records.
+#[derive(Clone, Copy, Debug, Default)]
+struct PartitionBound {
+    /// Offset, exclusive, at which consumption of the partition stops.
+    end_offset: Option<i64>,
+    /// Timestamp in milliseconds since the Unix epoch, exclusive, at which consumption of the
+    /// partition stops.
+    end_timestamp: Option<i64>,
+}
 
-        let json_value =
-            serde_json::to_value(filterable_record).expect("FilterableRecord serializes to JSON");
+impl PartitionBound {
+    /// Determines if the bound limits consumption of the partition at all.
+    fn is_bounded(&self) -> bool {
+        self.end_offset.is_some() || self.end_timestamp.is_some()
+    }
+    /// Determines if the [`BorrowedMessage`] is past the bound and should not be consumed.
+    fn is_exceeded_by(&self, msg: &BorrowedMessage<'_>) -> bool {
+        let offset_exceeded = self.end_offset.is_some_and(|end| msg.offset() >= end);
 
-        let json_path =
-            serde_json_path::JsonPath::parse(filter.as_ref()).expect("valid JSONPath expression");
+        let timestamp_exceeded = self
+            .end_timestamp
+            .zip(msg.timestamp().to_millis())
+            .is_some_and(|(end, ts)| ts >= end);
 
-        !json_path.query(&json_value).is_empty()
+        offset_exceeded || timestamp_exceeded
+    }
+    /// Determines if the [`BorrowedMessage`] is the last one that should be consumed before the
+    /// bound is reached.
+    fn is_reached_by(&self, msg: &BorrowedMessage<'_>) -> bool {
+        self.end_offset.is_some_and(|end| msg.offset() + 1 >= end)
     }
 }
 
-/// The [`ConsumerContext`] is a struct that is used to implement a custom Kafka consumer context
-/// to hook into key events in the lifecycle of a Kafka consumer.
-#[derive(Debug)]
-struct ConsumerContext {
-    /// [`Sender`] that is used to publish the [`ConsumerEvent::Statistics`] event when the latest
-    /// statistics are received from the librdkafka library.
-    consumer_tx: Sender<ConsumerEvent>,
+/// Enumerates the available strategies the Kafka consumer can use to commit the offsets of the
+/// records it has consumed back to the consumer group.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum CommitStrategy {
+    /// Never commit offsets. The consumer is read-only and does not affect the offsets of the
+    /// consumer group.
+    #[default]
+    None,
+    /// Asynchronously commit the offset of each record after it is consumed.
+    Async,
+    /// Asynchronously commit the offsets of the records consumed so far once the specified number
+    /// of records have been consumed from a partition, or from all assigned partitions when the
+    /// consumer is subscribed to the topics.
+    Batch(u32),
 }
 
-impl ConsumerContext {
-    /// Creates a new [`ConsumerContext`] which uses the specified [`Sender`] to publish events to
-    /// the consumer channel.
-    fn new(consumer_tx: Sender<ConsumerEvent>) -> Self {
-        Self { consumer_tx }
+impl<T> From<T> for CommitStrategy
+where
+    T: AsRef<str>,
+{
+    /// Converts the value to the corresponding [`CommitStrategy`].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the batch size is not a valid positive number.
+    fn from(value: T) -> Self {
+        let s = value.as_ref().trim();
+
+        if s.eq_ignore_ascii_case(COMMIT_STRATEGY_ASYNC) {
+            Self::Async
+        } else if let Some(size) = s.strip_prefix(COMMIT_STRATEGY_BATCH_PREFIX) {
+            let size = size
+                .trim()
+                .parse::<u32>()
+                .ok()
+                .filter(|size| *size > 0)
+                .expect("valid commit batch size");
+
+            Self::Batch(size)
+        } else {
+            Self::None
+        }
     }
 }
 
-impl ClientContext for ConsumerContext {
-    /// Receives log lines from the underlying librdkafka library.
-    fn log(&self, level: RDKafkaLogLevel, fac: &str, log_message: &str) {
-        match level {
-            RDKafkaLogLevel::Emerg
-            | RDKafkaLogLevel::Alert
-            | RDKafkaLogLevel::Critical
-            | RDKafkaLogLevel::Error => {
-                tracing::error!("{} {}", fac, log_message);
-            }
-            RDKafkaLogLevel::Warning => tracing::warn!("{} {}", fac, log_message),
-            RDKafkaLogLevel::Notice | RDKafkaLogLevel::Info => {
-                tracing::info!("{} {}", fac, log_message);
+impl Display for CommitStrategy {
+    /// Writes a string representation of the [`CommitStrategy`] value to the
+    /// [`std::fmt::Formatter`].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => f.write_str(COMMIT_STRATEGY_NONE),
+            Self::Async => f.write_str(COMMIT_STRATEGY_ASYNC),
+            Self::Batch(size) => {
+                f.write_fmt(format_args!("{}{}", COMMIT_STRATEGY_BATCH_PREFIX, size))
             }
-            RDKafkaLogLevel::Debug => tracing::debug!("{} {}", fac, log_message),
         }
     }
-    /// Receives the decoded statistics from the librdkafka client at the configured interval.
-    fn stats(&self, statistics: Statistics) {
-        let boxed_stats = statistics.into();
-
-        let tx = self.consumer_tx.clone();
+}
 
-        tokio::spawn(async move {
-            if let Err(e) = tx.send(ConsumerEvent::Statistics(boxed_stats)).await {
-                tracing::error!(
-                    "failed to send statistics over event consumer channel: {}",
-                    e
-                );
-            }
-        });
+impl<'de> serde::Deserialize<'de> for CommitStrategy {
+    /// Deserialize this value into the given [`serde::Deserializer`].
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(FromStrVisitor::default())
     }
 }
 
-impl RDConsumerContext for ConsumerContext {
-    /// Hook invoked right before the consumer begins rebalancing.
-    fn pre_rebalance(&self, _base_consumer: &BaseConsumer<Self>, rebalance: &Rebalance<'_>) {
-        tracing::info!("rebalance initiated: {:?}", rebalance);
-    }
-    /// Hook invoked after the consumer rebalancing has been completed.
-    fn post_rebalance(&self, _base_consumer: &BaseConsumer<Self>, rebalance: &Rebalance) {
-        match rebalance {
-            Rebalance::Assign(tpl) => {
-                tpl.elements().iter().for_each(|e| {
-                    tracing::info!("assigned partition {} on {}", e.partition(), e.topic())
-                });
-            }
-            Rebalance::Revoke(tpl) => {
-                tpl.elements().iter().for_each(|e| {
-                    tracing::info!("revoked partition {} on {}", e.partition(), e.topic())
-                });
-            }
-            Rebalance::Error(e) => tracing::error!("error during rebalance: {}", e),
-        }
-    }
-    /// Hook invoked after the consumer has attempted to commit offsets.
-    fn commit_callback(&self, result: KafkaResult<()>, offsets: &TopicPartitionList) {
-        match result {
-            Ok(_) => {
-                if tracing::event_enabled!(tracing::Level::DEBUG) {
-                    offsets.elements().iter().for_each(|e| {
-                        tracing::debug!(
-                            "committed offset {:?} on partition {} in topic {}",
-                            e.offset(),
-                            e.partition(),
-                            e.topic()
-                        )
-                    });
-                }
-            }
-            Err(e) => {
-                tracing::error!("error committing consumer offsets: {}", e);
-            }
-        }
+impl serde::Serialize for CommitStrategy {
+    /// Serialize this value into the given [`serde::Serializer`].
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let str = self.to_string();
+        serializer.serialize_str(&str)
     }
 }
 
-/// Enumeration of the states of a [`Record`] that was consumed from the Kafka topic.
-#[derive(Clone, Debug)]
-pub enum ConsumerEvent {
-    /// A [`Record`] was consumed, and it should be displayed to the user.
-    Received(Record),
-    /// A [`Record`] was consumed, but it does not match the configured JSONPath filter.
-    Filtered(Record),
-    /// Updated [`Statistics`] were emitted by the Kafka consumer.
-    Statistics(Box<Statistics>),
+/// Enumerates the ways that partitions of the topics can be assigned to the Kafka consumer.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum AssignmentMode {
+    /// Partitions are explicitly assigned to the consumer by the application.
+    #[default]
+    Manual,
+    /// The consumer subscribes to the topics and joins the consumer group, letting the group
+    /// coordinator assign partitions to it.
+    Subscribe,
 }
 
-#[derive(Builder, Clone)]
-pub struct ConsumeTopicConfig {
-    /// Name of the Kafka topic to consume messages from.
-    topic: String,
-    /// Partitions on the topic that the consumer should be assigned.
-    partitions: Vec<i32>,
-    /// Drives the partitions offsets the Kafka consumer seeks to before starting to consume
-    /// records.
-    seek_to: SeekTo,
-    /// Any filter to apply to the record.
-    filter: Option<String>,
-}
+impl<T> From<T> for AssignmentMode
+where
+    T: AsRef<str>,
+{
+    /// Converts the value to the corresponding [`AssignmentMode`].
+    fn from(value: T) -> Self {
+        let s = value.as_ref().trim();
+
+        if s.eq_ignore_ascii_case(ASSIGNMENT_MODE_SUBSCRIBE) {
+            Self::Subscribe
+        } else {
+            Self::Manual
+        }
+    }
+}
+
+impl Display for AssignmentMode {
+    /// Writes a string representation of the [`AssignmentMode`] value to the
+    /// [`std::fmt::Formatter`].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Manual => ASSIGNMENT_MODE_MANUAL,
+            Self::Subscribe => ASSIGNMENT_MODE_SUBSCRIBE,
+        };
+
+        f.write_str(s)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for AssignmentMode {
+    /// Deserialize this value into the given [`serde::Deserializer`].
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(FromStrVisitor::default())
+    }
+}
+
+impl serde::Serialize for AssignmentMode {
+    /// Serialize this value into the given [`serde::Serializer`].
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let str = self.to_string();
+        serializer.serialize_str(&str)
+    }
+}
+
+/// A point in time that the Kafka consumer can seek to on the partitions that make up the topic.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SeekTimestamp {
+    /// An absolute point in time, e.g. `2025-06-01T14:05:00Z` or `2025-06-01T14:05` in the local
+    /// timezone.
+    Absolute(DateTime<Local>),
+    /// A point in time relative to when the consumer is started, e.g. `-15m`. The contained
+    /// [`TimeDelta`] is the amount of time to go back from the start time.
+    Relative(TimeDelta),
+}
+
+impl SeekTimestamp {
+    /// Attempts to parse a [`SeekTimestamp`] from the string. Relative timestamps are a negative
+    /// whole number followed by a unit of `s`, `m`, `h` or `d`. Absolute timestamps are in ISO-8601
+    /// format with an optional timezone offset. Returns [`None`] if the string is not a timestamp.
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+
+        if let Some(relative) = value.strip_prefix(SEEK_TIMESTAMP_RELATIVE_PREFIX) {
+            let unit_idx = relative.len().checked_sub(1)?;
+
+            let (amount, unit) = relative.split_at_checked(unit_idx)?;
+
+            let amount = amount.parse::<i64>().ok().filter(|a| *a >= 0)?;
+
+            let delta = match unit {
+                "s" => TimeDelta::try_seconds(amount),
+                "m" => TimeDelta::try_minutes(amount),
+                "h" => TimeDelta::try_hours(amount),
+                "d" => TimeDelta::try_days(amount),
+                _ => None,
+            }?;
+
+            return Some(Self::Relative(delta));
+        }
+
+        if let Ok(date_time) = DateTime::parse_from_rfc3339(value) {
+            return Some(Self::Absolute(date_time.with_timezone(&Local)));
+        }
+
+        SEEK_TIMESTAMP_LOCAL_FORMATS
+            .iter()
+            .find_map(|fmt| NaiveDateTime::parse_from_str(value, fmt).ok())
+            .and_then(|naive| naive.and_local_timezone(Local).earliest())
+            .map(Self::Absolute)
+    }
+    /// Resolves the [`SeekTimestamp`] to the number of milliseconds since the Unix epoch. Relative
+    /// timestamps are resolved against the current time.
+    fn resolve_millis(&self) -> i64 {
+        match self {
+            Self::Absolute(date_time) => date_time.timestamp_millis(),
+            Self::Relative(delta) => (Local::now() - *delta).timestamp_millis(),
+        }
+    }
+}
+
+impl Display for SeekTimestamp {
+    /// Writes a string representation of the [`SeekTimestamp`] value to the
+    /// [`std::fmt::Formatter`].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Absolute(date_time) => f.write_str(&date_time.to_rfc3339()),
+            Self::Relative(delta) => {
+                let secs = delta.num_seconds();
+
+                let (amount, unit) = if secs != 0 && secs % 86_400 == 0 {
+                    (secs / 86_400, "d")
+                } else if secs != 0 && secs % 3_600 == 0 {
+                    (secs / 3_600, "h")
+                } else if secs != 0 && secs % 60 == 0 {
+                    (secs / 60, "m")
+                } else {
+                    (secs, "s")
+                };
+
+                f.write_fmt(format_args!(
+                    "{}{}{}",
+                    SEEK_TIMESTAMP_RELATIVE_PREFIX, amount, unit
+                ))
+            }
+        }
+    }
+}
+
+/// A position on a partition that the user can reposition a running Kafka consumer to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SeekPosition {
+    /// Earliest offset available on the partition.
+    Beginning,
+    /// Current end of the partition so that only new records are consumed.
+    End,
+    /// Specific offset on the partition.
+    Offset(i64),
+    /// First record produced at or after the point in time.
+    Timestamp(SeekTimestamp),
+}
+
+impl FromStr for SeekPosition {
+    type Err = anyhow::Error;
+
+    /// Parses a [`SeekPosition`] from a string. Accepts `beginning`, `end`, an offset or any
+    /// timestamp supported by [`SeekTimestamp::parse`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if s.eq_ignore_ascii_case(SEEK_POSITION_BEGINNING) {
+            Ok(Self::Beginning)
+        } else if s.eq_ignore_ascii_case(SEEK_POSITION_END) {
+            Ok(Self::End)
+        } else if let Ok(offset) = s.parse::<i64>()
+            && offset >= 0
+        {
+            Ok(Self::Offset(offset))
+        } else if let Some(timestamp) = SeekTimestamp::parse(s) {
+            Ok(Self::Timestamp(timestamp))
+        } else {
+            anyhow::bail!("invalid seek position: {}", s)
+        }
+    }
+}
+
+impl Display for SeekPosition {
+    /// Writes a string representation of the [`SeekPosition`] value to the
+    /// [`std::fmt::Formatter`].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Beginning => f.write_str(SEEK_POSITION_BEGINNING),
+            Self::End => f.write_str(SEEK_POSITION_END),
+            Self::Offset(offset) => offset.fmt(f),
+            Self::Timestamp(timestamp) => timestamp.fmt(f),
+        }
+    }
+}
+
+/// A simple [`serde::de::Visitor`] implementation that is capable of deserializing any value as
+/// long as it has a [`From`] implementation for a [`str`] reference.
+#[derive(Debug, Default)]
+struct FromStrVisitor<T>
+where
+    T: for<'a> From<&'a str>,
+{
+    _data: PhantomData<T>,
+}
+
+impl<'de, T> serde::de::Visitor<'de> for FromStrVisitor<T>
+where
+    T: for<'a> From<&'a str>,
+{
+    type Value = T;
+
+    /// Format a message stating what data this [`serde::de::Visitor`] expects to receive.
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a valid string representation")
+    }
+    /// Attempts to convert the [`str`] reference to a valid `T` based on its contents.
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(v.into())
+    }
+}
+
+/// Key and value of a record exactly as they are stored in the Kafka topic.
+pub type RecordData = (Option<Vec<u8>>, Option<Vec<u8>>);
+
+/// Raw bytes of the key or value of a record exactly as they were read from the Kafka topic.
+#[derive(Clone, Debug, Default)]
+pub struct RawBytes {
+    /// Bytes read from the topic, truncated to at most [`MAX_RAW_BYTES`].
+    pub data: Vec<u8>,
+    /// Number of bytes read from the topic before truncation.
+    pub len: usize,
+}
+
+impl RawBytes {
+    /// Creates a new [`RawBytes`] from the data read from the topic, keeping at most
+    /// [`MAX_RAW_BYTES`] of it.
+    fn new(data: &[u8]) -> Self {
+        Self {
+            data: data[..data.len().min(MAX_RAW_BYTES)].to_vec(),
+            len: data.len(),
+        }
+    }
+    /// Determines if some of the bytes read from the topic were discarded.
+    pub fn is_truncated(&self) -> bool {
+        self.data.len() < self.len
+    }
+}
+
+/// Details of a failure to deserialize the key or value of a record read from a Kafka topic.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodeError {
+    /// Message describing why the data could not be deserialized.
+    pub message: String,
+    /// [`Format`] that the data was expected to be in.
+    pub format: Format,
+    /// First byte of the data, if any.
+    pub magic_byte: Option<u8>,
+    /// Id of the schema encoded in the data when it was produced with a schema registry
+    /// serializer.
+    pub schema_id: Option<u32>,
+}
+
+impl DecodeError {
+    /// Creates a new [`DecodeError`] from the error returned when deserializing the data, reading
+    /// the magic byte and any schema id from the data itself.
+    fn new(err: &anyhow::Error, format: Format, data: &[u8]) -> Self {
+        Self {
+            message: format!("{:#}", err),
+            format,
+            magic_byte: data.first().copied(),
+            schema_id: schema_id(data),
+        }
+    }
+}
+
+impl Display for DecodeError {
+    /// Writes a string representation of the [`DecodeError`] value to the
+    /// [`std::fmt::Formatter`].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({} format", self.message, self.format)?;
+
+        if let Some(magic_byte) = self.magic_byte {
+            write!(f, ", magic byte 0x{:02x}", magic_byte)?;
+        }
+
+        if let Some(schema_id) = self.schema_id {
+            write!(f, ", schema id {}", schema_id)?;
+        }
+
+        f.write_str(")")
+    }
+}
+
+/// Reads the id of the schema from the wire prefix of the data. Data produced with a schema
+/// registry serializer starts with a zero magic byte followed by the id of the schema as a
+/// big-endian 32-bit integer. Returns [`None`] if the data does not start with the prefix.
+fn schema_id(data: &[u8]) -> Option<u32> {
+    match (data.first(), data.get(1..5)) {
+        (Some(0), Some(id)) => id.try_into().ok().map(u32::from_be_bytes),
+        _ => None,
+    }
+}
+
+/// Reads the leader epoch of the partition at the time the [`BorrowedMessage`] was appended to
+/// the log. Returns [`None`] if the broker did not provide it.
+fn leader_epoch(msg: &BorrowedMessage<'_>) -> Option<i32> {
+    // SAFETY: the native message pointer is valid for as long as the message is borrowed.
+    let leader_epoch = unsafe { bindings::rd_kafka_message_leader_epoch(msg.ptr()) };
+
+    Some(leader_epoch).filter(|e| *e >= 0)
+}
+
+/// Enumerates the types of timestamp that can be set on a record in a Kafka topic.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum TimestampType {
+    /// No timestamp is available for the record.
+    #[default]
+    NotAvailable,
+    /// Timestamp was set by the producer when the record was created.
+    CreateTime,
+    /// Timestamp was set by the broker when the record was appended to the log.
+    LogAppendTime,
+}
+
+impl Display for TimestampType {
+    /// Writes a string representation of the [`TimestampType`] value to the
+    /// [`std::fmt::Formatter`].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::NotAvailable => "NotAvailable",
+            Self::CreateTime => "CreateTime",
+            Self::LogAppendTime => "LogAppendTime",
+        };
+
+        f.write_str(s)
+    }
+}
+
+impl From<Timestamp> for TimestampType {
+    /// Converts the [`Timestamp`] of a Kafka message to the corresponding [`TimestampType`].
+    fn from(value: Timestamp) -> Self {
+        match value {
+            Timestamp::NotAvailable => Self::NotAvailable,
+            Timestamp::CreateTime(_) => Self::CreateTime,
+            Timestamp::LogAppendTime(_) => Self::LogAppendTime,
+        }
+    }
+}
+
+impl serde::Serialize for TimestampType {
+    /// Serialize this value into the given [`serde::Serializer`].
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let str = self.to_string();
+        serializer.serialize_str(&str)
+    }
+}
+
+/// A single header of a record consumed from a Kafka topic.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct RecordHeader {
+    /// Key of the header.
+    pub key: String,
+    /// Value of the header exactly as it was read from the topic, if one was set.
+    pub value: Option<Vec<u8>>,
+}
+
+impl RecordHeader {
+    /// Returns the value of the header as a string if one was set and it is valid UTF-8.
+    pub fn value_str(&self) -> Option<&str> {
+        self.value
+            .as_deref()
+            .and_then(|v| std::str::from_utf8(v).ok())
+    }
+}
+
+/// Contains the data in the record consumed from a Kafka topic.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Record {
+    /// Name of the topic that the record was consumed from.
+    pub topic: String,
+    /// Partition number the record was assigned in the topic.
+    pub partition: i32,
+    /// Offset of the record in the topic.
+    pub offset: i64,
+    /// Partition key for the record if one was set.
+    pub key: Option<String>,
+    /// Contains any headers from the Kafka record in the order they were set, including any
+    /// duplicate keys.
+    pub headers: Vec<RecordHeader>,
+    /// Value of the Kafka record, if one exists.
+    pub value: Option<String>,
+    /// Raw bytes of the partition key as read from the topic, if one was set.
+    #[serde(skip)]
+    pub raw_key: Option<RawBytes>,
+    /// Raw bytes of the value as read from the topic, if one exists.
+    #[serde(skip)]
+    pub raw_value: Option<RawBytes>,
+    /// Error encountered when deserializing the partition key, if any.
+    pub key_error: Option<DecodeError>,
+    /// Error encountered when deserializing the value, if any.
+    pub value_error: Option<DecodeError>,
+    /// Size in bytes of the partition key as read from the topic, if one was set.
+    pub key_size: Option<usize>,
+    /// Size in bytes of the value as read from the topic, if one exists.
+    pub value_size: Option<usize>,
+    /// Id of the schema the partition key was produced with, if it has a schema registry prefix.
+    pub key_schema_id: Option<u32>,
+    /// Id of the schema the value was produced with, if it has a schema registry prefix.
+    pub value_schema_id: Option<u32>,
+    /// Leader epoch of the partition when the record was appended to the log, if known.
+    pub leader_epoch: Option<i32>,
+    /// Local timestamp representing when the event was created.
+    pub timestamp: DateTime<Local>,
+    /// Type of the timestamp set on the record.
+    pub timestamp_type: TimestampType,
+}
+
+impl Record {
+    /// Determines if either the key or the value of this [`Record`] failed to deserialize.
+    pub fn has_decode_error(&self) -> bool {
+        self.key_error.is_some() || self.value_error.is_some()
+    }
+}
+
+/// The [`ConsumerContext`] is a struct that is used to implement a custom Kafka consumer context
+/// to hook into key events in the lifecycle of a Kafka consumer.
+#[derive(Debug)]
+struct ConsumerContext {
+    /// [`Sender`] that is used to publish the [`ConsumerEvent::Statistics`] event when the latest
+    /// statistics are received from the librdkafka library.
+    consumer_tx: Sender<ConsumerEvent>,
+}
+
+impl ConsumerContext {
+    /// Creates a new [`ConsumerContext`] which uses the specified [`Sender`] to publish events to
+    /// the consumer channel.
+    fn new(consumer_tx: Sender<ConsumerEvent>) -> Self {
+        Self { consumer_tx }
+    }
+}
+
+impl ClientContext for ConsumerContext {
+    /// Receives log lines from the underlying librdkafka library.
+    fn log(&self, level: RDKafkaLogLevel, fac: &str, log_message: &str) {
+        match level {
+            RDKafkaLogLevel::Emerg
+            | RDKafkaLogLevel::Alert
+            | RDKafkaLogLevel::Critical
+            | RDKafkaLogLevel::Error => {
+                tracing::error!("{} {}", fac, log_message);
+            }
+            RDKafkaLogLevel::Warning => tracing::warn!("{} {}", fac, log_message),
+            RDKafkaLogLevel::Notice | RDKafkaLogLevel::Info => {
+                tracing::info!("{} {}", fac, log_message);
+            }
+            RDKafkaLogLevel::Debug => tracing::debug!("{} {}", fac, log_message),
+        }
+    }
+    /// Receives the decoded statistics from the librdkafka client at the configured interval.
+    fn stats(&self, statistics: Statistics) {
+        let boxed_stats = statistics.into();
+
+        let tx = self.consumer_tx.clone();
+
+        tokio::spawn(async move {
+            if let Err(e) = tx.send(ConsumerEvent::Statistics(boxed_stats)).await {
+                tracing::error!(
+                    "failed to send statistics over event consumer channel: {}",
+                    e
+                );
+            }
+        });
+    }
+}
+
+impl RDConsumerContext for ConsumerContext {
+    /// Hook invoked right before the consumer begins rebalancing.
+    fn pre_rebalance(&self, _base_consumer: &BaseConsumer<Self>, rebalance: &Rebalance<'_>) {
+        tracing::info!("rebalance initiated: {:?}", rebalance);
+    }
+    /// Hook invoked after the consumer rebalancing has been completed. Publishes the
+    /// [`ConsumerEvent::Rebalanced`] event so that the application can display the partitions
+    /// that are currently assigned to the consumer.
+    fn post_rebalance(&self, base_consumer: &BaseConsumer<Self>, rebalance: &Rebalance) {
+        let mut group_rebalance = GroupRebalance {
+            generation: group_generation_id(base_consumer.client()),
+            ..Default::default()
+        };
+
+        match rebalance {
+            Rebalance::Assign(tpl) => {
+                tpl.elements().iter().for_each(|e| {
+                    tracing::info!("assigned partition {} on {}", e.partition(), e.topic())
+                });
+
+                group_rebalance.assigned = to_topic_partitions(tpl);
+            }
+            Rebalance::Revoke(tpl) => {
+                tpl.elements().iter().for_each(|e| {
+                    tracing::info!("revoked partition {} on {}", e.partition(), e.topic())
+                });
+
+                group_rebalance.revoked = to_topic_partitions(tpl);
+            }
+            Rebalance::Error(e) => {
+                tracing::error!("error during rebalance: {}", e);
+                return;
+            }
+        }
+
+        match base_consumer.assignment() {
+            Ok(assignment) => group_rebalance.assignment = to_topic_partitions(&assignment),
+            Err(e) => tracing::error!("failed to get consumer partition assignments: {}", e),
+        }
+
+        let tx = self.consumer_tx.clone();
+
+        tokio::spawn(async move {
+            if let Err(e) = tx.send(ConsumerEvent::Rebalanced(group_rebalance)).await {
+                tracing::error!(
+                    "failed to send rebalance over event consumer channel: {}",
+                    e
+                );
+            }
+        });
+    }
+    /// Hook invoked after the consumer has attempted to commit offsets.
+    fn commit_callback(&self, result: KafkaResult<()>, offsets: &TopicPartitionList) {
+        match result {
+            Ok(_) => {
+                if tracing::event_enabled!(tracing::Level::DEBUG) {
+                    offsets.elements().iter().for_each(|e| {
+                        tracing::debug!(
+                            "committed offset {:?} on partition {} in topic {}",
+                            e.offset(),
+                            e.partition(),
+                            e.topic()
+                        )
+                    });
+                }
+            }
+            Err(e) => {
+                tracing::error!("error committing consumer offsets: {}", e);
+            }
+        }
+    }
+}
+
+/// Converts the elements of the [`TopicPartitionList`] to a sorted list of [`TopicPartition`]s.
+fn to_topic_partitions(tpl: &TopicPartitionList) -> Vec<TopicPartition> {
+    let mut partitions: Vec<TopicPartition> = tpl
+        .elements()
+        .iter()
+        .map(|e| TopicPartition::new(e.topic(), e.partition()))
+        .collect();
+
+    partitions.sort();
+
+    partitions
+}
+
+/// Retrieves the generation of the consumer group that the [`Client`] is currently a member of.
+/// Returns [`None`] if the client is not a member of a consumer group.
+fn group_generation_id<C: ClientContext>(client: &Client<C>) -> Option<i32> {
+    // SAFETY: the native client pointer is valid for as long as the client is borrowed and the
+    // group metadata allocated by librdkafka is destroyed once the generation has been read.
+    let generation_id = unsafe {
+        let metadata = bindings::rd_kafka_consumer_group_metadata(client.native_ptr());
+
+        if metadata.is_null() {
+            return None;
+        }
+
+        let generation_id = bindings::rd_kafka_consumer_group_metadata_generation_id(metadata);
+
+        bindings::rd_kafka_consumer_group_metadata_destroy(metadata);
+
+        generation_id
+    };
+
+    Some(generation_id).filter(|g| *g >= 0)
+}
+
+/// Details of a rebalance of the consumer group that changed the partitions assigned to the
+/// Kafka consumer.
+#[derive(Clone, Debug, Default)]
+pub struct GroupRebalance {
+    /// Generation of the consumer group once the rebalance completed, if it could be determined.
+    pub generation: Option<i32>,
+    /// Partitions that were assigned to the consumer by the rebalance.
+    pub assigned: Vec<TopicPartition>,
+    /// Partitions that were revoked from the consumer by the rebalance.
+    pub revoked: Vec<TopicPartition>,
+    /// All partitions assigned to the consumer once the rebalance completed.
+    pub assignment: Vec<TopicPartition>,
+}
+
+/// Enumeration of the states of a [`Record`] that was consumed from the Kafka topic.
+#[derive(Clone, Debug)]
+pub enum ConsumerEvent {
+    /// A [`Record`] was consumed, and it should be displayed to the user.
+    Received(Box<Record>),
+    /// A [`Record`] was consumed, but it does not match the current filter.
+    Filtered(Box<Record>),
+    /// Updated [`Statistics`] were emitted by the Kafka consumer.
+    Statistics(Box<Statistics>),
+    /// The configured bound has been reached on all partitions assigned to the Kafka consumer.
+    Complete,
+    /// The consumer group was rebalanced and the partitions assigned to the Kafka consumer
+    /// changed.
+    Rebalanced(GroupRebalance),
+}
+
+#[derive(Builder, Clone)]
+pub struct ConsumeTopicConfig {
+    /// Drives whether partitions are assigned to the Kafka consumer by the application or by the
+    /// consumer group coordinator.
+    assignment_mode: AssignmentMode,
+    /// Names of, or `^` prefixed regular expressions matching, the Kafka topics that the consumer
+    /// subscribes to when using the [`AssignmentMode::Subscribe`] mode.
+    #[builder(default)]
+    topics: Vec<String>,
+    /// Partitions of the Kafka topics that the consumer should be assigned when using the
+    /// [`AssignmentMode::Manual`] mode.
+    #[builder(default)]
+    partitions: Vec<TopicPartition>,
+    /// Drives the partitions offsets the Kafka consumer seeks to before starting to consume
+    /// records.
+    seek_to: SeekTo,
+    /// Any filter to apply to the record when the consumer is started. The filter can be replaced
+    /// while the consumer is running using [`Consumer::set_filter`].
+    filter: Option<RecordFilter>,
+    /// Drives if and how the Kafka consumer commits the offsets of consumed records.
+    commit_strategy: CommitStrategy,
+    /// Drives the point on the partitions where the Kafka consumer stops consuming records.
+    until: ConsumeUntil,
+}
 
 impl ConsumeTopicConfig {
     /// Creates a new default [`ConsumeTopicConfigBuilder`] which is used to construct a
@@ -449,157 +1279,663 @@ pub struct ConsumerConfig {
     consumer_tx: Sender<ConsumerEvent>,
 }
 
-impl ConsumerConfig {
-    /// Creates a new default [`ConsumerConfigBuilder`] which is used to construct a
-    /// [`ConsumerConfig`].
-    pub fn builder() -> ConsumerConfigBuilder {
-        ConsumerConfigBuilder::default()
-    }
-}
+impl ConsumerConfig {
+    /// Creates a new default [`ConsumerConfigBuilder`] which is used to construct a
+    /// [`ConsumerConfig`].
+    pub fn builder() -> ConsumerConfigBuilder {
+        ConsumerConfigBuilder::default()
+    }
+}
+
+impl TryFrom<ConsumerConfig> for Consumer {
+    type Error = anyhow::Error;
+
+    /// Attempts to convert the [`ConsumerConfig`] into a [`Consumer`].
+    fn try_from(config: ConsumerConfig) -> Result<Self, Self::Error> {
+        Self::new(config)
+    }
+}
+
+/// High-level Kafka consumer. Through this struct the application can easily start, pause and
+/// resume the underlying Kafka consumer.
+pub struct Consumer {
+    /// Underlying Kafka consumer.
+    consumer: Arc<StreamConsumer<ConsumerContext>>,
+    /// Configuration the underlying Kafka consumer was created with, used to create the short
+    /// lived consumers that fetch single records without affecting its assignment.
+    client_config: ClientConfig,
+    /// Specifies the [`KeyDeserializer`] that should be used to deserialize the key of the Kafka
+    /// record.
+    key_deserializer: Arc<dyn KeyDeserializer>,
+    /// Specifies the [`ValueDeserializer`] that should be used to deserialize the value of the
+    /// Kafka record.
+    value_deserializer: Arc<dyn ValueDeserializer>,
+    /// Sender for the Kafka consumer channel.
+    consumer_tx: Sender<ConsumerEvent>,
+    /// Filter applied to the records consumed, shared with the tasks spawned when the consumer was
+    /// started so that it can be replaced while they are running.
+    filter: Arc<RwLock<Option<RecordFilter>>>,
+    /// Handles for the tasks spawned when the consumer was started so that they can be aborted
+    /// when the consumer is stopped.
+    tasks: Mutex<Vec<JoinHandle<()>>>,
+    /// Partitions whose task stopped consuming because the bound for the partition was reached.
+    /// They are kept paused when the consumer is resumed until they are seeked to a new position.
+    completed: Arc<Mutex<HashSet<TopicPartition>>>,
+    /// Number of bounded partitions that have not yet reached their bound. Shared with the tasks
+    /// spawned when the consumer was started.
+    remaining: Arc<AtomicUsize>,
+    /// Offsets of the records consumed that have not been committed yet when using the
+    /// [`CommitStrategy::Batch`] strategy. Shared with the tasks spawned when the consumer was
+    /// started so that the offsets can be committed when the consumer is stopped or paused.
+    pending_commits: Arc<Mutex<HashMap<TopicPartition, i64>>>,
+}
+
+impl Consumer {
+    /// Creates a new [`Consumer`] with the specified dependencies.
+    fn new(config: ConsumerConfig) -> anyhow::Result<Self> {
+        let mut client_config = ClientConfig::new();
+
+        // apply default config
+        client_config.set("auto.offset.reset", "latest");
+        client_config.set("statistics.interval.ms", "5000");
+
+        // apply user config
+        client_config.extend(config.props);
+
+        // apply enforced config
+        client_config.set("enable.auto.commit", "false");
+
+        if tracing::enabled!(tracing::Level::DEBUG) {
+            client_config.set("debug", "consumer,cgrp,topic");
+
+            for (k, v) in client_config.config_map().iter() {
+                tracing::debug!("consumer property {} set to {}", k, v,);
+            }
+        }
+
+        let context = ConsumerContext::new(config.consumer_tx.clone());
+
+        let consumer: StreamConsumer<ConsumerContext> = client_config
+            .set_log_level(RDKafkaLogLevel::Debug)
+            .create_with_context(context)
+            .context("create Kafka consumer")?;
+
+        Ok(Self {
+            consumer: Arc::new(consumer),
+            client_config,
+            key_deserializer: config.key_deserializer,
+            value_deserializer: config.value_deserializer,
+            consumer_tx: config.consumer_tx,
+            filter: Arc::new(RwLock::new(None)),
+            tasks: Mutex::new(Vec::new()),
+            completed: Arc::new(Mutex::new(HashSet::new())),
+            remaining: Arc::new(AtomicUsize::new(0)),
+            pending_commits: Arc::new(Mutex::new(HashMap::new())),
+        })
+    }
+    /// Starts the consumption of records from the specified Kafka topic partitions, or from the
+    /// partitions assigned by the consumer group coordinator when subscribing to the topics.
+    /// Blocks while the offsets of the partitions are resolved, so it should not be called from
+    /// an asynchronous context.
+    pub fn start(&self, config: ConsumeTopicConfig) -> anyhow::Result<()> {
+        self.set_filter(config.filter.clone());
+
+        if config.assignment_mode == AssignmentMode::Subscribe {
+            return self.subscribe(config);
+        }
+
+        tracing::info!(
+            "assigning partitions to Kafka consumer: {:?}",
+            config.partitions
+        );
+
+        // the watermarks are only needed to resolve tail offsets and partition bounds, and are
+        // fetched for all of the partitions at once rather than one partition at a time
+        let watermarks = if matches!(config.seek_to, SeekTo::Tail(_))
+            || !matches!(config.until, ConsumeUntil::None)
+        {
+            self.fetch_watermarks(&config.partitions)
+                .context("fetch partition watermarks")?
+        } else {
+            HashMap::new()
+        };
+
+        let mut assignments_list = TopicPartitionList::with_capacity(config.partitions.len());
+
+        for tp in config.partitions.iter() {
+            let TopicPartition { topic, partition } = tp;
+
+            match config.seek_to {
+                SeekTo::None => {
+                    let _ = assignments_list.add_partition(topic, *partition);
+                }
+                SeekTo::Reset => assignments_list
+                    .add_partition_offset(topic, *partition, Offset::Offset(0))
+                    .context("add partition offset")?,
+                SeekTo::Custom(ref partition_offsets) => {
+                    match PartitionOffset::find(partition_offsets, tp, &config.partitions)? {
+                        Some(po) => assignments_list
+                            .add_partition_offset(topic, *partition, Offset::Offset(po.offset))
+                            .context("add partition offset")?,
+                        None => {
+                            let _ = assignments_list.add_partition(topic, *partition);
+                        }
+                    }
+                }
+                SeekTo::Timestamp(_) => {
+                    let _ = assignments_list.add_partition(topic, *partition);
+                }
+                SeekTo::Tail(count) => {
+                    let offset = tail_offset(watermark(&watermarks, tp)?, count);
+
+                    tracing::info!("resolved tail offset {} on partition {}", offset, tp);
+
+                    assignments_list
+                        .add_partition_offset(topic, *partition, Offset::Offset(offset))
+                        .context("add partition offset")?
+                }
+            }
+        }
+
+        if let SeekTo::Timestamp(ref timestamp) = config.seek_to {
+            assignments_list = self
+                .resolve_offsets_for_timestamp(assignments_list, timestamp)
+                .context("resolve offsets for timestamp")?;
+        }
+
+        self.consumer
+            .assign(&assignments_list)
+            .context("assign partitions to consumer")?;
+
+        let bounds = self
+            .resolve_partition_bounds(&config.partitions, &config.until, &watermarks)
+            .context("resolve partition bounds")?;
+
+        let start_offsets = self
+            .resolve_start_offsets(&assignments_list, &bounds, &watermarks)
+            .context("resolve start offsets")?;
+
+        self.completed
+            .lock()
+            .expect("completed partitions lock")
+            .clear();
+
+        // only the partitions that are bounded ever report that their bound was reached, so the
+        // consumer is complete once all of those have done so
+        let bounded = bounds.values().filter(|b| b.is_bounded()).count();
+
+        self.remaining.store(bounded, Ordering::Relaxed);
+
+        let mut tasks = Vec::with_capacity(config.partitions.len() + 1);
+
+        for topic_partition in config.partitions.iter() {
+            let TopicPartition { topic, partition } = topic_partition;
+
+            let partition_queue = self
+                .consumer
+                .split_partition_queue(topic, *partition)
+                .expect("partition queue created");
+
+            let bound = bounds.get(topic_partition).copied().unwrap_or_default();
+
+            let bound_reached = bound
+                .end_offset
+                .zip(start_offsets.get(topic_partition))
+                .is_some_and(|(end_offset, start_offset)| *start_offset >= end_offset);
+
+            let task = PartitionConsumerTask {
+                processor: self.record_processor(&config, Some(topic_partition.clone())),
+                topic: topic.clone(),
+                partition: *partition,
+                partition_queue: Arc::new(partition_queue),
+                bound,
+                remaining: Arc::clone(&self.remaining),
+                completed: Arc::clone(&self.completed),
+            };
+
+            tasks.push(tokio::spawn(async move {
+                if bound_reached {
+                    task.on_bound_reached().await;
+                }
+
+                if let Err(e) = task.run().await {
+                    tracing::error!("error during partition consumer task: {}", e);
+                }
+            }));
+        }
+
+        let task_consumer = Arc::clone(&self.consumer);
+
+        // according to the crate docs, the main StreamConsumer must be awaited periodically even
+        // if all partitions queues have been split off in order to receive events. See the
+        // documentation linked below for details.
+        //
+        // https://docs.rs/rdkafka/latest/rdkafka/consumer/stream_consumer/struct.StreamConsumer.html
+        tasks.push(tokio::spawn(async move {
+            let message = task_consumer.recv().await;
+            panic!(
+                "StreamConsumer unexpectedly received message: {:?}",
+                message
+            );
+        }));
+
+        self.tasks
+            .lock()
+            .expect("consumer tasks lock")
+            .extend(tasks);
+
+        Ok(())
+    }
+    /// Subscribes the consumer to the Kafka topics so that it joins the consumer group and is
+    /// assigned partitions by the group coordinator. Records are consumed from the offsets
+    /// committed for the consumer group, so any configured [`SeekTo`] or [`ConsumeUntil`] value
+    /// is ignored.
+    fn subscribe(&self, config: ConsumeTopicConfig) -> anyhow::Result<()> {
+        tracing::info!("subscribing Kafka consumer to topics: {:?}", config.topics);
+
+        if !matches!(config.seek_to, SeekTo::None) || !matches!(config.until, ConsumeUntil::None) {
+            tracing::warn!("seek to and until values are ignored when subscribing to topics");
+        }
+
+        let topics: Vec<&str> = config.topics.iter().map(String::as_str).collect();
+
+        self.consumer
+            .subscribe(&topics)
+            .context("subscribe consumer to topics")?;
+
+        let task = SubscriptionConsumerTask {
+            processor: self.record_processor(&config, None),
+        };
+
+        self.tasks
+            .lock()
+            .expect("consumer tasks lock")
+            .push(tokio::spawn(async move {
+                if let Err(e) = task.run().await {
+                    tracing::error!("error during subscription consumer task: {}", e);
+                }
+            }));
+
+        Ok(())
+    }
+    /// Creates a new [`RecordProcessor`] that processes the records consumed from the topic
+    /// partition, or from all assigned partitions if none is specified, based on the
+    /// [`ConsumeTopicConfig`].
+    fn record_processor(
+        &self,
+        config: &ConsumeTopicConfig,
+        topic_partition: Option<TopicPartition>,
+    ) -> RecordProcessor<StreamConsumer<ConsumerContext>, ConsumerContext> {
+        RecordProcessor {
+            consumer: Arc::clone(&self.consumer),
+            key_deserializer: Arc::clone(&self.key_deserializer),
+            value_deserializer: Arc::clone(&self.value_deserializer),
+            filter: Arc::clone(&self.filter),
+            commit_strategy: config.commit_strategy,
+            uncommitted: AtomicU32::new(0),
+            topic_partition,
+            pending_commits: Arc::clone(&self.pending_commits),
+            consumer_tx: self.consumer_tx.clone(),
+            _context: PhantomData,
+        }
+    }
+    /// Replaces the filter applied to the records consumed. Takes effect for the next record
+    /// processed by each of the running tasks, so it can be called while the consumer is running.
+    pub fn set_filter(&self, filter: Option<RecordFilter>) {
+        tracing::info!(
+            "setting consumer filter to {}",
+            filter.as_ref().map_or("none", RecordFilter::expression)
+        );
+
+        *self.filter.write().expect("consumer filter lock") = filter;
+    }
+    /// Stops the consumption of records by aborting all of the tasks spawned when the consumer
+    /// was started, leaving the consumer group if subscribed, and removing the current partition
+    /// assignments. The consumer can then be started again for the same or a different topic.
+    pub fn stop(&self) -> anyhow::Result<()> {
+        tracing::info!("stopping Kafka consumer");
+
+        for task in self.tasks.lock().expect("consumer tasks lock").drain(..) {
+            task.abort();
+        }
+
+        self.commit_pending();
+
+        self.consumer.unsubscribe();
+
+        self.consumer
+            .unassign()
+            .context("unassign consumer partitions")
+    }
+    /// Asynchronously commits the offsets of the records consumed that have not been committed yet
+    /// when using the [`CommitStrategy::Batch`] strategy.
+    fn commit_pending(&self) {
+        let pending: Vec<(TopicPartition, i64)> = self
+            .pending_commits
+            .lock()
+            .expect("pending commits lock")
+            .drain()
+            .collect();
+
+        if pending.is_empty() {
+            return;
+        }
+
+        tracing::info!(
+            "committing pending offsets for {} partitions",
+            pending.len()
+        );
+
+        if let Err(e) = commit_offsets(self.consumer.as_ref(), pending) {
+            tracing::error!("error committing pending offsets: {:#}", e);
+        }
+    }
+    /// Resolves the offset on each partition in the [`TopicPartitionList`] of the earliest record
+    /// whose timestamp is greater than or equal to the given [`SeekTimestamp`]. If no such record
+    /// exists on a partition, then the offset will be set to the end of that partition.
+    fn resolve_offsets_for_timestamp(
+        &self,
+        mut partitions: TopicPartitionList,
+        timestamp: &SeekTimestamp,
+    ) -> anyhow::Result<TopicPartitionList> {
+        let timestamp_millis = timestamp.resolve_millis();
+
+        tracing::info!(
+            "resolving partition offsets for timestamp {} ({})",
+            timestamp,
+            timestamp_millis
+        );
+
+        partitions
+            .set_all_offsets(Offset::Offset(timestamp_millis))
+            .context("set timestamp on partitions")?;
+
+        let resolved = self
+            .consumer
+            .offsets_for_times(partitions, OFFSET_LOOKUP_TIMEOUT)
+            .context("fetch offsets for times")?;
+
+        for e in resolved.elements() {
+            tracing::info!(
+                "resolved offset {:?} on partition {} for timestamp {}",
+                e.offset(),
+                e.partition(),
+                timestamp
+            );
+        }
+
+        Ok(resolved)
+    }
+    /// Fetches the low and high watermarks of all of the partitions. The offsets for the earliest
+    /// and latest timestamps are the watermarks of a partition, so they are resolved for all of
+    /// the partitions with two offset lookups rather than one lookup for each partition.
+    fn fetch_watermarks(
+        &self,
+        partitions: &[TopicPartition],
+    ) -> anyhow::Result<HashMap<TopicPartition, (i64, i64)>> {
+        let mut partitions_list = TopicPartitionList::with_capacity(partitions.len());
+
+        for tp in partitions.iter() {
+            let _ = partitions_list.add_partition(&tp.topic, tp.partition);
+        }
+
+        let mut lookup = |offset: Offset| -> anyhow::Result<HashMap<TopicPartition, i64>> {
+            partitions_list
+                .set_all_offsets(offset)
+                .context("set lookup offsets")?;
+
+            let resolved = self
+                .consumer
+                .offsets_for_times(partitions_list.clone(), OFFSET_LOOKUP_TIMEOUT)
+                .context("fetch offsets for times")?;
+
+            resolved
+                .elements()
+                .iter()
+                .map(|e| {
+                    let tp = TopicPartition::new(e.topic(), e.partition());
+
+                    e.error()
+                        .with_context(|| format!("fetch offset of partition {}", tp))?;
+
+                    match e.offset() {
+                        Offset::Offset(offset) => Ok((tp, offset)),
+                        offset => {
+                            anyhow::bail!("unexpected offset {:?} for partition {}", offset, tp)
+                        }
+                    }
+                })
+                .collect()
+        };
+
+        let low = lookup(Offset::Beginning).context("fetch low watermarks")?;
+        let high = lookup(Offset::End).context("fetch high watermarks")?;
+
+        Ok(low
+            .into_iter()
+            .filter_map(|(tp, low)| high.get(&tp).map(|high| (tp, (low, *high))))
+            .collect())
+    }
+    /// Resolves the [`PartitionBound`] for each of the partitions based on the [`ConsumeUntil`]
+    /// value using the previously fetched watermarks of the partitions. Partitions that are not
+    /// bounded are not contained in the returned map.
+    fn resolve_partition_bounds(
+        &self,
+        partitions: &[TopicPartition],
+        until: &ConsumeUntil,
+        watermarks: &HashMap<TopicPartition, (i64, i64)>,
+    ) -> anyhow::Result<HashMap<TopicPartition, PartitionBound>> {
+        let mut bounds = HashMap::new();
+
+        match until {
+            ConsumeUntil::None => {}
+            ConsumeUntil::End => {
+                for tp in partitions.iter() {
+                    let (_, high) = watermark(watermarks, tp)?;
+
+                    let bound = PartitionBound {
+                        end_offset: Some(high),
+                        end_timestamp: None,
+                    };
+
+                    bounds.insert(tp.clone(), bound);
+                }
+            }
+            ConsumeUntil::Custom(partition_offsets) => {
+                for tp in partitions.iter() {
+                    if let Some(po) = PartitionOffset::find(partition_offsets, tp, partitions)? {
+                        let bound = PartitionBound {
+                            end_offset: Some(po.offset + 1),
+                            end_timestamp: None,
+                        };
+
+                        bounds.insert(tp.clone(), bound);
+                    }
+                }
+            }
+            ConsumeUntil::Timestamp(timestamp) => {
+                let mut timestamps_list = TopicPartitionList::with_capacity(partitions.len());
+
+                for tp in partitions.iter() {
+                    let _ = timestamps_list.add_partition(&tp.topic, tp.partition);
+                }
 
-impl TryFrom<ConsumerConfig> for Consumer {
-    type Error = anyhow::Error;
+                let resolved = self
+                    .resolve_offsets_for_timestamp(timestamps_list, timestamp)
+                    .context("resolve offsets for end timestamp")?;
 
-    /// Attempts to convert the [`ConsumerConfig`] into a [`Consumer`].
-    fn try_from(config: ConsumerConfig) -> Result<Self, Self::Error> {
-        Self::new(config)
-    }
-}
+                let end_timestamp = timestamp.resolve_millis();
 
-/// High-level Kafka consumer. Through this struct the application can easily start, pause and
-/// resume the underlying Kafka consumer.
-pub struct Consumer {
-    /// Underlying Kafka consumer.
-    consumer: Arc<StreamConsumer<ConsumerContext>>,
-    /// Specifies the [`KeyDeserializer`] that should be used to deserialize the key of the Kafka
-    /// record.
-    key_deserializer: Arc<dyn KeyDeserializer>,
-    /// Specifies the [`ValueDeserializer`] that should be used to deserialize the value of the
-    /// Kafka record.
-    value_deserializer: Arc<dyn ValueDeserializer>,
-    /// Sender for the Kafka consumer channel.
-    consumer_tx: Sender<ConsumerEvent>,
-}
+                for e in resolved.elements() {
+                    let tp = TopicPartition::new(e.topic(), e.partition());
 
-impl Consumer {
-    /// Creates a new [`Consumer`] with the specified dependencies.
-    fn new(config: ConsumerConfig) -> anyhow::Result<Self> {
-        let mut client_config = ClientConfig::new();
+                    // when no record exists at or after a timestamp that has already passed, all
+                    // records currently on the partition are before it
+                    let end_offset = match e.offset() {
+                        Offset::Offset(offset) => Some(offset),
+                        _ if end_timestamp <= Local::now().timestamp_millis() => {
+                            Some(watermark(watermarks, &tp)?.1)
+                        }
+                        _ => None,
+                    };
 
-        // apply default config
-        client_config.set("auto.offset.reset", "latest");
-        client_config.set("statistics.interval.ms", "5000");
+                    let bound = PartitionBound {
+                        end_offset,
+                        end_timestamp: Some(end_timestamp),
+                    };
 
-        // apply user config
-        client_config.extend(config.props);
+                    bounds.insert(tp, bound);
+                }
+            }
+        }
 
-        // apply enforced config
-        client_config.set("enable.auto.commit", "false");
+        Ok(bounds)
+    }
+    /// Resolves the concrete offset that the consumer will start consuming from on each of the
+    /// partitions with an end offset, given the logical [`Offset`] it was assigned with. When the
+    /// consumer was not assigned an explicit offset, the committed offset for the consumer group
+    /// is used and, if none exists, the high watermark of the partition. The committed offsets of
+    /// all of the partitions that need them are fetched at once.
+    fn resolve_start_offsets(
+        &self,
+        assignments: &TopicPartitionList,
+        bounds: &HashMap<TopicPartition, PartitionBound>,
+        watermarks: &HashMap<TopicPartition, (i64, i64)>,
+    ) -> anyhow::Result<HashMap<TopicPartition, i64>> {
+        let mut start_offsets = HashMap::new();
 
-        if tracing::enabled!(tracing::Level::DEBUG) {
-            client_config.set("debug", "consumer,cgrp,topic");
+        let mut committed_list = TopicPartitionList::new();
 
-            for (k, v) in client_config.config_map().iter() {
-                tracing::debug!("consumer property {} set to {}", k, v,);
+        for e in assignments.elements() {
+            let tp = TopicPartition::new(e.topic(), e.partition());
+
+            if bounds.get(&tp).is_none_or(|b| b.end_offset.is_none()) {
+                continue;
             }
+
+            let start_offset = match e.offset() {
+                Offset::Offset(offset) => offset,
+                Offset::Beginning => watermark(watermarks, &tp)?.0,
+                Offset::End => watermark(watermarks, &tp)?.1,
+                _ => {
+                    let _ = committed_list.add_partition(&tp.topic, tp.partition);
+                    continue;
+                }
+            };
+
+            start_offsets.insert(tp, start_offset);
         }
 
-        let context = ConsumerContext::new(config.consumer_tx.clone());
+        if committed_list.count() > 0 {
+            let committed = self
+                .consumer
+                .committed_offsets(committed_list, OFFSET_LOOKUP_TIMEOUT)
+                .context("fetch committed offsets")?;
 
-        let consumer: StreamConsumer<ConsumerContext> = client_config
-            .set_log_level(RDKafkaLogLevel::Debug)
-            .create_with_context(context)
-            .context("create Kafka consumer")?;
+            for e in committed.elements() {
+                let tp = TopicPartition::new(e.topic(), e.partition());
 
-        Ok(Self {
-            consumer: Arc::new(consumer),
-            key_deserializer: config.key_deserializer,
-            value_deserializer: config.value_deserializer,
-            consumer_tx: config.consumer_tx,
-        })
-    }
-    /// Starts the consumption of records from the specified Kafka topic.
-    pub fn start(&self, config: ConsumeTopicConfig) -> anyhow::Result<()> {
-        let topic = config.topic.as_ref();
+                let start_offset = match e.offset() {
+                    Offset::Offset(offset) => offset,
+                    _ => watermark(watermarks, &tp)?.1,
+                };
 
+                start_offsets.insert(tp, start_offset);
+            }
+        }
+
+        Ok(start_offsets)
+    }
+    /// Repositions the running consumer to the [`SeekPosition`] on the specified topic partition,
+    /// or on all assigned partitions if no partition is specified. Partitions that had already
+    /// reached their bound are consumed again from the new position once they are resumed, which
+    /// is indicated by the returned value. Blocks while the partitions are seeked, so it should
+    /// not be called from an asynchronous context.
+    pub fn seek(
+        &self,
+        partition: Option<&TopicPartition>,
+        position: SeekPosition,
+    ) -> anyhow::Result<bool> {
         tracing::info!(
-            "assigning partitions to Kafka consumer: {:?}",
-            config.partitions
+            "seeking Kafka consumer to {} on partition {:?}",
+            position,
+            partition
         );
 
-        let mut assignments_list = TopicPartitionList::with_capacity(config.partitions.len());
+        let assignment = self
+            .consumer
+            .assignment()
+            .context("get consumer partition assignments")?;
 
-        for partition in config.partitions.iter() {
-            match config.seek_to {
-                SeekTo::None => {
-                    let _ = assignments_list.add_partition(topic, *partition);
-                }
-                SeekTo::Reset => assignments_list
-                    .add_partition_offset(topic, *partition, Offset::Offset(0))
-                    .context("add partition offset")?,
-                SeekTo::Custom(ref partition_offsets) => {
-                    match partition_offsets
-                        .iter()
-                        .find(|po| po.partition == *partition)
-                    {
-                        Some(po) => assignments_list
-                            .add_partition_offset(topic, *partition, Offset::Offset(po.offset))
-                            .context("add partition offset")?,
-                        None => {
-                            let _ = assignments_list.add_partition(topic, *partition);
-                        }
-                    }
-                }
-            }
+        let mut partitions_list = TopicPartitionList::with_capacity(assignment.count());
+
+        for e in assignment.elements().iter().filter(|e| {
+            partition.is_none_or(|tp| tp.topic == e.topic() && tp.partition == e.partition())
+        }) {
+            let _ = partitions_list.add_partition(e.topic(), e.partition());
         }
 
-        self.consumer
-            .assign(&assignments_list)
-            .context("assign partitions to consumer")?;
+        if partitions_list.count() == 0 {
+            match partition {
+                Some(tp) => anyhow::bail!("partition {} is not assigned to the consumer", tp),
+                None => anyhow::bail!("no partitions are assigned to the consumer"),
+            }
+        }
 
-        for partition in config.partitions.iter() {
-            let partition_queue = self
-                .consumer
-                .split_partition_queue(topic.as_ref(), *partition)
-                .expect("partition queue created");
+        let partitions_list = match position {
+            SeekPosition::Beginning => {
+                partitions_list
+                    .set_all_offsets(Offset::Beginning)
+                    .context("set beginning offsets")?;
+                partitions_list
+            }
+            SeekPosition::End => {
+                partitions_list
+                    .set_all_offsets(Offset::End)
+                    .context("set end offsets")?;
+                partitions_list
+            }
+            SeekPosition::Offset(offset) => {
+                partitions_list
+                    .set_all_offsets(Offset::Offset(offset))
+                    .context("set custom offsets")?;
+                partitions_list
+            }
+            SeekPosition::Timestamp(ref timestamp) => self
+                .resolve_offsets_for_timestamp(partitions_list, timestamp)
+                .context("resolve offsets for timestamp")?,
+        };
 
-            let task = PartitionConsumerTask {
-                consumer: Arc::clone(&self.consumer),
-                partition_queue: Arc::new(partition_queue),
-                key_deserializer: Arc::clone(&self.key_deserializer),
-                value_deserializer: Arc::clone(&self.value_deserializer),
-                filter: config.filter.clone(),
-                consumer_tx: self.consumer_tx.clone(),
-            };
+        let seeked = self
+            .consumer
+            .seek_partitions(partitions_list, OFFSET_LOOKUP_TIMEOUT)
+            .context("seek partitions")?;
 
-            tokio::spawn(async move {
-                if let Err(e) = task.run().await {
-                    tracing::error!("error during partition consumer task: {}", e);
-                }
-            });
+        for e in seeked.elements() {
+            e.error()
+                .with_context(|| format!("seek partition {}", e.partition()))?;
         }
 
-        let task_consumer = Arc::clone(&self.consumer);
+        let mut completed = self.completed.lock().expect("completed partitions lock");
 
-        // according to the crate docs, the main StreamConsumer must be awaited periodically even
-        // if all partitions queues have been split off in order to receive events. See the
-        // documentation linked below for details.
-        //
-        // https://docs.rs/rdkafka/latest/rdkafka/consumer/stream_consumer/struct.StreamConsumer.html
-        tokio::spawn(async move {
-            let message = task_consumer.recv().await;
-            panic!(
-                "StreamConsumer unexpectedly received message: {:?}",
-                message
-            );
-        });
+        let mut reopened = false;
 
-        Ok(())
+        for e in seeked.elements() {
+            if completed.remove(&TopicPartition::new(e.topic(), e.partition())) {
+                self.remaining.fetch_add(1, Ordering::Relaxed);
+                reopened = true;
+            }
+        }
+
+        Ok(reopened)
     }
-    /// Pauses the consumption of records from the topic.
+    /// Pauses the consumption of records from the topic. Any offsets that have not been committed
+    /// yet are committed so that they are not lost if the application exits while paused.
     pub fn pause(&self) -> anyhow::Result<()> {
         tracing::debug!("attemping to pause Kafka consumer");
 
+        self.commit_pending();
+
         let assignment = self
             .consumer
             .assignment()
@@ -609,7 +1945,8 @@ impl Consumer {
             .pause(&assignment)
             .context("pause consumer assignments")
     }
-    /// Resumes the consumption of records from the topic.
+    /// Resumes the consumption of records from the topic. Partitions that have already reached
+    /// their bound remain paused.
     pub fn resume(&self) -> anyhow::Result<()> {
         tracing::debug!("attemping to resume Kafka consumer");
 
@@ -618,8 +1955,20 @@ impl Consumer {
             .assignment()
             .context("get consumer partition assignments")?;
 
+        let completed = self.completed.lock().expect("completed partitions lock");
+
+        let mut partitions_list = TopicPartitionList::with_capacity(assignment.count());
+
+        for e in assignment
+            .elements()
+            .iter()
+            .filter(|e| !completed.contains(&TopicPartition::new(e.topic(), e.partition())))
+        {
+            let _ = partitions_list.add_partition(e.topic(), e.partition());
+        }
+
         self.consumer
-            .resume(&assignment)
+            .resume(&partitions_list)
             .context("resume consumer assignments")
     }
     /// Retrieves metadata for the specified topic from the Kafka cluster. If no topic is
@@ -640,172 +1989,285 @@ impl Consumer {
 
         Ok(metadata)
     }
-}
-
-/// A view of a [`Record`] that can be more easily filtered using a JSONPath query.
-#[derive(Debug, Serialize)]
-#[serde(rename_all = "camelCase")]
-struct FilterableRecord {
-    /// Filterable info data.
-    info: Vec<HashMap<String, String>>,
-    /// Filterable header data.
-    headers: Vec<HashMap<String, String>>,
-    /// Filterable value data.
-    value: serde_json::Value,
-}
-
-impl From<&Record> for FilterableRecord {
-    /// Creates a new [`FilterableRecord`] from a [`Record`] reference.
-    fn from(record: &Record) -> Self {
-        let mut info = Vec::new();
+    /// Fetches the key and value of the record at the offset of the topic partition exactly as
+    /// they are stored in the topic. A separate consumer is assigned to the topic partition so
+    /// that the records being consumed are not affected.
+    pub fn fetch_record_data(
+        &self,
+        topic_partition: &TopicPartition,
+        offset: i64,
+    ) -> anyhow::Result<RecordData> {
+        let consumer: BaseConsumer = self
+            .client_config
+            .create()
+            .context("create Kafka consumer")?;
 
-        if let Some(pk) = record.key.as_ref() {
-            let mut pk_map = HashMap::new();
-            pk_map.insert(String::from("key"), pk.clone());
+        let mut partitions_list = TopicPartitionList::new();
+        partitions_list
+            .add_partition_offset(
+                &topic_partition.topic,
+                topic_partition.partition,
+                Offset::Offset(offset),
+            )
+            .context("add topic partition offset")?;
+
+        consumer
+            .assign(&partitions_list)
+            .context("assign topic partition")?;
+
+        let deadline = Instant::now() + RECORD_FETCH_TIMEOUT;
+
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+
+            if remaining.is_zero() {
+                anyhow::bail!(
+                    "timed out fetching record at {} offset {}",
+                    topic_partition,
+                    offset
+                );
+            }
 
-            info.push(pk_map);
+            match consumer.poll(remaining) {
+                Some(Ok(message)) if message.offset() == offset => {
+                    return Ok((
+                        message.key().map(<[u8]>::to_vec),
+                        message.payload().map(<[u8]>::to_vec),
+                    ));
+                }
+                // the record was removed from the topic, e.g. by compaction or retention
+                Some(Ok(message)) if message.offset() > offset => {
+                    anyhow::bail!(
+                        "record at {} offset {} no longer exists",
+                        topic_partition,
+                        offset
+                    );
+                }
+                Some(Ok(_)) | None => continue,
+                Some(Err(e)) => {
+                    return Err(e).with_context(|| {
+                        format!("fetch record at {} offset {}", topic_partition, offset)
+                    });
+                }
+            }
         }
+    }
+}
 
-        let mut offset_map = HashMap::new();
-        offset_map.insert(String::from("offset"), record.offset.to_string());
-        info.push(offset_map);
+/// Asynchronously commits the offsets for the topic partitions to the consumer group.
+fn commit_offsets<Con, Ctx>(
+    consumer: &Con,
+    offsets: Vec<(TopicPartition, i64)>,
+) -> anyhow::Result<()>
+where
+    Con: RDConsumer<Ctx>,
+    Ctx: RDConsumerContext,
+{
+    let mut offsets_list = TopicPartitionList::with_capacity(offsets.len());
 
-        let mut partition_map = HashMap::new();
-        partition_map.insert(String::from("partition"), record.partition.to_string());
-        info.push(partition_map);
+    for (tp, offset) in offsets {
+        offsets_list
+            .add_partition_offset(&tp.topic, tp.partition, Offset::Offset(offset))
+            .context("add partition offset")?;
+    }
 
-        let mut headers = Vec::new();
-        for (k, v) in record.headers.iter() {
-            let mut record_headers = HashMap::new();
-            record_headers.insert(k.clone(), v.clone());
+    consumer
+        .commit(&offsets_list, CommitMode::Async)
+        .context("commit offsets")
+}
 
-            headers.push(record_headers);
-        }
+/// Looks up the previously fetched low and high watermarks of the [`TopicPartition`].
+fn watermark(
+    watermarks: &HashMap<TopicPartition, (i64, i64)>,
+    tp: &TopicPartition,
+) -> anyhow::Result<(i64, i64)> {
+    watermarks
+        .get(tp)
+        .copied()
+        .ok_or_else(|| anyhow::anyhow!("no watermarks fetched for partition {}", tp))
+}
 
-        let value = serde_json::to_value(&record.value).expect("value serializes to JSON");
+/// Computes the offset on a partition that the consumer should start from in order to consume the
+/// last `count` records currently on the partition from its low and high watermarks as
+/// `max(low, high - count)`.
+fn tail_offset((low, high): (i64, i64), count: u64) -> i64 {
+    let count = i64::try_from(count).unwrap_or(i64::MAX);
 
-        Self {
-            info,
-            headers,
-            value,
-        }
-    }
+    low.max(high.saturating_sub(count))
 }
 
-/// A task which is executed in a background thread that handles consuming messages from a Kafka
-/// topic.
-struct PartitionConsumerTask<Con, Ctx>
+/// Processes the messages consumed from a Kafka topic by converting them to [`Record`]s, applying
+/// any filter, publishing them over the consumer channel and committing their offsets.
+struct RecordProcessor<Con, Ctx>
 where
     Con: RDConsumer<Ctx>,
     Ctx: RDConsumerContext,
 {
     /// Raw Kafka consumer.
     consumer: Arc<Con>,
-    /// The partition queue that the task is handling Kafka records for.
-    partition_queue: Arc<StreamPartitionQueue<Ctx>>,
     /// Specifies the [`KeyDeserializer`] that should be used to deserialize the key of the Kafka
     /// record.
     key_deserializer: Arc<dyn KeyDeserializer>,
     /// Specifies the [`ValueDeserializer`] that should be used to deserialize the value of the
     /// Kafka record.
     value_deserializer: Arc<dyn ValueDeserializer>,
-    /// Any filter to apply to the record.
-    filter: Option<String>,
+    /// Any filter to apply to the record, shared with the [`Consumer`] so that it can be replaced
+    /// while the record processor is running.
+    filter: Arc<RwLock<Option<RecordFilter>>>,
+    /// Drives if and how the offsets of consumed records are committed.
+    commit_strategy: CommitStrategy,
+    /// Number of records consumed since offsets were last committed when using the
+    /// [`CommitStrategy::Batch`] strategy.
+    uncommitted: AtomicU32,
+    /// Topic partition that the processor handles records for, or all assigned partitions when
+    /// not specified. Only the offsets of these partitions are committed by the processor.
+    topic_partition: Option<TopicPartition>,
+    /// Offsets of the records consumed that have not been committed yet when using the
+    /// [`CommitStrategy::Batch`] strategy. Shared with the [`Consumer`] and the other processors.
+    pending_commits: Arc<Mutex<HashMap<TopicPartition, i64>>>,
     /// Sender for the Kafka consumer channel.
     consumer_tx: Sender<ConsumerEvent>,
+    /// Marker for the consumer context type.
+    _context: PhantomData<Ctx>,
 }
 
-impl<Con, Ctx> PartitionConsumerTask<Con, Ctx>
+impl<Con, Ctx> RecordProcessor<Con, Ctx>
 where
     Con: RDConsumer<Ctx>,
     Ctx: RDConsumerContext,
 {
-    /// Runs the task by subscribing to the partition queue and then consuming messages from it.
-    async fn run(&self) -> anyhow::Result<()> {
-        let stream_procesor = self
-            .partition_queue
-            .stream()
-            .try_for_each(|msg| async move {
-                let record = self.create_record(&msg).await;
-
-                let consumer_event = match &self.filter {
-                    Some(filter) if !record.matches(filter) => ConsumerEvent::Filtered(record),
-                    _ => ConsumerEvent::Received(record),
-                };
+    /// Processes the [`BorrowedMessage`] consumed from the Kafka topic.
+    async fn process(&self, msg: &BorrowedMessage<'_>) {
+        let record = Box::new(self.create_record(msg).await);
+
+        // the lock guard must be released before the event is sent over the channel
+        let filtered = self
+            .filter
+            .read()
+            .expect("consumer filter lock")
+            .as_ref()
+            .is_some_and(|f| !f.matches(&record, self.value_deserializer.format()));
+
+        let consumer_event = if filtered {
+            ConsumerEvent::Filtered(record)
+        } else {
+            ConsumerEvent::Received(record)
+        };
 
-                if let Err(e) = self.consumer_tx.send(consumer_event).await {
-                    tracing::error!("failed to send consumer event over channel: {}", e);
-                }
+        if let Err(e) = self.consumer_tx.send(consumer_event).await {
+            tracing::error!("failed to send consumer event over channel: {}", e);
+        }
 
-                if let Err(err) = self.consumer.commit_message(&msg, CommitMode::Sync) {
-                    tracing::error!("error committing Kafka message: {}", err);
+        self.commit(msg);
+    }
+    /// Commits the offset of the [`BorrowedMessage`] based on the configured [`CommitStrategy`].
+    /// When committing in batches, only the offsets of the partitions handled by the processor
+    /// are committed once it has consumed the batch size.
+    fn commit(&self, msg: &BorrowedMessage<'_>) {
+        let result = match self.commit_strategy {
+            CommitStrategy::None => return,
+            CommitStrategy::Async => self
+                .consumer
+                .commit_message(msg, CommitMode::Async)
+                .context("commit message"),
+            CommitStrategy::Batch(size) => {
+                let mut pending = self.pending_commits.lock().expect("pending commits lock");
+
+                // the committed offset is the offset of the next record to consume
+                pending.insert(
+                    TopicPartition::new(msg.topic(), msg.partition()),
+                    msg.offset() + 1,
+                );
+
+                let uncommitted = self.uncommitted.fetch_add(1, Ordering::Relaxed) + 1;
+
+                if uncommitted < size {
+                    return;
                 }
 
-                Ok(())
-            });
+                self.uncommitted.store(0, Ordering::Relaxed);
+
+                let offsets: Vec<(TopicPartition, i64)> = match self.topic_partition.as_ref() {
+                    Some(tp) => pending.remove_entry(tp).into_iter().collect(),
+                    None => pending.drain().collect(),
+                };
+
+                drop(pending);
 
-        stream_procesor.await.context("process Kafka record stream")
+                commit_offsets(self.consumer.as_ref(), offsets)
+            }
+        };
+
+        if let Err(err) = result {
+            tracing::error!("error committing Kafka message: {:#}", err);
+        }
     }
     /// Creates a new [`Record`] from the [`BorrowedMessage`] read from the Kafka topic.
     async fn create_record(&self, msg: &BorrowedMessage<'_>) -> Record {
-        let key = match msg.key() {
-            None => None,
+        let (key, key_error) = match msg.key() {
+            None => (None, None),
             Some(data) => match self
                 .key_deserializer
                 .deserialize_key(msg.topic(), msg.headers(), data)
                 .await
             {
-                Ok(key) => Some(key),
+                Ok(key) => (Some(key), None),
                 Err(e) => {
                     tracing::error!("error deserializing message key: {}", e);
-                    None
+
+                    let format = self.key_deserializer.format();
+                    (None, Some(DecodeError::new(&e, format, data)))
                 }
             },
         };
 
-        let headers: HashMap<String, String> = match msg.headers() {
-            Some(hs) => {
-                let mut headers = HashMap::new();
-                for h in hs.iter() {
-                    let value = match std::str::from_utf8(h.value.expect("header value exists")) {
-                        Ok(s) => String::from(s),
-                        Err(e) => {
-                            tracing::warn!("invalid UTF8 record header value: {}", e);
-                            String::from("")
-                        }
-                    };
-
-                    headers.insert(String::from(h.key), value);
-                }
-
-                headers
-            }
-            None => HashMap::new(),
+        let headers: Vec<RecordHeader> = match msg.headers() {
+            Some(hs) => hs
+                .iter()
+                .map(|h| RecordHeader {
+                    key: String::from(h.key),
+                    value: h.value.map(<[u8]>::to_vec),
+                })
+                .collect(),
+            None => Vec::new(),
         };
 
-        let value = match msg.payload() {
-            None => None,
+        let (value, value_error) = match msg.payload() {
+            None => (None, None),
             Some(data) => match self
                 .value_deserializer
                 .deserialize_value(msg.topic(), msg.headers(), data)
                 .await
             {
-                Ok(v) => Some(v),
+                Ok(v) => (Some(v), None),
                 Err(e) => {
                     tracing::error!("error deserializing message value: {}", e);
-                    None
+
+                    let format = self.value_deserializer.format();
+                    (None, Some(DecodeError::new(&e, format, data)))
                 }
             },
         };
 
-        let timestamp_millis = msg
-            .timestamp()
-            .to_millis()
-            .expect("Kafka message has valid timestamp");
+        // a record without a timestamp is shown with the time it was consumed instead
+        let local_date_time = match msg.timestamp().to_millis() {
+            Some(timestamp_millis) => DateTime::from_timestamp_millis(timestamp_millis)
+                .expect("DateTime created from millis")
+                .into(),
+            None => Local::now(),
+        };
+
+        // the wire prefix is only read when a format is configured so that plain string data
+        // which happens to start with a zero byte is not mistaken for a schema id
+        let key_schema_id = msg
+            .key()
+            .filter(|_| self.key_deserializer.format() != Format::None)
+            .and_then(schema_id);
 
-        let local_date_time = DateTime::from_timestamp_millis(timestamp_millis)
-            .expect("DateTime created from millis")
-            .into();
+        let value_schema_id = msg
+            .payload()
+            .filter(|_| self.value_deserializer.format() != Format::None)
+            .and_then(schema_id);
 
         Record {
             partition: msg.partition(),
@@ -813,8 +2275,144 @@ where
             key,
             headers,
             value,
+            raw_key: msg.key().map(RawBytes::new),
+            raw_value: msg.payload().map(RawBytes::new),
+            key_error,
+            value_error,
+            key_size: msg.key().map(<[u8]>::len),
+            value_size: msg.payload().map(<[u8]>::len),
+            key_schema_id,
+            value_schema_id,
+            leader_epoch: leader_epoch(msg),
             timestamp: local_date_time,
+            timestamp_type: msg.timestamp().into(),
             offset: msg.offset(),
         }
     }
 }
+
+/// A task which is executed in a background thread that handles consuming messages from a single
+/// partition of a Kafka topic that was explicitly assigned to the consumer.
+struct PartitionConsumerTask<Con, Ctx>
+where
+    Con: RDConsumer<Ctx>,
+    Ctx: RDConsumerContext,
+{
+    /// Processes the messages consumed from the partition.
+    processor: RecordProcessor<Con, Ctx>,
+    /// Name of the topic that the partition belongs to.
+    topic: String,
+    /// Partition that the task is handling Kafka records for.
+    partition: i32,
+    /// The partition queue that the task is handling Kafka records for.
+    partition_queue: Arc<StreamPartitionQueue<Ctx>>,
+    /// Point on the partition where the task should stop consuming records.
+    bound: PartitionBound,
+    /// Number of bounded partitions that have not yet reached their bound. Shared between all of
+    /// the tasks started for the topic.
+    remaining: Arc<AtomicUsize>,
+    /// Partitions that have reached their bound. Shared with the [`Consumer`] so that they are not
+    /// resumed until they are seeked.
+    completed: Arc<Mutex<HashSet<TopicPartition>>>,
+}
+
+impl<Con, Ctx> PartitionConsumerTask<Con, Ctx>
+where
+    Con: RDConsumer<Ctx>,
+    Ctx: RDConsumerContext,
+{
+    /// Runs the task by subscribing to the partition queue and then consuming messages from it.
+    /// Once the bound for the partition, if any, is reached, messages are discarded until the
+    /// partition is seeked to a new position and consumed again.
+    async fn run(&self) -> anyhow::Result<()> {
+        let mut stream = self.partition_queue.stream();
+
+        while let Some(msg) = stream
+            .try_next()
+            .await
+            .context("process Kafka record stream")?
+        {
+            // messages fetched before the partition was paused are past the bound
+            if self.is_completed() {
+                continue;
+            }
+
+            if self.bound.is_exceeded_by(&msg) {
+                self.on_bound_reached().await;
+                continue;
+            }
+
+            self.processor.process(&msg).await;
+
+            if self.bound.is_reached_by(&msg) {
+                self.on_bound_reached().await;
+            }
+        }
+
+        Ok(())
+    }
+    /// Determines if the partition has reached its bound and has not been seeked since.
+    fn is_completed(&self) -> bool {
+        self.completed
+            .lock()
+            .expect("completed partitions lock")
+            .contains(&TopicPartition::new(self.topic.clone(), self.partition))
+    }
+    /// Invoked when the bound for the partition has been reached. Pauses the partition so that no
+    /// more records are fetched for it and publishes the [`ConsumerEvent::Complete`] event once all
+    /// partitions have reached their bound.
+    async fn on_bound_reached(&self) {
+        tracing::info!(
+            "reached end bound {:?} on partition {}",
+            self.bound,
+            self.partition
+        );
+
+        let mut partitions_list = TopicPartitionList::with_capacity(1);
+        let _ = partitions_list.add_partition(&self.topic, self.partition);
+
+        if let Err(e) = self.processor.consumer.pause(&partitions_list) {
+            tracing::error!("failed to pause partition {}: {}", self.partition, e);
+        }
+
+        self.completed
+            .lock()
+            .expect("completed partitions lock")
+            .insert(TopicPartition::new(self.topic.clone(), self.partition));
+
+        if self.remaining.fetch_sub(1, Ordering::Relaxed) == 1
+            && let Err(e) = self
+                .processor
+                .consumer_tx
+                .send(ConsumerEvent::Complete)
+                .await
+        {
+            tracing::error!("failed to send consumer event over channel: {}", e);
+        }
+    }
+}
+
+/// A task which is executed in a background thread that handles consuming messages from all of
+/// the partitions assigned to the consumer by the consumer group coordinator.
+struct SubscriptionConsumerTask {
+    /// Processes the messages consumed from the assigned partitions.
+    processor: RecordProcessor<StreamConsumer<ConsumerContext>, ConsumerContext>,
+}
+
+impl SubscriptionConsumerTask {
+    /// Runs the task by consuming messages from the main consumer stream until the task is
+    /// aborted.
+    async fn run(&self) -> anyhow::Result<()> {
+        let mut stream = self.processor.consumer.stream();
+
+        while let Some(msg) = stream
+            .try_next()
+            .await
+            .context("process Kafka record stream")?
+        {
+            self.processor.process(&msg).await;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,204 @@
+use crate::kafka::{RecordHeader, TopicPartition, schema::Version};
+
+use anyhow::Context;
+use derive_builder::Builder;
+use rdkafka::{
+    ClientConfig, ClientContext,
+    config::RDKafkaLogLevel,
+    message::{Header, OwnedHeaders},
+    producer::{FutureProducer, FutureRecord},
+};
+use std::{collections::HashMap, time::Duration};
+
+/// Maximum amount of time to wait for space in the producer queue when sending a record.
+const PRODUCE_QUEUE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Contains the data for a record that should be produced to a Kafka topic.
+#[derive(Clone, Debug, Default)]
+pub struct ProduceRecord {
+    /// Name of the topic that the record should be produced to.
+    pub topic: String,
+    /// Partition that the record should be produced to. When not specified the partition is
+    /// chosen by the partitioner configured for the producer.
+    pub partition: Option<i32>,
+    /// Partition key for the record, if any.
+    pub key: Option<Vec<u8>>,
+    /// Headers for the record in the order they should be set.
+    pub headers: Vec<RecordHeader>,
+    /// Value of the record. A record without a value is a tombstone.
+    pub value: Option<Vec<u8>>,
+    /// Timestamp of the record in milliseconds since the Unix epoch. When not specified the
+    /// producer sets the timestamp to the time the record is sent.
+    pub timestamp: Option<i64>,
+}
+
+/// Contains a previously consumed record that should be replayed to a Kafka topic.
+#[derive(Clone, Debug, Default)]
+pub struct ReplayRecord {
+    /// Record to produce with the key and value of the consumed record.
+    pub record: ProduceRecord,
+    /// Topic partition and offset of the consumed record if its key or value was truncated when it
+    /// was consumed, in which case the original key and value are fetched from the topic again
+    /// when the record is replayed.
+    pub refetch: Option<(TopicPartition, i64)>,
+}
+
+/// Contains the data for a record entered as text which must be serialized into a
+/// [`ProduceRecord`] before it can be produced to a Kafka topic.
+#[derive(Clone, Debug, Default)]
+pub struct ProduceRequest {
+    /// Name of the topic that the record should be produced to.
+    pub topic: String,
+    /// Partition that the record should be produced to. When not specified the partition is
+    /// chosen by the partitioner configured for the producer.
+    pub partition: Option<i32>,
+    /// Partition key for the record as text, if any.
+    pub key: Option<String>,
+    /// Headers for the record in the order they should be set.
+    pub headers: Vec<RecordHeader>,
+    /// Value of the record as text. A record without a value is a tombstone.
+    pub value: Option<String>,
+    /// Version of the schema registered for the key subject that the key should be serialized
+    /// with. When not specified the latest version is used.
+    pub key_schema_version: Option<Version>,
+    /// Version of the schema registered for the value subject that the value should be
+    /// serialized with. When not specified the latest version is used.
+    pub value_schema_version: Option<Version>,
+    /// Timestamp of the record in milliseconds since the Unix epoch. When not specified the
+    /// producer sets the timestamp to the time the record is sent.
+    pub timestamp: Option<i64>,
+}
+
+/// Contains the location of a record that was produced to a Kafka topic.
+#[derive(Clone, Debug)]
+pub struct ProducedRecord {
+    /// Name of the topic that the record was produced to.
+    pub topic: String,
+    /// Partition that the record was produced to.
+    pub partition: i32,
+    /// Offset of the record in the partition.
+    pub offset: i64,
+}
+
+/// Custom client context for the producer to handle logging.
+struct ProducerContext;
+
+impl ClientContext for ProducerContext {
+    /// Logs messages from the underlying rdkafka library using the tracing crate.
+    fn log(&self, level: RDKafkaLogLevel, fac: &str, log_message: &str) {
+        match level {
+            RDKafkaLogLevel::Emerg
+            | RDKafkaLogLevel::Alert
+            | RDKafkaLogLevel::Critical
+            | RDKafkaLogLevel::Error => {
+                tracing::error!("{} {}", fac, log_message);
+            }
+            RDKafkaLogLevel::Warning => tracing::warn!("{} {}", fac, log_message),
+            RDKafkaLogLevel::Notice | RDKafkaLogLevel::Info => {
+                tracing::info!("{} {}", fac, log_message);
+            }
+            RDKafkaLogLevel::Debug => tracing::debug!("{} {}", fac, log_message),
+        }
+    }
+}
+
+/// Defines the configuration used to create a new instance of [`Producer`].
+#[derive(Builder, Clone)]
+pub struct ProducerConfig {
+    /// Configuration properties that will be set for the Kafka producer.
+    props: HashMap<String, String>,
+}
+
+impl ProducerConfig {
+    /// Creates a new default [`ProducerConfigBuilder`] which can be used to create a new
+    /// [`ProducerConfig`].
+    pub fn builder() -> ProducerConfigBuilder {
+        ProducerConfigBuilder::default()
+    }
+}
+
+/// High-level Kafka producer used to publish records to Kafka topics.
+pub struct Producer {
+    /// Underlying Kafka producer.
+    producer: FutureProducer<ProducerContext>,
+}
+
+impl Producer {
+    /// Creates a new [`Producer`] using the specified [`ProducerConfig`].
+    pub fn new(config: ProducerConfig) -> anyhow::Result<Self> {
+        let mut client_config = ClientConfig::new();
+
+        // apply default config
+        client_config.set("message.timeout.ms", "30000");
+
+        // apply user config
+        client_config.extend(config.props);
+
+        if tracing::enabled!(tracing::Level::DEBUG) {
+            client_config.set("debug", "broker,topic,msg");
+
+            for (k, v) in client_config.config_map().iter() {
+                tracing::debug!("producer property {} set to {}", k, v);
+            }
+        }
+
+        let producer = client_config
+            .set_log_level(RDKafkaLogLevel::Debug)
+            .create_with_context(ProducerContext)
+            .context("create Kafka producer")?;
+
+        Ok(Self { producer })
+    }
+    /// Produces the [`ProduceRecord`] to its Kafka topic and waits for it to be acknowledged by
+    /// the broker, returning the partition and offset that the record was written to.
+    pub async fn send(&self, record: ProduceRecord) -> anyhow::Result<ProducedRecord> {
+        let headers = record.headers.iter().fold(
+            OwnedHeaders::new_with_capacity(record.headers.len()),
+            |hs, h| {
+                hs.insert(Header {
+                    key: h.key.as_str(),
+                    value: h.value.as_deref(),
+                })
+            },
+        );
+
+        let mut future_record: FutureRecord<'_, [u8], [u8]> =
+            FutureRecord::to(&record.topic).headers(headers);
+
+        if let Some(partition) = record.partition {
+            future_record = future_record.partition(partition);
+        }
+
+        if let Some(key) = record.key.as_deref() {
+            future_record = future_record.key(key);
+        }
+
+        if let Some(value) = record.value.as_deref() {
+            future_record = future_record.payload(value);
+        }
+
+        if let Some(timestamp) = record.timestamp {
+            future_record = future_record.timestamp(timestamp);
+        }
+
+        let delivery = self
+            .producer
+            .send(future_record, PRODUCE_QUEUE_TIMEOUT)
+            .await
+            .map_err(|(e, _)| e)
+            .with_context(|| format!("produce record to topic {}", record.topic))?;
+
+        tracing::info!(
+            "produced record to topic {} partition {} at offset {}",
+            record.topic,
+            delivery.partition,
+            delivery.offset
+        );
+
+        Ok(ProducedRecord {
+            topic: record.topic,
+            partition: delivery.partition,
+            offset: delivery.offset,
+        })
+    }
+}
@@ -254,6 +254,10 @@ where
             ttl,
         }
     }
+    /// Returns a reference to the underlying schema registry [`Client`].
+    pub fn client(&self) -> &C {
+        &self.client
+    }
     /// Loads all of the non-deleted subjects from the schema registry.
     pub async fn get_subjects(&self) -> anyhow::Result<Vec<Subject>> {
         let subjects = self
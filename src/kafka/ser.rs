@@ -0,0 +1,1278 @@
+use crate::kafka::{
+    Format,
+    producer::{ProduceRecord, ProduceRequest},
+    schema::{Schema, SchemaClient, SchemaRef, Subject, Version},
+};
+
+use anyhow::Context;
+use async_trait::async_trait;
+use base64::Engine;
+use protofish::{
+    context::{MessageField, MessageInfo, Multiplicity, ValueType},
+    decode::{EnumValue, FieldValue, MessageValue, Value},
+    prelude::Context as ProtoContext,
+};
+use schema_registry_client::{
+    rest::schema_registry_client::Client,
+    serdes::{
+        avro::AvroSerializer,
+        config::{SchemaSelector, SerializerConfig},
+        json::JsonSerializer,
+        serde::{SerdeFormat, SerdeType, SerializationContext},
+    },
+};
+use std::{collections::HashMap, sync::Arc};
+
+/// The first byte of Kafka record data serialized using the schema registry wire format.
+const MAGIC_BYTE: u8 = 0;
+
+/// Suffix appended to the topic name to determine the subject of the schema for record keys.
+const KEY_SUBJECT_SUFFIX: &str = "-key";
+
+/// Suffix appended to the topic name to determine the subject of the schema for record values.
+const VALUE_SUBJECT_SUFFIX: &str = "-value";
+
+/// A trait which defines the behavior required to serialize the key of a Kafka message from the
+/// String entered by the end user.
+#[async_trait]
+pub trait KeySerializer: Send + Sync {
+    /// Transforms the String representation of the key into bytes. The version selects the schema
+    /// that the key is serialized with when backed by the schema registry.
+    async fn serialize_key(
+        &self,
+        topic: &str,
+        version: Option<Version>,
+        data: &str,
+    ) -> anyhow::Result<Vec<u8>>;
+    /// Returns the [`Format`] that the key is serialized to.
+    fn format(&self) -> Format;
+}
+
+/// A trait which defines the behavior required to serialize the value of a Kafka message from the
+/// String entered by the end user.
+#[async_trait]
+pub trait ValueSerializer: Send + Sync {
+    /// Transforms the String representation of the value into bytes. The version selects the
+    /// schema that the value is serialized with when backed by the schema registry.
+    async fn serialize_value(
+        &self,
+        topic: &str,
+        version: Option<Version>,
+        data: &str,
+    ) -> anyhow::Result<Vec<u8>>;
+    /// Returns the [`Format`] that the value is serialized to.
+    fn format(&self) -> Format;
+}
+
+/// Serializes the key and value of a [`ProduceRequest`] using the configured [`KeySerializer`]
+/// and [`ValueSerializer`] to create the [`ProduceRecord`] that is sent to Kafka.
+#[derive(Clone)]
+pub struct RecordSerializer {
+    /// [`KeySerializer`] used to serialize the record key.
+    key_serializer: Arc<dyn KeySerializer>,
+    /// [`ValueSerializer`] used to serialize the record value.
+    value_serializer: Arc<dyn ValueSerializer>,
+}
+
+impl RecordSerializer {
+    /// Creates a new [`RecordSerializer`] with the given key and value serializers.
+    pub fn new(
+        key_serializer: Arc<dyn KeySerializer>,
+        value_serializer: Arc<dyn ValueSerializer>,
+    ) -> Self {
+        Self {
+            key_serializer,
+            value_serializer,
+        }
+    }
+    /// Serializes the key and value of the [`ProduceRequest`] into a [`ProduceRecord`] using the
+    /// schema versions selected for each, if any.
+    pub async fn serialize(&self, request: ProduceRequest) -> anyhow::Result<ProduceRecord> {
+        tracing::debug!(
+            "serializing record for topic {} with key format {} and value format {}",
+            request.topic,
+            self.key_serializer.format(),
+            self.value_serializer.format()
+        );
+
+        let key = match request.key {
+            Some(key) => Some(
+                self.key_serializer
+                    .serialize_key(&request.topic, request.key_schema_version, &key)
+                    .await
+                    .context("serialize record key")?,
+            ),
+            None => None,
+        };
+
+        let value = match request.value {
+            Some(value) => Some(
+                self.value_serializer
+                    .serialize_value(&request.topic, request.value_schema_version, &value)
+                    .await
+                    .context("serialize record value")?,
+            ),
+            None => None,
+        };
+
+        Ok(ProduceRecord {
+            topic: request.topic,
+            partition: request.partition,
+            key,
+            headers: request.headers,
+            value,
+            timestamp: request.timestamp,
+        })
+    }
+}
+
+/// Serializer implementation that converts the String directly to UTF-8 bytes.
+pub struct StringSerializer;
+
+#[async_trait]
+impl KeySerializer for StringSerializer {
+    /// Transforms the String into its UTF-8 bytes.
+    async fn serialize_key(
+        &self,
+        _topic: &str,
+        _version: Option<Version>,
+        data: &str,
+    ) -> anyhow::Result<Vec<u8>> {
+        Ok(data.as_bytes().to_vec())
+    }
+    /// Returns the [`Format`] that the key is serialized to.
+    fn format(&self) -> Format {
+        Format::None
+    }
+}
+
+#[async_trait]
+impl ValueSerializer for StringSerializer {
+    /// Transforms the String into its UTF-8 bytes.
+    async fn serialize_value(
+        &self,
+        _topic: &str,
+        _version: Option<Version>,
+        data: &str,
+    ) -> anyhow::Result<Vec<u8>> {
+        Ok(data.as_bytes().to_vec())
+    }
+    /// Returns the [`Format`] that the value is serialized to.
+    fn format(&self) -> Format {
+        Format::None
+    }
+}
+
+/// Serializer implementation that ensures the String is valid JSON without schema validation.
+pub struct JsonStringSerializer;
+
+impl JsonStringSerializer {
+    /// Parses the String as JSON and returns the bytes of its compact representation.
+    fn serialize(&self, data: &str) -> anyhow::Result<Vec<u8>> {
+        let json: serde_json::Value = serde_json::from_str(data).context("parse JSON data")?;
+
+        serde_json::to_vec(&json).context("create JSON bytes")
+    }
+}
+
+#[async_trait]
+impl KeySerializer for JsonStringSerializer {
+    /// Transforms the JSON String into bytes.
+    async fn serialize_key(
+        &self,
+        _topic: &str,
+        _version: Option<Version>,
+        data: &str,
+    ) -> anyhow::Result<Vec<u8>> {
+        self.serialize(data).context("serialize JSON key")
+    }
+    /// Returns the [`Format`] that the key is serialized to.
+    fn format(&self) -> Format {
+        Format::Json
+    }
+}
+
+#[async_trait]
+impl ValueSerializer for JsonStringSerializer {
+    /// Transforms the JSON String into bytes.
+    async fn serialize_value(
+        &self,
+        _topic: &str,
+        _version: Option<Version>,
+        data: &str,
+    ) -> anyhow::Result<Vec<u8>> {
+        self.serialize(data).context("serialize JSON value")
+    }
+    /// Returns the [`Format`] that the value is serialized to.
+    fn format(&self) -> Format {
+        Format::Json
+    }
+}
+
+/// Serializer implementation that uses the Confluent Schema Registry to validate JSON data
+/// against a JSON schema and serialize it using the schema registry wire format.
+pub struct JsonSchemaSerializer<C>
+where
+    C: Client,
+{
+    /// [`SchemaClient`] used to resolve the schema to serialize with.
+    schema_client: SchemaClient<C>,
+}
+
+impl<C> JsonSchemaSerializer<C>
+where
+    C: Client + Send + Sync,
+{
+    /// Creates a new [`JsonSchemaSerializer`] with the given [`SchemaClient`].
+    pub fn new(schema_client: SchemaClient<C>) -> Self {
+        Self { schema_client }
+    }
+    /// Validates the JSON data against the selected version of the schema registered for the
+    /// topic and serializes it.
+    async fn serialize(
+        &self,
+        topic: &str,
+        serde_type: SerdeType,
+        version: Option<Version>,
+        data: &str,
+    ) -> anyhow::Result<Vec<u8>> {
+        let schema = load_schema(&self.schema_client, topic, &serde_type, version).await?;
+
+        let json: serde_json::Value = serde_json::from_str(data).context("parse JSON data")?;
+
+        let json_serializer = JsonSerializer::new(
+            self.schema_client.client(),
+            None,
+            None,
+            serializer_config(&schema),
+        )
+        .map_err(|e| anyhow::anyhow!("unable to create JSON serializer: {}", e))?;
+
+        let ctx = SerializationContext {
+            topic: topic.to_string(),
+            serde_type,
+            serde_format: SerdeFormat::Json,
+            headers: None,
+        };
+
+        json_serializer
+            .serialize(&ctx, json)
+            .await
+            .map_err(|e| anyhow::anyhow!("unable to serialize JSON data: {}", e))
+    }
+}
+
+#[async_trait]
+impl<C> KeySerializer for JsonSchemaSerializer<C>
+where
+    C: Client + Send + Sync,
+{
+    /// Transforms the JSON String into bytes using the JSON schema serializer.
+    async fn serialize_key(
+        &self,
+        topic: &str,
+        version: Option<Version>,
+        data: &str,
+    ) -> anyhow::Result<Vec<u8>> {
+        self.serialize(topic, SerdeType::Key, version, data)
+            .await
+            .context("serialize JSON key")
+    }
+    /// Returns the [`Format`] that the key is serialized to.
+    fn format(&self) -> Format {
+        Format::Json
+    }
+}
+
+#[async_trait]
+impl<C> ValueSerializer for JsonSchemaSerializer<C>
+where
+    C: Client + Send + Sync,
+{
+    /// Transforms the JSON String into bytes using the JSON schema serializer.
+    async fn serialize_value(
+        &self,
+        topic: &str,
+        version: Option<Version>,
+        data: &str,
+    ) -> anyhow::Result<Vec<u8>> {
+        self.serialize(topic, SerdeType::Value, version, data)
+            .await
+            .context("serialize JSON value")
+    }
+    /// Returns the [`Format`] that the value is serialized to.
+    fn format(&self) -> Format {
+        Format::Json
+    }
+}
+
+/// Serializer implementation that uses the Confluent Schema Registry to convert JSON data to an
+/// Avro value conforming to the registered schema and serialize it using the schema registry wire
+/// format.
+pub struct AvroSchemaSerializer<C>
+where
+    C: Client,
+{
+    /// [`SchemaClient`] used to resolve the schema to serialize with.
+    schema_client: SchemaClient<C>,
+}
+
+impl<C> AvroSchemaSerializer<C>
+where
+    C: Client + Send + Sync,
+{
+    /// Creates a new [`AvroSchemaSerializer`] with the given [`SchemaClient`].
+    pub fn new(schema_client: SchemaClient<C>) -> Self {
+        Self { schema_client }
+    }
+    /// Converts the JSON data to an Avro value resolved against the selected version of the
+    /// schema registered for the topic and serializes it.
+    async fn serialize(
+        &self,
+        topic: &str,
+        serde_type: SerdeType,
+        version: Option<Version>,
+        data: &str,
+    ) -> anyhow::Result<Vec<u8>> {
+        let schema = load_schema(&self.schema_client, topic, &serde_type, version).await?;
+
+        let references = load_references(&self.schema_client, &schema)
+            .await
+            .context("load schema references")?;
+
+        let (avro_schema, referenced_schemas) =
+            apache_avro::Schema::parse_str_with_list(&schema.schema, references)
+                .context("parse Avro schema")?;
+
+        // named types are only resolved from the list of schemas when one is provided, so the
+        // schema itself must be included when it has references
+        let schemata = match referenced_schemas.is_empty() {
+            true => Vec::new(),
+            false => referenced_schemas
+                .iter()
+                .chain(std::iter::once(&avro_schema))
+                .collect(),
+        };
+
+        let json: serde_json::Value = serde_json::from_str(data).context("parse JSON data")?;
+
+        let value = apache_avro::types::Value::from(json)
+            .resolve_schemata(&avro_schema, schemata)
+            .context("JSON data does not match Avro schema")?;
+
+        let avro_serializer = AvroSerializer::new(
+            self.schema_client.client(),
+            None,
+            None,
+            serializer_config(&schema),
+        )
+        .map_err(|e| anyhow::anyhow!("unable to create Avro serializer: {}", e))?;
+
+        let ctx = SerializationContext {
+            topic: topic.to_string(),
+            serde_type,
+            serde_format: SerdeFormat::Avro,
+            headers: None,
+        };
+
+        avro_serializer
+            .serialize(&ctx, value)
+            .await
+            .map_err(|e| anyhow::anyhow!("unable to serialize Avro data: {}", e))
+    }
+}
+
+#[async_trait]
+impl<C> KeySerializer for AvroSchemaSerializer<C>
+where
+    C: Client + Send + Sync,
+{
+    /// Transforms the JSON String into bytes using the Avro schema serializer.
+    async fn serialize_key(
+        &self,
+        topic: &str,
+        version: Option<Version>,
+        data: &str,
+    ) -> anyhow::Result<Vec<u8>> {
+        self.serialize(topic, SerdeType::Key, version, data)
+            .await
+            .context("serialize Avro key")
+    }
+    /// Returns the [`Format`] that the key is serialized to.
+    fn format(&self) -> Format {
+        Format::Avro
+    }
+}
+
+#[async_trait]
+impl<C> ValueSerializer for AvroSchemaSerializer<C>
+where
+    C: Client + Send + Sync,
+{
+    /// Transforms the JSON String into bytes using the Avro schema serializer.
+    async fn serialize_value(
+        &self,
+        topic: &str,
+        version: Option<Version>,
+        data: &str,
+    ) -> anyhow::Result<Vec<u8>> {
+        self.serialize(topic, SerdeType::Value, version, data)
+            .await
+            .context("serialize Avro value")
+    }
+    /// Returns the [`Format`] that the value is serialized to.
+    fn format(&self) -> Format {
+        Format::Avro
+    }
+}
+
+/// Serializer implementation that encodes JSON data as a Protobuf message using the definition of
+/// the message type in the schema registered for the topic and frames it using the schema
+/// registry wire format.
+pub struct ProtobufSchemaSerializer<C>
+where
+    C: Client,
+{
+    /// [`SchemaClient`] used to resolve the schema to serialize with.
+    schema_client: SchemaClient<C>,
+    /// Fully qualified Protobuf message type to serialize the Kafka record key data from.
+    key_type: Option<String>,
+    /// Fully qualified Protobuf message type to serialize the Kafka record value data from.
+    value_type: Option<String>,
+}
+
+impl<C> ProtobufSchemaSerializer<C>
+where
+    C: Client + Send + Sync,
+{
+    /// Creates a new [`ProtobufSchemaSerializer`].
+    pub fn new(
+        schema_client: SchemaClient<C>,
+        key_type: Option<impl Into<String>>,
+        value_type: Option<impl Into<String>>,
+    ) -> Self {
+        Self {
+            schema_client,
+            key_type: key_type.map(|k| k.into()),
+            value_type: value_type.map(|k| k.into()),
+        }
+    }
+    /// Encodes the JSON data as the specified message type defined in the selected version of the
+    /// schema registered for the topic and frames it with the schema identifier and the indexes
+    /// of the message type within the schema.
+    async fn serialize(
+        &self,
+        topic: &str,
+        serde_type: SerdeType,
+        message_type: &str,
+        version: Option<Version>,
+        data: &str,
+    ) -> anyhow::Result<Vec<u8>> {
+        let schema = load_schema(&self.schema_client, topic, &serde_type, version).await?;
+
+        let references = load_references(&self.schema_client, &schema)
+            .await
+            .context("load schema references")?;
+
+        // the schema itself must be the first file so that the message indexes are resolved
+        // against its declarations rather than those of the referenced schemas
+        let definitions: Vec<String> = std::iter::once(schema.schema.clone())
+            .chain(references)
+            .collect();
+
+        let declarations = ProtoDeclarations::scan(&definitions);
+
+        // the protobuf context cannot parse map fields, so they are rejected up front rather than
+        // failing while the schema is parsed
+        if let Some(field) = declarations.map_fields.first() {
+            anyhow::bail!(
+                "protobuf map field {} in schema {} version {} is not supported",
+                field,
+                schema.id,
+                schema.version
+            );
+        }
+
+        let context = ProtoContext::parse(&definitions).context("parse Protobuf schema")?;
+
+        let message_type = message_type.trim_start_matches('.');
+
+        let Some(msg_info) = context.get_message(message_type) else {
+            anyhow::bail!(
+                "protobuf message type {} is not defined in schema {} version {}",
+                message_type,
+                schema.id,
+                schema.version
+            );
+        };
+
+        let Some(message_indexes) = proto_message_indexes(&schema.schema, message_type) else {
+            anyhow::bail!(
+                "protobuf message type {} is not declared in schema {} version {}, it is only \
+                 defined by a referenced schema",
+                message_type,
+                schema.id,
+                schema.version
+            );
+        };
+
+        let json: serde_json::Value = serde_json::from_str(data).context("parse JSON data")?;
+
+        let msg_value = json_to_message(&context, &declarations, msg_info, &json)
+            .with_context(|| format!("convert JSON data to protobuf message {}", message_type))?;
+
+        let mut bytes = vec![MAGIC_BYTE];
+        bytes.extend_from_slice(&schema.id.to_be_bytes());
+        bytes.extend_from_slice(&encode_message_indexes(&message_indexes));
+        bytes.extend_from_slice(&msg_value.encode(&context));
+
+        Ok(bytes)
+    }
+}
+#[async_trait]
+impl<C> KeySerializer for ProtobufSchemaSerializer<C>
+where
+    C: Client + Send + Sync,
+{
+    /// Transforms the JSON String into bytes using the Protobuf schema serializer.
+    async fn serialize_key(
+        &self,
+        topic: &str,
+        version: Option<Version>,
+        data: &str,
+    ) -> anyhow::Result<Vec<u8>> {
+        let Some(message_type) = self.key_type.as_ref() else {
+            anyhow::bail!("no protobuf message type configured for key serialization");
+        };
+
+        self.serialize(topic, SerdeType::Key, message_type, version, data)
+            .await
+            .context("serialize Protobuf key")
+    }
+    /// Returns the [`Format`] that the key is serialized to.
+    fn format(&self) -> Format {
+        Format::Protobuf
+    }
+}
+
+#[async_trait]
+impl<C> ValueSerializer for ProtobufSchemaSerializer<C>
+where
+    C: Client + Send + Sync,
+{
+    /// Transforms the JSON String into bytes using the Protobuf schema serializer.
+    async fn serialize_value(
+        &self,
+        topic: &str,
+        version: Option<Version>,
+        data: &str,
+    ) -> anyhow::Result<Vec<u8>> {
+        let Some(message_type) = self.value_type.as_ref() else {
+            anyhow::bail!("no protobuf message type configured for value serialization");
+        };
+
+        self.serialize(topic, SerdeType::Value, message_type, version, data)
+            .await
+            .context("serialize Protobuf value")
+    }
+    /// Returns the [`Format`] that the value is serialized to.
+    fn format(&self) -> Format {
+        Format::Protobuf
+    }
+}
+
+/// Loads the specified version of the schema registered for the key or value subject of the
+/// topic, or the latest version if no version is specified.
+async fn load_schema<C>(
+    schema_client: &SchemaClient<C>,
+    topic: &str,
+    serde_type: &SerdeType,
+    version: Option<Version>,
+) -> anyhow::Result<Schema>
+where
+    C: Client + Send + Sync,
+{
+    let suffix = match serde_type {
+        SerdeType::Key => KEY_SUBJECT_SUFFIX,
+        SerdeType::Value => VALUE_SUBJECT_SUFFIX,
+    };
+
+    let subject = Subject::from(format!("{}{}", topic, suffix));
+
+    schema_client
+        .get_schema(&subject, version)
+        .await
+        .with_context(|| format!("load schema for subject {}", subject))
+}
+
+/// Loads the definitions of all schemas referenced, directly or transitively, by the given
+/// [`Schema`].
+async fn load_references<C>(
+    schema_client: &SchemaClient<C>,
+    schema: &Schema,
+) -> anyhow::Result<Vec<String>>
+where
+    C: Client + Send + Sync,
+{
+    let mut definitions = Vec::new();
+
+    let mut pending: Vec<SchemaRef> = schema.references.clone().unwrap_or_default();
+
+    while let Some(reference) = pending.pop() {
+        let referenced = schema_client
+            .get_schema(&reference.subject, Some(reference.version))
+            .await
+            .with_context(|| format!("load referenced schema {}", reference.name))?;
+
+        pending.extend(referenced.references.unwrap_or_default());
+        definitions.push(referenced.schema);
+    }
+
+    Ok(definitions)
+}
+
+/// Creates the [`SerializerConfig`] which serializes data with the given [`Schema`] and validates
+/// it before serialization. Schemas are never registered automatically.
+fn serializer_config(schema: &Schema) -> SerializerConfig {
+    SerializerConfig::new(
+        false,
+        Some(SchemaSelector::SchemaId(schema.id)),
+        false,
+        true,
+        HashMap::new(),
+    )
+}
+
+/// Converts a JSON number, or a string containing one as is common for 64-bit integers, into an
+/// integer of the requested type.
+fn json_to_int<T>(json: &serde_json::Value) -> Option<T>
+where
+    T: TryFrom<i64> + TryFrom<u64>,
+{
+    match json {
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => T::try_from(i).ok(),
+            None => n.as_u64().and_then(|u| T::try_from(u).ok()),
+        },
+        serde_json::Value::String(s) => match s.parse::<i64>() {
+            Ok(i) => T::try_from(i).ok(),
+            Err(_) => s.parse::<u64>().ok().and_then(|u| T::try_from(u).ok()),
+        },
+        _ => None,
+    }
+}
+
+/// Recursively converts a JSON object to a Protobuf message value of the given message type.
+fn json_to_message(
+    context: &ProtoContext,
+    declarations: &ProtoDeclarations,
+    msg_info: &MessageInfo,
+    json: &serde_json::Value,
+) -> anyhow::Result<MessageValue> {
+    let Some(object) = json.as_object() else {
+        anyhow::bail!("expected JSON object for message {}", msg_info.full_name);
+    };
+
+    let mut fields = Vec::new();
+
+    for (name, json_value) in object {
+        let Some(field) = msg_info.get_field_by_name(name) else {
+            anyhow::bail!("unknown field {} for message {}", name, msg_info.full_name);
+        };
+
+        if json_value.is_null() {
+            continue;
+        }
+
+        match field.multiplicity {
+            Multiplicity::Repeated | Multiplicity::RepeatedPacked => {
+                let Some(items) = json_value.as_array() else {
+                    anyhow::bail!("expected JSON array for repeated field {}", name);
+                };
+
+                for item in items {
+                    fields.push(FieldValue {
+                        number: field.number,
+                        value: json_to_value(context, declarations, field, item)?,
+                    });
+                }
+            }
+            Multiplicity::Single | Multiplicity::Optional => fields.push(FieldValue {
+                number: field.number,
+                value: json_to_value(context, declarations, field, json_value)?,
+            }),
+        }
+    }
+
+    Ok(MessageValue {
+        msg_ref: msg_info.self_ref,
+        fields,
+        garbage: None,
+    })
+}
+
+/// Converts a single JSON value to the Protobuf value for the type of the given field. Enum
+/// values are specified either by their name or by their number.
+fn json_to_value(
+    context: &ProtoContext,
+    declarations: &ProtoDeclarations,
+    field: &MessageField,
+    json: &serde_json::Value,
+) -> anyhow::Result<Value> {
+    let invalid = || {
+        anyhow::anyhow!(
+            "invalid value {} for field {} of type {:?}",
+            json,
+            field.name,
+            field.field_type
+        )
+    };
+
+    let value = match field.field_type {
+        ValueType::Double => Value::Double(json.as_f64().ok_or_else(invalid)?),
+        ValueType::Float => Value::Float(json.as_f64().ok_or_else(invalid)? as f32),
+        ValueType::Int32 => Value::Int32(json_to_int(json).ok_or_else(invalid)?),
+        ValueType::Int64 => Value::Int64(json_to_int(json).ok_or_else(invalid)?),
+        ValueType::UInt32 => Value::UInt32(json_to_int(json).ok_or_else(invalid)?),
+        ValueType::UInt64 => Value::UInt64(json_to_int(json).ok_or_else(invalid)?),
+        ValueType::SInt32 => Value::SInt32(json_to_int(json).ok_or_else(invalid)?),
+        ValueType::SInt64 => Value::SInt64(json_to_int(json).ok_or_else(invalid)?),
+        ValueType::Fixed32 => Value::Fixed32(json_to_int(json).ok_or_else(invalid)?),
+        ValueType::Fixed64 => Value::Fixed64(json_to_int(json).ok_or_else(invalid)?),
+        ValueType::SFixed32 => Value::SFixed32(json_to_int(json).ok_or_else(invalid)?),
+        ValueType::SFixed64 => Value::SFixed64(json_to_int(json).ok_or_else(invalid)?),
+        ValueType::Bool => Value::Bool(json.as_bool().ok_or_else(invalid)?),
+        ValueType::String => Value::String(json.as_str().ok_or_else(invalid)?.to_string()),
+        ValueType::Bytes => {
+            let encoded = json.as_str().ok_or_else(invalid)?;
+
+            let bytes = base64::engine::general_purpose::STANDARD
+                .decode(encoded)
+                .with_context(|| format!("decode base64 bytes for field {}", field.name))?;
+
+            Value::Bytes(bytes.into())
+        }
+        ValueType::Message(msg_ref) => {
+            let child_info = context.resolve_message(msg_ref);
+
+            Value::Message(Box::new(json_to_message(
+                context,
+                declarations,
+                child_info,
+                json,
+            )?))
+        }
+        ValueType::Enum(enum_ref) => {
+            let enum_info = context.resolve_enum(enum_ref);
+
+            // the protobuf context only supports looking up enum fields by value, so names are
+            // resolved using the enum values declared in the schema definitions
+            let value = json_to_int(json)
+                .or_else(|| {
+                    json.as_str()
+                        .and_then(|name| declarations.enum_value(&enum_info.full_name, name))
+                })
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "invalid value {} for field {} of enum {}",
+                        json,
+                        field.name,
+                        enum_info.full_name
+                    )
+                })?;
+
+            Value::Enum(EnumValue { enum_ref, value })
+        }
+    };
+
+    Ok(value)
+}
+
+/// Scope of a Protobuf schema definition that is tracked while scanning it for message
+/// declarations.
+enum ProtoScope {
+    /// The top level of the schema or the body of a message, in which message types are declared.
+    Messages {
+        /// Message indexes leading to the scope, empty at the top level.
+        indexes: Vec<i32>,
+        /// Fully qualified name of the scope, i.e. the package or the message name.
+        name: String,
+        /// Number of message types declared in the scope so far.
+        declared: i32,
+    },
+    /// Any other block such as an enum, oneof, service or option value.
+    Other,
+}
+
+/// Determines the message indexes of the fully qualified message type in the Protobuf schema
+/// definition, i.e. the index of each enclosing message and of the message type itself in
+/// declaration order. Returns [`None`] if the message type is not declared in the schema.
+fn proto_message_indexes(schema: &str, message_type: &str) -> Option<Vec<i32>> {
+    let tokens = proto_tokens(schema);
+
+    let mut scopes = vec![ProtoScope::Messages {
+        indexes: Vec::new(),
+        name: String::new(),
+        declared: 0,
+    }];
+
+    let mut i = 0;
+
+    while i < tokens.len() {
+        let token = tokens[i];
+
+        let next = |n: usize| tokens.get(i + n).copied().unwrap_or_default();
+
+        let top_level = scopes.len() == 1;
+
+        match (token, scopes.last_mut()) {
+            ("package", Some(ProtoScope::Messages { name, .. })) if top_level => {
+                *name = next(1).to_string();
+                i += 2;
+            }
+            // groups declare a message type named after the group alongside their field
+            (
+                "message" | "group",
+                Some(ProtoScope::Messages {
+                    indexes,
+                    name,
+                    declared,
+                }),
+            ) if (token == "message" && next(2) == "{")
+                || (token == "group" && next(2) == "=" && next(4) == "{") =>
+            {
+                let mut message_indexes = indexes.clone();
+                message_indexes.push(*declared);
+                *declared += 1;
+
+                let message_name = match name.is_empty() {
+                    true => next(1).to_string(),
+                    false => format!("{}.{}", name, next(1)),
+                };
+
+                if message_name == message_type {
+                    return Some(message_indexes);
+                }
+
+                scopes.push(ProtoScope::Messages {
+                    indexes: message_indexes,
+                    name: message_name,
+                    declared: 0,
+                });
+
+                i += if token == "message" { 3 } else { 5 };
+            }
+            // map fields declare a nested entry message type in the message descriptor
+            ("map", Some(ProtoScope::Messages { declared, .. })) if next(1) == "<" => {
+                *declared += 1;
+                i += 2;
+            }
+            ("{", _) => {
+                scopes.push(ProtoScope::Other);
+                i += 1;
+            }
+            ("}", _) => {
+                scopes.pop();
+                i += 1;
+            }
+            _ => i += 1,
+        }
+    }
+
+    None
+}
+
+/// Scope of a Protobuf schema definition that is tracked while scanning it for the declarations
+/// that the protobuf context does not expose.
+enum ProtoDeclarationScope {
+    /// The body of a message with the given fully qualified name.
+    Message(String),
+    /// The body of an enum with the given fully qualified name.
+    Enum(String),
+    /// Any other block such as a oneof, service or option value.
+    Other,
+}
+
+/// Declarations of Protobuf schema definitions that are not exposed by the protobuf context.
+#[derive(Debug, Default)]
+struct ProtoDeclarations {
+    /// Values of each enum keyed by the fully qualified name of the enum and then the value name.
+    enum_values: HashMap<String, HashMap<String, i64>>,
+    /// Fully qualified names of the map fields declared in the schema definitions.
+    map_fields: Vec<String>,
+}
+
+impl ProtoDeclarations {
+    /// Scans the Protobuf schema definitions for enum values and map fields.
+    fn scan(schemas: &[String]) -> Self {
+        let mut declarations = Self::default();
+
+        for schema in schemas {
+            declarations.scan_schema(schema);
+        }
+
+        declarations
+    }
+    /// Scans a single Protobuf schema definition for enum values and map fields.
+    fn scan_schema(&mut self, schema: &str) {
+        let tokens = proto_tokens(schema);
+
+        let mut package = String::new();
+
+        let mut scopes: Vec<ProtoDeclarationScope> = Vec::new();
+
+        let mut i = 0;
+
+        while i < tokens.len() {
+            let token = tokens[i];
+
+            let next = |n: usize| tokens.get(i + n).copied().unwrap_or_default();
+
+            let qualify = |name: &str| match scopes.last() {
+                Some(ProtoDeclarationScope::Message(parent)) => format!("{}.{}", parent, name),
+                _ if package.is_empty() => name.to_string(),
+                _ => format!("{}.{}", package, name),
+            };
+
+            match (token, scopes.last()) {
+                ("package", None) => {
+                    package = next(1).to_string();
+                    i += 2;
+                }
+                ("message", None | Some(ProtoDeclarationScope::Message(_))) if next(2) == "{" => {
+                    scopes.push(ProtoDeclarationScope::Message(qualify(next(1))));
+                    i += 3;
+                }
+                // groups declare a message type named after the group alongside their field
+                ("group", Some(ProtoDeclarationScope::Message(_)))
+                    if next(2) == "=" && next(4) == "{" =>
+                {
+                    scopes.push(ProtoDeclarationScope::Message(qualify(next(1))));
+                    i += 5;
+                }
+                ("enum", None | Some(ProtoDeclarationScope::Message(_))) if next(2) == "{" => {
+                    let name = qualify(next(1));
+
+                    self.enum_values.entry(name.clone()).or_default();
+                    scopes.push(ProtoDeclarationScope::Enum(name));
+                    i += 3;
+                }
+                ("map", Some(ProtoDeclarationScope::Message(message))) if next(1) == "<" => {
+                    let close = tokens[i..].iter().position(|t| *t == ">").unwrap_or(0);
+
+                    let field = tokens.get(i + close + 1).copied().unwrap_or_default();
+
+                    self.map_fields.push(format!("{}.{}", message, field));
+                    i += close + 1;
+                }
+                (name, Some(ProtoDeclarationScope::Enum(enum_name)))
+                    if next(1) == "=" && name != "option" =>
+                {
+                    let (number, len) = match next(2) {
+                        "-" => (parse_proto_int(next(3)).map(|n| -n), 4),
+                        n => (parse_proto_int(n), 3),
+                    };
+
+                    if let Some(number) = number {
+                        self.enum_values
+                            .entry(enum_name.clone())
+                            .or_default()
+                            .insert(name.to_string(), number);
+                    }
+
+                    i += len;
+                }
+                ("{", _) => {
+                    scopes.push(ProtoDeclarationScope::Other);
+                    i += 1;
+                }
+                ("}", _) => {
+                    scopes.pop();
+                    i += 1;
+                }
+                _ => i += 1,
+            }
+        }
+    }
+    /// Returns the number of the named value of the enum with the fully qualified name, if the
+    /// enum declares it.
+    fn enum_value(&self, enum_type: &str, name: &str) -> Option<i64> {
+        self.enum_values.get(enum_type)?.get(name).copied()
+    }
+}
+
+/// Parses a decimal, hexadecimal or octal integer literal from a Protobuf schema definition.
+fn parse_proto_int(literal: &str) -> Option<i64> {
+    if let Some(hex) = literal
+        .strip_prefix("0x")
+        .or_else(|| literal.strip_prefix("0X"))
+    {
+        i64::from_str_radix(hex, 16).ok()
+    } else if literal.len() > 1 && literal.starts_with('0') {
+        i64::from_str_radix(&literal[1..], 8).ok()
+    } else {
+        literal.parse().ok()
+    }
+}
+
+/// Splits the Protobuf schema definition into identifiers, string literals and symbols, skipping
+/// whitespace and comments.
+fn proto_tokens(schema: &str) -> Vec<&str> {
+    let bytes = schema.as_bytes();
+
+    let is_ident = |b: u8| b.is_ascii_alphanumeric() || b == b'_' || b == b'.';
+
+    let mut tokens = Vec::new();
+
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let rest = &schema[i..];
+
+        if bytes[i].is_ascii_whitespace() {
+            i += 1;
+        } else if rest.starts_with("//") {
+            i += rest.find('\n').unwrap_or(rest.len());
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            i += comment.find("*/").map(|n| n + 4).unwrap_or(rest.len());
+        } else if bytes[i] == b'"' || bytes[i] == b'\'' {
+            let start = i;
+            let quote = bytes[i];
+
+            i += 1;
+
+            while i < bytes.len() && bytes[i] != quote {
+                i += if bytes[i] == b'\\' { 2 } else { 1 };
+            }
+
+            i = (i + 1).min(bytes.len());
+            tokens.push(&schema[start..i]);
+        } else if is_ident(bytes[i]) {
+            let start = i;
+
+            while i < bytes.len() && is_ident(bytes[i]) {
+                i += 1;
+            }
+
+            tokens.push(&schema[start..i]);
+        } else {
+            let len = rest.chars().next().map(char::len_utf8).unwrap_or(1);
+
+            tokens.push(&rest[..len]);
+            i += len;
+        }
+    }
+
+    tokens
+}
+
+/// Encodes the message indexes of a Protobuf message type as they are written after the schema
+/// identifier in the schema registry wire format. The common case of the first message type in
+/// the schema is written as a single 0 byte, otherwise the number of indexes is followed by the
+/// indexes themselves, all as zigzag encoded variable length integers.
+fn encode_message_indexes(indexes: &[i32]) -> Vec<u8> {
+    if indexes == [0] {
+        return vec![0];
+    }
+
+    let mut bytes = Vec::new();
+
+    for value in std::iter::once(indexes.len() as i32).chain(indexes.iter().copied()) {
+        let mut zigzag = ((value << 1) ^ (value >> 31)) as u32;
+
+        while zigzag >= 0x80 {
+            bytes.push((zigzag as u8) | 0x80);
+            zigzag >>= 7;
+        }
+
+        bytes.push(zigzag as u8);
+    }
+
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Schema declaring nested messages, a map field, a group and enums used by these tests.
+    const SCHEMA: &str = r#"
+        syntax = "proto2";
+
+        package com.acme;
+
+        // message Commented { }
+        message Order {
+            /* message AlsoCommented { } */
+            optional string note = 1 [default = "message Quoted { }"];
+            map<string, int32> counts = 2;
+
+            message Line {
+                optional string sku = 1;
+            }
+
+            enum Status {
+                option allow_alias = true;
+                UNKNOWN = 0;
+                OPEN = 1;
+                CLOSED = 0x2;
+                VOID = -1;
+            }
+        }
+
+        message Customer {
+            optional group Address = 1 {
+                optional string city = 1;
+            }
+
+            message Profile {
+                message Preferences {
+                    optional bool email = 1;
+                }
+            }
+        }
+    "#;
+
+    /// Schema without map fields that the protobuf context is able to parse.
+    const ENUM_SCHEMA: &str = r#"
+        syntax = "proto3";
+
+        package com.acme;
+
+        message Shipment {
+            Carrier carrier = 1;
+            repeated Carrier fallbacks = 2;
+
+            enum Carrier {
+                UNKNOWN = 0;
+                POST = 1;
+                COURIER = 2;
+            }
+        }
+    "#;
+
+    /// Converts the JSON to a `com.acme.Shipment` message and returns the enum values of its
+    /// fields.
+    fn shipment_enums(json: serde_json::Value) -> anyhow::Result<Vec<(u64, i64)>> {
+        let schemas = vec![String::from(ENUM_SCHEMA)];
+
+        let context = ProtoContext::parse(&schemas).expect("schema parses");
+
+        let declarations = ProtoDeclarations::scan(&schemas);
+
+        let msg_info = context.get_message("com.acme.Shipment").expect("message");
+
+        let message = json_to_message(&context, &declarations, msg_info, &json)?;
+
+        let enums = message
+            .fields
+            .into_iter()
+            .map(|f| match f.value {
+                Value::Enum(e) => (f.number, e.value),
+                other => panic!("expected enum value, found {:?}", other),
+            })
+            .collect();
+
+        Ok(enums)
+    }
+
+    #[test]
+    fn message_indexes_of_top_level_messages() {
+        assert_eq!(
+            proto_message_indexes(SCHEMA, "com.acme.Order"),
+            Some(vec![0])
+        );
+        assert_eq!(
+            proto_message_indexes(SCHEMA, "com.acme.Customer"),
+            Some(vec![1])
+        );
+    }
+
+    #[test]
+    fn message_indexes_of_nested_messages() {
+        // the map field declares an entry message ahead of Line
+        assert_eq!(
+            proto_message_indexes(SCHEMA, "com.acme.Order.Line"),
+            Some(vec![0, 1])
+        );
+        assert_eq!(
+            proto_message_indexes(SCHEMA, "com.acme.Customer.Address"),
+            Some(vec![1, 0])
+        );
+        assert_eq!(
+            proto_message_indexes(SCHEMA, "com.acme.Customer.Profile.Preferences"),
+            Some(vec![1, 1, 0])
+        );
+    }
+
+    #[test]
+    fn message_indexes_ignore_comments_strings_and_unknown_types() {
+        assert_eq!(proto_message_indexes(SCHEMA, "com.acme.Commented"), None);
+        assert_eq!(
+            proto_message_indexes(SCHEMA, "com.acme.Order.AlsoCommented"),
+            None
+        );
+        assert_eq!(proto_message_indexes(SCHEMA, "com.acme.Order.Quoted"), None);
+        assert_eq!(proto_message_indexes(SCHEMA, "Order"), None);
+    }
+
+    #[test]
+    fn message_indexes_without_package() {
+        let schema = "message A { message B { } } message C { }";
+
+        assert_eq!(proto_message_indexes(schema, "A.B"), Some(vec![0, 0]));
+        assert_eq!(proto_message_indexes(schema, "C"), Some(vec![1]));
+    }
+
+    #[test]
+    fn first_message_is_encoded_as_a_single_zero() {
+        assert_eq!(encode_message_indexes(&[0]), vec![0]);
+    }
+
+    #[test]
+    fn message_indexes_are_encoded_as_zigzag_varints() {
+        assert_eq!(encode_message_indexes(&[1]), vec![2, 2]);
+        assert_eq!(encode_message_indexes(&[0, 1]), vec![4, 0, 2]);
+        assert_eq!(encode_message_indexes(&[1, 1, 0]), vec![6, 2, 2, 0]);
+        // 64 is zigzag encoded as 128, which no longer fits in a single byte
+        assert_eq!(encode_message_indexes(&[64]), vec![2, 0x80, 0x01]);
+        assert_eq!(encode_message_indexes(&[0, 8191]), vec![4, 0, 0xfe, 0x7f]);
+    }
+
+    #[test]
+    fn declarations_include_enum_values_and_map_fields() {
+        let declarations = ProtoDeclarations::scan(&[String::from(SCHEMA)]);
+
+        let status = "com.acme.Order.Status";
+
+        assert_eq!(declarations.enum_value(status, "UNKNOWN"), Some(0));
+        assert_eq!(declarations.enum_value(status, "OPEN"), Some(1));
+        assert_eq!(declarations.enum_value(status, "CLOSED"), Some(2));
+        assert_eq!(declarations.enum_value(status, "VOID"), Some(-1));
+        assert_eq!(declarations.enum_value(status, "allow_alias"), None);
+        assert_eq!(declarations.enum_value("com.acme.Status", "OPEN"), None);
+        assert_eq!(declarations.map_fields, vec!["com.acme.Order.counts"]);
+    }
+
+    #[test]
+    fn enums_are_converted_from_names_or_numbers() {
+        let json = serde_json::json!({ "carrier": "COURIER", "fallbacks": ["POST", 0, "2"] });
+
+        assert_eq!(
+            shipment_enums(json).expect("converts"),
+            vec![(1, 2), (2, 1), (2, 0), (2, 2)]
+        );
+    }
+
+    #[test]
+    fn unknown_enum_names_name_the_field() {
+        let err = shipment_enums(serde_json::json!({ "carrier": "PIGEON" }))
+            .expect_err("unknown enum name")
+            .to_string();
+
+        assert_eq!(
+            err,
+            "invalid value \"PIGEON\" for field carrier of enum com.acme.Shipment.Carrier"
+        );
+    }
+}
@@ -8,6 +8,7 @@ mod util;
 use crate::{
     app::{
         config::{Config, PersistedConfig},
+        import::{ImportRequest, Importer},
         App,
     },
     kafka::{
@@ -15,20 +16,26 @@ use crate::{
             AvroSchemaDeserializer, JsonSchemaDeserializer, JsonStringDeserializer,
             KeyDeserializer, ProtobufSchemaDeserializer, StringDeserializer, ValueDeserializer,
         },
-        Format, SeekTo,
+        producer::{Producer, ProducerConfig},
+        schema::{DEFAULT_CACHE_TTL, SchemaClient},
+        ser::{
+            AvroSchemaSerializer, JsonSchemaSerializer, JsonStringSerializer, KeySerializer,
+            ProtobufSchemaSerializer, RecordSerializer, StringSerializer, ValueSerializer,
+        },
+        AssignmentMode, CommitStrategy, ConsumeUntil, Format, SeekTo,
     },
     trace::{CaptureLayer, Log},
 };
 
 use anyhow::Context;
 use chrono::Local;
-use clap::Parser;
+use clap::{Args, Parser, Subcommand};
 use config::{ConfigError, Map, Source, Value};
 use schema_registry_client::rest::{
     client_config::ClientConfig,
     schema_registry_client::{Client, SchemaRegistryClient},
 };
-use std::{fs::File, io::BufReader, sync::Arc};
+use std::{fs::File, io::BufReader, num::NonZeroU32, sync::Arc};
 use tokio::sync::mpsc::Receiver;
 use tracing::level_filters::LevelFilter;
 use tracing_subscriber::{prelude::*, EnvFilter, Registry};
@@ -41,9 +48,11 @@ struct Cli {
     /// records.
     #[arg(short, long)]
     bootstrap_servers: Option<String>,
-    /// Name of the Kafka topic which records will be consumed from. If no topic is specified, the
-    /// application will fallback to displaying the list of topics available on the Kafka cluster
-    /// for the user to browse.
+    /// Name of the Kafka topic which records will be consumed from. A CSV of topic names can be
+    /// specified to consume from multiple topics, and any entry prefixed with `^` is treated as a
+    /// regular expression matched against the topics on the Kafka cluster. If no topic is
+    /// specified, the application will fallback to displaying the list of topics available on the
+    /// Kafka cluster for the user to browse.
     #[arg(short, long)]
     topic: Option<String>,
     /// CSV of the partition numbers that the consumer should be assigned. This argument is used to
@@ -95,14 +104,38 @@ struct Cli {
     /// is executing the application.
     #[arg(short, long)]
     group_id: Option<String>,
+    /// Drives how partitions of the topic are assigned to the Kafka consumer. Valid values:
+    /// `manual` to explicitly assign the partitions, or `subscribe` to join the consumer group and
+    /// let the group coordinator assign partitions, in which case records are consumed from the
+    /// offsets committed for the group and the partitions, seek to and until arguments are
+    /// ignored. Defaults to `manual`.
+    #[arg(long)]
+    assignment_mode: Option<String>,
     /// CSV of colon separated pairs of partitions and offsets that the Kafka consumer will seek to
-    /// before starting to consume records.
+    /// before starting to consume records. Alternatively, an ISO-8601 timestamp, e.g.
+    /// `2025-06-01T14:05:00Z`, or a relative time, e.g. `-15m`, can be specified to seek all
+    /// partitions to the first record produced at or after that point in time, or `tail:N` to
+    /// start with the last N records on each partition.
     #[arg(long)]
     seek_to: Option<String>,
-    /// JSONPath filter that is applied to a records as they are received from the consumer. Can be
-    /// used to filter out any records from the Kafka topic that the end user may not be interested
-    /// in. A record will only be presented to the user if it matches the filter. By default, no
-    /// filter is applied.
+    /// Strategy used by the Kafka consumer to commit the offsets of consumed records to the
+    /// consumer group. By default, no offsets are committed so that browsing a topic never affects
+    /// the offsets of the group. Valid values: `none`, `async` to commit each record
+    /// asynchronously, or `batch:N` to asynchronously commit every N records per partition.
+    #[arg(long)]
+    commit_strategy: Option<String>,
+    /// Point on the partitions where the Kafka consumer will stop consuming records. Valid values:
+    /// `end` to stop at the high watermark of each partition at the time the consumer was started,
+    /// a CSV of colon separated pairs of partitions and inclusive end offsets, or an ISO-8601 or
+    /// relative timestamp to stop at the first record produced at or after that point in time. By
+    /// default, records are consumed indefinitely.
+    #[arg(long)]
+    until: Option<String>,
+    /// Filter that is applied to a records as they are received from the consumer. Can be used to
+    /// filter out any records from the Kafka topic that the end user may not be interested in. A
+    /// record will only be presented to the user if it matches the filter. Filters prefixed with
+    /// `expr:` are written in the filter expression language, otherwise the filter is a JSONPath
+    /// query which may optionally be prefixed with `jsonpath:`. By default, no filter is applied.
     #[arg(short, long)]
     filter: Option<String>,
     /// Specifies the name of pre-configured set of values that will be used as default values for
@@ -116,6 +149,33 @@ struct Cli {
     /// then older records will be removed as newer ones are inserted. Defaults to 256.
     #[arg(long)]
     max_records: Option<usize>,
+    /// Command to run instead of starting the TUI, if any.
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+/// Enumeration of the commands that can be run without starting the TUI.
+#[derive(Clone, Debug, Subcommand)]
+enum Command {
+    /// Produces the records contained in a JSON Lines file to a Kafka topic and then exits. Each
+    /// line has the same shape as an exported record, i.e. `key`, `headers` and `value` along with
+    /// an optional `topic`, `partition` and `timestamp`.
+    Produce(ProduceArgs),
+}
+
+/// Arguments for the `produce` command.
+#[derive(Clone, Debug, Args)]
+struct ProduceArgs {
+    /// Path to the JSON Lines file containing the records to produce, one record per line.
+    file: String,
+    /// Name of the Kafka topic that the records will be produced to. If not specified, the topic
+    /// is read from each line of the file.
+    #[arg(short, long)]
+    topic: Option<String>,
+    /// Maximum number of records that will be produced per second. By default, records are
+    /// produced as fast as they are acknowledged by the Kafka brokers.
+    #[arg(short, long)]
+    rate: Option<NonZeroU32>,
 }
 
 impl Source for Cli {
@@ -208,8 +268,30 @@ impl Source for Cli {
             cfg.insert(String::from("group_id"), Value::from(group_id.clone()));
         }
 
-        let seek_to: SeekTo = self.seek_to.as_ref().map(Into::into).unwrap_or_default();
-        cfg.insert(String::from("seek_to"), Value::from(seek_to));
+        if let Some(assignment_mode) = self.assignment_mode.as_ref() {
+            cfg.insert(
+                String::from("assignment_mode"),
+                Value::from(AssignmentMode::from(assignment_mode)),
+            );
+        }
+
+        if let Some(seek_to) = self.seek_to.as_ref() {
+            cfg.insert(String::from("seek_to"), Value::from(SeekTo::from(seek_to)));
+        }
+
+        if let Some(commit_strategy) = self.commit_strategy.as_ref() {
+            cfg.insert(
+                String::from("commit_strategy"),
+                Value::from(CommitStrategy::from(commit_strategy)),
+            );
+        }
+
+        if let Some(until) = self.until.as_ref() {
+            cfg.insert(
+                String::from("until"),
+                Value::from(ConsumeUntil::from(until)),
+            );
+        }
 
         if let Some(filter) = self.filter.as_ref() {
             cfg.insert(String::from("filter"), config::Value::from(filter.clone()));
@@ -245,6 +327,7 @@ async fn main() -> anyhow::Result<()> {
 
     let args = Cli::parse();
     let profile_name = args.profile.clone();
+    let command = args.command.clone();
 
     let persisted_config =
         PersistedConfig::load_from_home_dir().context("load PersistedConfig from home dir")?;
@@ -252,7 +335,10 @@ async fn main() -> anyhow::Result<()> {
     let config = Config::new(args, persisted_config.clone(), profile_name)
         .context("create application config")?;
 
-    run_app(persisted_config, config, logs_rx).await
+    match command {
+        Some(Command::Produce(produce_args)) => run_produce(config, produce_args, logs_rx).await,
+        None => run_app(persisted_config, config, logs_rx).await,
+    }
 }
 
 /// Environment variable that can be used to enable capturing logs to a file for debugging.
@@ -355,11 +441,17 @@ async fn run_app(
 
     let schema_registry_arc = schema_registry_client.map(|c| Arc::new(c.clone()));
 
+    let (key_serializer, value_serializer) =
+        create_serializers(&config, schema_registry_arc.clone())
+            .context("create key and value serializers")?;
+
     let app = App::new(
         persisted_config,
         config,
         key_deserializer,
         value_deserializer,
+        key_serializer,
+        value_serializer,
         schema_registry_arc,
     )
     .context("initialize application")?;
@@ -374,6 +466,78 @@ async fn run_app(
     result
 }
 
+/// Number of lines handled between each progress report printed by the `produce` command.
+const PRODUCE_PROGRESS_INTERVAL: usize = 1000;
+
+/// Runs the `produce` command which produces the records contained in a JSON Lines file to a Kafka
+/// topic, printing the progress and a summary to the terminal.
+async fn run_produce(
+    config: Config,
+    args: ProduceArgs,
+    logs_rx: Option<Receiver<Log>>,
+) -> anyhow::Result<()> {
+    // captured logs are only displayed by the TUI so discard them to keep the channel from filling
+    if let Some(mut rx) = logs_rx {
+        tokio::spawn(async move { while rx.recv().await.is_some() {} });
+    }
+
+    let schema_registry_arc = create_schema_registry_client(&config).map(|c| Arc::new(c.clone()));
+
+    let (key_serializer, value_serializer) = create_serializers(&config, schema_registry_arc)
+        .context("create key and value serializers")?;
+
+    let producer_config = ProducerConfig::builder()
+        .props(config.producer_properties())
+        .build()
+        .expect("valid ProducerConfig");
+
+    let producer = Producer::new(producer_config).context("create Kafka producer")?;
+
+    let importer = Importer::new(
+        Arc::new(producer),
+        RecordSerializer::new(key_serializer, value_serializer),
+        config.value_format,
+    );
+
+    let request = ImportRequest {
+        path: args.file,
+        topic: args.topic,
+        rate: args.rate,
+    };
+
+    let summary = importer
+        .import(&request, |progress| {
+            if let Err(e) = progress.result {
+                eprintln!("{:#}", e);
+            }
+
+            let handled = progress.summary.produced + progress.summary.failed;
+
+            if handled % PRODUCE_PROGRESS_INTERVAL == 0 {
+                println!(
+                    "{} records produced, {} failed",
+                    progress.summary.produced, progress.summary.failed
+                );
+            }
+        })
+        .await
+        .context("produce records from file")?;
+
+    println!(
+        "produced {} records from {} in {:.1}s, {} failed",
+        summary.produced,
+        request.path,
+        summary.elapsed.as_secs_f64(),
+        summary.failed
+    );
+
+    if summary.failed > 0 {
+        anyhow::bail!("{} records could not be produced", summary.failed);
+    }
+
+    Ok(())
+}
+
 /// Creeates a [`SchemaRegistryClient`] if a URL is specified in the configuration. The reference to
 /// the client is intentionally leaked to ensure it has a `'static` lifetime as required by the
 /// Kafka record deserialziers. This is acceptable as the client is intended to live for the entire
@@ -623,3 +787,122 @@ fn create_value_deserializer(
 
     Ok(value_deserializer)
 }
+
+/// Creates both the [`KeySerializer`] and [`ValueSerializer`] that will be used to serialize
+/// records produced to Kafka topics. Records are serialized in the same formats that are
+/// configured for the records consumed.
+fn create_serializers(
+    config: &Config,
+    schema_registry_client: Option<Arc<SchemaRegistryClient>>,
+) -> anyhow::Result<(Arc<dyn KeySerializer>, Arc<dyn ValueSerializer>)> {
+    let schema_client =
+        schema_registry_client.map(|client| SchemaClient::new(client, DEFAULT_CACHE_TTL));
+
+    let key_serializer = create_key_serializer(config, schema_client.clone())?;
+
+    let value_serializer = create_value_serializer(config, schema_client)?;
+
+    Ok((key_serializer, value_serializer))
+}
+
+/// Creates the [`KeySerializer`] that will be used to serialize the keys of records produced to
+/// Kafka topics based on the application configuration.
+fn create_key_serializer(
+    config: &Config,
+    schema_client: Option<SchemaClient<SchemaRegistryClient>>,
+) -> anyhow::Result<Arc<dyn KeySerializer>> {
+    let key_serializer: Arc<dyn KeySerializer> = match config.key_format {
+        Format::None => Arc::new(StringSerializer),
+        Format::Json => match schema_client {
+            Some(client) => {
+                tracing::info!("using JSONSchema key serializer with schema registry");
+
+                Arc::new(JsonSchemaSerializer::new(client))
+            }
+            None => {
+                tracing::info!("using JSON key serializer without schema registry");
+
+                Arc::new(JsonStringSerializer)
+            }
+        },
+        Format::Avro => match schema_client {
+            Some(client) => {
+                tracing::info!("using Avro schema key serializer with schema registry");
+
+                Arc::new(AvroSchemaSerializer::new(client))
+            }
+            None => {
+                anyhow::bail!("schema registry url must be specified when key format is avro")
+            }
+        },
+        Format::Protobuf => match schema_client {
+            Some(client) => {
+                tracing::info!("using Protobuf schema key serializer with schema registry");
+
+                let value_type: Option<String> = None;
+
+                Arc::new(ProtobufSchemaSerializer::new(
+                    client,
+                    config.key_protobuf_type.clone(),
+                    value_type,
+                ))
+            }
+            None => {
+                anyhow::bail!("schema registry url must be specified when key format is protobuf")
+            }
+        },
+    };
+
+    Ok(key_serializer)
+}
+
+/// Creates the [`ValueSerializer`] that will be used to serialize the values of records produced
+/// to Kafka topics based on the application configuration.
+fn create_value_serializer(
+    config: &Config,
+    schema_client: Option<SchemaClient<SchemaRegistryClient>>,
+) -> anyhow::Result<Arc<dyn ValueSerializer>> {
+    let value_serializer: Arc<dyn ValueSerializer> = match config.value_format {
+        Format::None => Arc::new(StringSerializer),
+        Format::Json => match schema_client {
+            Some(client) => {
+                tracing::info!("using JSONSchema value serializer with schema registry");
+
+                Arc::new(JsonSchemaSerializer::new(client))
+            }
+            None => {
+                tracing::info!("using JSON value serializer without schema registry");
+
+                Arc::new(JsonStringSerializer)
+            }
+        },
+        Format::Avro => match schema_client {
+            Some(client) => {
+                tracing::info!("using Avro schema value serializer with schema registry");
+
+                Arc::new(AvroSchemaSerializer::new(client))
+            }
+            None => {
+                anyhow::bail!("schema registry url must be specified when value format is avro")
+            }
+        },
+        Format::Protobuf => match schema_client {
+            Some(client) => {
+                tracing::info!("using Protobuf schema value serializer with schema registry");
+
+                let key_type: Option<String> = None;
+
+                Arc::new(ProtobufSchemaSerializer::new(
+                    client,
+                    key_type,
+                    config.value_protobuf_type.clone(),
+                ))
+            }
+            None => {
+                anyhow::bail!("schema registry url must be specified when value format is protobuf")
+            }
+        },
+    };
+
+    Ok(value_serializer)
+}
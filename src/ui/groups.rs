@@ -0,0 +1,657 @@
+use crate::{
+    app::{BufferedKeyPress, config::Theme},
+    event::Event,
+    kafka::{
+        TopicPartition,
+        admin::{ConsumerGroup, ConsumerGroupOffset},
+    },
+    ui::{Component, MappedKeyEvent},
+};
+
+use crossterm::event::{KeyCode, KeyEvent};
+use derive_builder::Builder;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Padding, Paragraph, Row, Table, TableState},
+};
+use std::str::FromStr;
+
+/// Text displayed to the user in the footer for the refresh key binding.
+const KEY_BINDING_REFRESH: &str = "(r) refresh";
+
+/// Key bindings that are always displayed to the user in the footer when viewing the consumer
+/// groups screen.
+const GROUPS_KEY_BINDINGS: [&str; 7] = [
+    super::KEY_BINDING_QUIT,
+    super::KEY_BINDING_CHANGE_FOCUS,
+    super::KEY_BINDING_TOP,
+    super::KEY_BINDING_NEXT,
+    super::KEY_BINDING_PREV,
+    super::KEY_BINDING_BOTTOM,
+    KEY_BINDING_REFRESH,
+];
+
+/// Headers for the consumer groups table along with their fill constraints.
+const GROUPS_HEADERS: [(&str, u16); 4] =
+    [("Name", 5), ("State", 2), ("Protocol", 2), ("Members", 2)];
+
+/// Headers for the consumer group members table along with their fill constraints.
+const MEMBERS_HEADERS: [(&str, u16); 4] = [
+    ("Member ID", 4),
+    ("Client ID", 3),
+    ("Host", 3),
+    ("Partitions", 4),
+];
+
+/// Headers for the consumer group offsets table along with their fill constraints.
+const OFFSETS_HEADERS: [(&str, u16); 5] = [
+    ("Topic", 4),
+    ("Partition", 2),
+    ("Committed", 2),
+    ("Log End", 2),
+    ("Lag", 2),
+];
+
+/// Text displayed in place of an offset that is not known.
+const UNKNOWN_OFFSET: &str = "-";
+
+/// Formats an offset or lag for display, using a placeholder if it is not known.
+fn format_offset(offset: Option<i64>) -> String {
+    offset.map_or(String::from(UNKNOWN_OFFSET), |o| o.to_string())
+}
+
+/// Formats the partitions assigned to a consumer group member grouped by topic, e.g.
+/// `orders[0,1] payments[2]`.
+fn format_assignment(assignment: &[TopicPartition]) -> String {
+    let mut topics: Vec<(&str, Vec<String>)> = Vec::new();
+
+    for tp in assignment {
+        match topics.last_mut() {
+            Some((topic, partitions)) if *topic == tp.topic => {
+                partitions.push(tp.partition.to_string());
+            }
+            _ => topics.push((tp.topic.as_str(), vec![tp.partition.to_string()])),
+        }
+    }
+
+    topics
+        .iter()
+        .map(|(topic, partitions)| format!("{}[{}]", topic, partitions.join(",")))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Enumerates the possible network states of the [`Groups`] component.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+enum NetworkStatus {
+    /// The component is idle and not performing any network operations.
+    #[default]
+    Idle,
+    /// The component is loading the consumer groups from the Kafka cluster.
+    LoadingGroups,
+    /// The component is loading the offsets of the selected consumer group from the Kafka cluster.
+    LoadingOffsets,
+}
+
+/// Enumeration of the widgets in the [`Groups`] component that can have focus.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+enum GroupsWidget {
+    /// Consumer groups table widget.
+    #[default]
+    Groups,
+    /// Members table widget of the selected consumer group.
+    Members,
+    /// Offsets table widget of the selected consumer group.
+    Offsets,
+}
+
+/// Manages state related to the consumer groups and the UI that renders them to the user.
+#[derive(Debug, Default)]
+struct GroupsState {
+    /// Stores the widget that currently has focus.
+    active_widget: GroupsWidget,
+    /// List of all consumer groups retrieved from the Kafka cluster.
+    groups: Vec<ConsumerGroup>,
+    /// Currently selected consumer group.
+    selected_group: Option<ConsumerGroup>,
+    /// Offsets of the currently selected consumer group, once they have been loaded.
+    selected_group_offsets: Option<Vec<ConsumerGroupOffset>>,
+    /// Manages state of the consumer groups table widget.
+    groups_table_state: TableState,
+    /// Manages state of the members table widget.
+    members_table_state: TableState,
+    /// Manages state of the offsets table widget.
+    offsets_table_state: TableState,
+    /// Current network status of the component.
+    network_status: NetworkStatus,
+}
+
+impl GroupsState {
+    /// Moves focus to the next widget. The members and offsets tables can only receive focus once
+    /// a consumer group has been selected.
+    fn select_next_widget(&mut self) {
+        if self.selected_group.is_none() {
+            return;
+        }
+
+        self.active_widget = match self.active_widget {
+            GroupsWidget::Groups => GroupsWidget::Members,
+            GroupsWidget::Members => GroupsWidget::Offsets,
+            GroupsWidget::Offsets => GroupsWidget::Groups,
+        };
+    }
+    /// Selects the consumer group at the specified index, bounded by the number of groups, and
+    /// clears the details of the previously selected group. Returns the selected group if the
+    /// selection changed.
+    fn select_group(&mut self, idx: usize) -> Option<&ConsumerGroup> {
+        if self.groups.is_empty() {
+            return None;
+        }
+
+        let idx = idx.min(self.groups.len() - 1);
+
+        if self.groups_table_state.selected() == Some(idx) {
+            return None;
+        }
+
+        self.groups_table_state.select(Some(idx));
+        self.members_table_state.select(None);
+        self.offsets_table_state.select(None);
+
+        self.selected_group = self.groups.get(idx).cloned();
+        self.selected_group_offsets = None;
+
+        self.selected_group.as_ref()
+    }
+    /// Selects the consumer group with the specified name, or deselects the current group if it
+    /// no longer exists. Used to keep the selection on the same group when the list is reloaded.
+    fn reselect_group(&mut self, name: &str) {
+        match self.groups.iter().position(|g| g.name == name) {
+            Some(idx) => {
+                self.groups_table_state.select(Some(idx));
+                self.selected_group = self.groups.get(idx).cloned();
+            }
+            None => {
+                self.groups_table_state.select(None);
+                self.selected_group = None;
+                self.selected_group_offsets = None;
+                self.active_widget = GroupsWidget::Groups;
+            }
+        }
+    }
+    /// Selects the row at the specified index in the members or offsets table that currently has
+    /// focus, bounded by the number of rows.
+    fn select_detail_row(&mut self, idx: usize) {
+        let (len, table_state) = match self.active_widget {
+            GroupsWidget::Groups => return,
+            GroupsWidget::Members => (
+                self.selected_group.as_ref().map_or(0, |g| g.members.len()),
+                &mut self.members_table_state,
+            ),
+            GroupsWidget::Offsets => (
+                self.selected_group_offsets.as_ref().map_or(0, Vec::len),
+                &mut self.offsets_table_state,
+            ),
+        };
+
+        if len > 0 {
+            table_state.select(Some(idx.min(len - 1)));
+        }
+    }
+    /// Returns the index of the selected row in the table that currently has focus, if any.
+    fn selected_row(&self) -> Option<usize> {
+        match self.active_widget {
+            GroupsWidget::Groups => self.groups_table_state.selected(),
+            GroupsWidget::Members => self.members_table_state.selected(),
+            GroupsWidget::Offsets => self.offsets_table_state.selected(),
+        }
+    }
+    /// Returns the total lag of the selected consumer group across the partitions whose lag is
+    /// known, if the offsets have been loaded.
+    fn total_lag(&self) -> Option<i64> {
+        self.selected_group_offsets
+            .as_ref()
+            .map(|offsets| offsets.iter().filter_map(ConsumerGroupOffset::lag).sum())
+    }
+}
+
+/// Contains the [`Color`]s from the application [`Theme`] required to render the [`Groups`]
+/// component.
+#[derive(Debug)]
+struct GroupsTheme {
+    /// Color used for the borders of the main info panels.
+    panel_border_color: Color,
+    /// Color used for the borders of the selected info panel.
+    selected_panel_border_color: Color,
+    /// Color used for the label text in tables, etc.
+    label_color: Color,
+    /// Color used for the lag of partitions that the consumer group is behind on.
+    highlight_text_color: Color,
+    /// Color used for the key bindings text. Defaults to white.
+    key_bindings_text_color: Color,
+}
+
+impl From<&Theme> for GroupsTheme {
+    /// Converts a reference to a [`Theme`] to a new [`GroupsTheme`].
+    fn from(value: &Theme) -> Self {
+        let panel_border_color =
+            Color::from_str(value.panel_border_color.as_str()).expect("valid RGB hex");
+
+        let selected_panel_border_color =
+            Color::from_str(value.selected_panel_border_color.as_str()).expect("valid RGB hex");
+
+        let label_color = Color::from_str(value.label_color.as_str()).expect("valid RGB hex");
+
+        let highlight_text_color =
+            Color::from_str(value.highlight_text_color.as_str()).expect("valid RGB hex");
+
+        let key_bindings_text_color =
+            Color::from_str(value.key_bindings_text_color.as_str()).expect("valid RGB hex");
+
+        Self {
+            panel_border_color,
+            selected_panel_border_color,
+            label_color,
+            highlight_text_color,
+            key_bindings_text_color,
+        }
+    }
+}
+
+/// Configuration used to create a new [`Groups`] component.
+#[derive(Builder, Debug)]
+pub struct GroupsConfig<'a> {
+    /// Reference to the application [`Theme`].
+    theme: &'a Theme,
+}
+
+impl<'a> GroupsConfig<'a> {
+    /// Creates a new default [`GroupsConfigBuilder`] which can be used to create a new
+    /// [`GroupsConfig`].
+    pub fn builder() -> GroupsConfigBuilder<'a> {
+        GroupsConfigBuilder::default()
+    }
+}
+
+impl<'a> From<GroupsConfig<'a>> for Groups {
+    /// Converts from an owned [`GroupsConfig`] to an owned [`Groups`].
+    fn from(value: GroupsConfig<'a>) -> Self {
+        Self::new(value)
+    }
+}
+
+/// The application [`Component`] that is responsible for displaying the consumer groups that
+/// exist on the Kafka cluster along with their members and offsets.
+#[derive(Debug)]
+pub struct Groups {
+    /// Current state of the component and it's underlying widgets.
+    state: GroupsState,
+    /// Color scheme for the component.
+    theme: GroupsTheme,
+}
+
+impl Groups {
+    /// Creates a new [`Groups`] component using the specified [`GroupsConfig`].
+    fn new(config: GroupsConfig) -> Self {
+        Self {
+            state: GroupsState::default(),
+            theme: config.theme.into(),
+        }
+    }
+    /// Invoked when the list of consumer groups has been loaded from the Kafka cluster.
+    fn on_consumer_groups_loaded(&mut self, groups: Vec<ConsumerGroup>) {
+        if self.state.network_status == NetworkStatus::LoadingGroups {
+            self.state.network_status = NetworkStatus::Idle;
+        }
+
+        self.state.groups = groups;
+
+        if let Some(name) = self.state.selected_group.as_ref().map(|g| g.name.clone()) {
+            self.state.reselect_group(&name);
+        }
+    }
+    /// Invoked when the offsets of a consumer group have been loaded from the Kafka cluster. The
+    /// offsets are ignored if the consumer group is no longer selected.
+    fn on_consumer_group_offsets_loaded(
+        &mut self,
+        group: &str,
+        offsets: Option<Vec<ConsumerGroupOffset>>,
+    ) {
+        if self
+            .state
+            .selected_group
+            .as_ref()
+            .is_none_or(|g| g.name != group)
+        {
+            return;
+        }
+
+        self.state.network_status = NetworkStatus::Idle;
+        self.state.selected_group_offsets = offsets;
+
+        // keep the selected row within the reloaded offsets
+        let len = self
+            .state
+            .selected_group_offsets
+            .as_ref()
+            .map_or(0, Vec::len);
+
+        let selected = self
+            .state
+            .offsets_table_state
+            .selected()
+            .filter(|_| len > 0)
+            .map(|idx| idx.min(len - 1));
+
+        self.state.offsets_table_state.select(selected);
+    }
+    /// Maps a [`KeyEvent`] that moves the selection in the table that currently has focus to the
+    /// index of the row that should be selected.
+    fn map_navigation_key(
+        &self,
+        event: KeyEvent,
+        buffered: Option<&BufferedKeyPress>,
+    ) -> Option<usize> {
+        let selected = self.state.selected_row();
+
+        match event.code {
+            KeyCode::Char('g') if buffered.filter(|kp| kp.is('g')).is_some() => Some(0),
+            KeyCode::Char('j') => Some(selected.map_or(0, |i| i + 1)),
+            KeyCode::Char('k') => Some(selected.map_or(0, |i| i.saturating_sub(1))),
+            KeyCode::Char('G') => Some(usize::MAX),
+            _ => None,
+        }
+    }
+    /// Renders the table of consumer groups contained in the Kafka cluster.
+    fn render_groups(&mut self, frame: &mut Frame, area: Rect) {
+        if self.state.network_status == NetworkStatus::LoadingGroups && self.state.groups.is_empty()
+        {
+            self.render_message(frame, area, "Loading consumer groups...");
+            return;
+        } else if self.state.groups.is_empty() {
+            self.render_message(frame, area, "No consumer groups found");
+            return;
+        }
+
+        let groups_block = self.panel_block(
+            format!(" Consumer Groups - {} ", self.state.groups.len()),
+            GroupsWidget::Groups,
+        );
+
+        let groups_rows: Vec<Row> = self
+            .state
+            .groups
+            .iter()
+            .map(|g| {
+                Row::new(vec![
+                    g.name.clone(),
+                    g.state.clone(),
+                    g.protocol.clone(),
+                    g.members.len().to_string(),
+                ])
+            })
+            .collect();
+
+        let groups_table = Table::new(groups_rows, fill_constraints(&GROUPS_HEADERS))
+            .column_spacing(1)
+            .header(self.header_row(&GROUPS_HEADERS))
+            .row_highlight_style(Modifier::REVERSED)
+            .block(groups_block);
+
+        frame.render_stateful_widget(groups_table, area, &mut self.state.groups_table_state);
+    }
+    /// Renders the details of the selected consumer group, if one is selected.
+    fn render_group_details(&mut self, frame: &mut Frame, area: Rect) {
+        if self.state.selected_group.is_none() {
+            self.render_message(frame, area, "No consumer group selected");
+            return;
+        }
+
+        let [members_panel, offsets_panel] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+            .areas(area);
+
+        self.render_group_members(frame, members_panel);
+        self.render_group_offsets(frame, offsets_panel);
+    }
+    /// Renders the members panel of the selected consumer group.
+    fn render_group_members(&mut self, frame: &mut Frame, area: Rect) {
+        let Some(group) = self.state.selected_group.as_ref() else {
+            return;
+        };
+
+        let members_block = self.panel_block(
+            format!(" Members - {} ", group.members.len()),
+            GroupsWidget::Members,
+        );
+
+        let members_rows: Vec<Row> = group
+            .members
+            .iter()
+            .map(|m| {
+                Row::new(vec![
+                    m.id.clone(),
+                    m.client_id.clone(),
+                    m.client_host.clone(),
+                    format_assignment(&m.assignment),
+                ])
+            })
+            .collect();
+
+        let members_table = Table::new(members_rows, fill_constraints(&MEMBERS_HEADERS))
+            .column_spacing(1)
+            .header(self.header_row(&MEMBERS_HEADERS))
+            .row_highlight_style(Modifier::REVERSED)
+            .block(members_block);
+
+        frame.render_stateful_widget(members_table, area, &mut self.state.members_table_state);
+    }
+    /// Renders the offsets panel of the selected consumer group.
+    fn render_group_offsets(&mut self, frame: &mut Frame, area: Rect) {
+        let Some(offsets) = self.state.selected_group_offsets.as_ref() else {
+            let msg = if self.state.network_status == NetworkStatus::LoadingOffsets {
+                "Loading offsets..."
+            } else {
+                "Offsets unavailable"
+            };
+
+            self.render_message(frame, area, msg);
+            return;
+        };
+
+        let offsets_block = self.panel_block(
+            format!(" Offsets - {} ", offsets.len()),
+            GroupsWidget::Offsets,
+        );
+
+        let offsets_rows: Vec<Row> = offsets
+            .iter()
+            .map(|o| {
+                let lag = o.lag();
+
+                let lag_style = if lag.is_some_and(|l| l > 0) {
+                    Style::from(self.theme.highlight_text_color)
+                } else {
+                    Style::default()
+                };
+
+                Row::new(vec![
+                    Span::raw(o.topic_partition.topic.as_str()),
+                    Span::raw(o.topic_partition.partition.to_string()),
+                    Span::raw(format_offset(o.committed)),
+                    Span::raw(format_offset(o.log_end)),
+                    Span::raw(format_offset(lag)).style(lag_style),
+                ])
+            })
+            .collect();
+
+        let offsets_table = Table::new(offsets_rows, fill_constraints(&OFFSETS_HEADERS))
+            .column_spacing(1)
+            .header(self.header_row(&OFFSETS_HEADERS))
+            .row_highlight_style(Modifier::REVERSED)
+            .block(offsets_block);
+
+        frame.render_stateful_widget(offsets_table, area, &mut self.state.offsets_table_state);
+    }
+    /// Creates the bordered [`Block`] for a panel, highlighting it if the widget has focus.
+    fn panel_block(&self, title: String, widget: GroupsWidget) -> Block<'static> {
+        let block = Block::bordered()
+            .title(title)
+            .border_style(self.theme.panel_border_color)
+            .padding(Padding::new(1, 1, 0, 0));
+
+        if self.state.active_widget == widget {
+            block
+                .border_type(BorderType::Thick)
+                .border_style(self.theme.selected_panel_border_color)
+        } else {
+            block
+        }
+    }
+    /// Creates the header [`Row`] for a table from the specified headers.
+    fn header_row(&self, headers: &[(&'static str, u16)]) -> Row<'static> {
+        headers
+            .iter()
+            .map(|(title, _)| title.bold().style(self.theme.label_color))
+            .collect()
+    }
+    /// Renders the given message centered both vertically and horizontally in the given area.
+    fn render_message(&self, frame: &mut Frame, area: Rect, msg: &str) {
+        let [empty_area, text_area] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .areas(area);
+
+        let empty_text = Paragraph::default().block(
+            Block::default()
+                .borders(Borders::LEFT | Borders::TOP | Borders::RIGHT)
+                .border_style(self.theme.panel_border_color),
+        );
+
+        let message_block = Block::default()
+            .borders(Borders::LEFT | Borders::BOTTOM | Borders::RIGHT)
+            .border_style(self.theme.panel_border_color);
+
+        let message_text = Paragraph::new(msg)
+            .style(self.theme.panel_border_color)
+            .block(message_block)
+            .centered();
+
+        frame.render_widget(empty_text, empty_area);
+        frame.render_widget(message_text, text_area);
+    }
+}
+
+/// Creates the fill [`Constraint`]s for the columns of a table from the specified headers.
+fn fill_constraints(headers: &[(&str, u16)]) -> Vec<Constraint> {
+    headers
+        .iter()
+        .map(|(_, fill)| Constraint::Fill(*fill))
+        .collect()
+}
+
+impl Component for Groups {
+    /// Returns the name of the [`Component`] which is displayed to the user as a menu item.
+    fn name(&self) -> &'static str {
+        "Groups"
+    }
+    /// Allows the [`Component`] to handle any [`Event`] that was not handled by the main
+    /// application.
+    fn on_app_event(&mut self, event: &Event) {
+        match event {
+            Event::ConsumerGroupsLoaded(groups) => self.on_consumer_groups_loaded(groups.to_vec()),
+            Event::ConsumerGroupOffsetsLoaded(group, offsets) => {
+                self.on_consumer_group_offsets_loaded(group, offsets.clone())
+            }
+            Event::SelectNextWidget => self.state.select_next_widget(),
+            _ => {}
+        }
+    }
+    /// Allows the [`Component`] to map a [`KeyEvent`] to an [`Event`] which will be published
+    /// for processing.
+    fn map_key_event(
+        &mut self,
+        event: KeyEvent,
+        buffered: Option<&BufferedKeyPress>,
+    ) -> MappedKeyEvent {
+        if let KeyCode::Char('r') = event.code {
+            self.state.network_status = NetworkStatus::LoadingGroups;
+
+            let selected = self.state.selected_group.as_ref().map(|g| g.name.clone());
+
+            return MappedKeyEvent::Dispatch(Event::LoadConsumerGroups(selected));
+        }
+
+        let Some(idx) = self.map_navigation_key(event, buffered) else {
+            return MappedKeyEvent::Unhandled;
+        };
+
+        if self.state.active_widget != GroupsWidget::Groups {
+            self.state.select_detail_row(idx);
+            return MappedKeyEvent::Consumed;
+        }
+
+        match self.state.select_group(idx) {
+            Some(group) => {
+                let group = group.clone();
+
+                self.state.network_status = NetworkStatus::LoadingOffsets;
+
+                MappedKeyEvent::Dispatch(Event::LoadConsumerGroupOffsets(group))
+            }
+            None => MappedKeyEvent::Consumed,
+        }
+    }
+    /// Allows the [`Component`] to render the status line text into the footer.
+    fn render_status_line(&self, frame: &mut Frame, area: Rect) {
+        let mut spans = vec![
+            Span::styled("Total: ", Style::from(self.theme.label_color).bold()),
+            Span::raw(self.state.groups.len().to_string()),
+        ];
+
+        if let Some(total_lag) = self.state.total_lag() {
+            spans.push(Span::raw(" | "));
+            spans.push(Span::styled(
+                "Lag: ",
+                Style::from(self.theme.label_color).bold(),
+            ));
+            spans.push(Span::raw(total_lag.to_string()));
+        }
+
+        let text = Paragraph::new(Line::from(spans)).left_aligned();
+
+        frame.render_widget(text, area);
+    }
+    /// Allows the [`Component`] to render the key bindings text into the footer.
+    fn render_key_bindings(&self, frame: &mut Frame, area: Rect) {
+        let text = Paragraph::new(GROUPS_KEY_BINDINGS.join(" | "))
+            .style(self.theme.key_bindings_text_color)
+            .right_aligned();
+
+        frame.render_widget(text, area);
+    }
+    /// Renders the component-specific widgets to the terminal.
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let [groups_panel, details_panel] = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+            .areas(area);
+
+        self.render_groups(frame, groups_panel);
+        self.render_group_details(frame, details_panel);
+    }
+    /// Hook for the [`Component`] to run any logic required when it becomes active. The
+    /// [`Component`] can also return an optional [`Event`] that will be dispatched.
+    fn on_activate(&mut self) -> Option<Event> {
+        if self.state.groups.is_empty() {
+            self.state.network_status = NetworkStatus::LoadingGroups;
+            Some(Event::LoadConsumerGroups(None))
+        } else {
+            None
+        }
+    }
+}
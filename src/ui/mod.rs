@@ -1,4 +1,6 @@
+mod groups;
 mod logs;
+mod produce;
 mod records;
 mod schemas;
 mod settings;
@@ -7,7 +9,9 @@ mod topics;
 mod widget;
 
 pub use crate::ui::{
+    groups::{Groups, GroupsConfig},
     logs::{Logs, LogsConfig},
+    produce::{Produce, ProduceConfig},
     records::{Records, RecordsConfig},
     schemas::{Schemas, SchemasConfig},
     settings::{Settings, SettingsConfig},
@@ -42,6 +46,12 @@ const KEY_BINDING_PAUSE: &str = "(p) pause";
 /// Text displayed to the user in the footer for the resume key binding.
 const KEY_BINDING_RESUME: &str = "(r) resume";
 
+/// Text displayed to the user in the footer for the seek key binding.
+const KEY_BINDING_SEEK: &str = "(s) seek";
+
+/// Text displayed to the user in the footer for the cycle value view key binding.
+const KEY_BINDING_VALUE_VIEW: &str = "(v) cycle view";
+
 /// Text displayed to the user in the footer for the scroll down key binding.
 const KEY_BINDING_SCROLL_DOWN: &str = "(j) down";
 
@@ -92,6 +102,12 @@ pub trait Component {
     ) -> MappedKeyEvent {
         MappedKeyEvent::Unhandled
     }
+    /// Determines if the [`Component`] is currently capturing all key input, for example while the
+    /// user is typing into a dialog. When capturing, application level key bindings are bypassed
+    /// and every [`KeyEvent`] is passed to [`Component::map_key_event`].
+    fn is_capturing_input(&self) -> bool {
+        false
+    }
     /// Allows the component to handle any [`Event`] that was not handled by the main
     /// application.
     fn on_app_event(&mut self, _event: &Event) {}
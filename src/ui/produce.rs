@@ -0,0 +1,923 @@
+use crate::{
+    app::{
+        BufferedKeyPress, Notification,
+        config::Theme,
+        import::{ImportRequest, ImportSummary},
+    },
+    event::Event,
+    kafka::{
+        RecordHeader,
+        producer::{ProduceRequest, ProducedRecord},
+        schema::Version,
+    },
+    ui::{
+        Component, MappedKeyEvent,
+        widget::{TextArea, TextInput},
+    },
+};
+
+use crossterm::event::{KeyCode, KeyEvent};
+use derive_builder::Builder;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Clear, Padding, Paragraph, Wrap},
+};
+use std::{num::NonZeroU32, str::FromStr};
+
+/// Text displayed to the user in the footer for the edit field key binding.
+const KEY_BINDING_EDIT: &str = "(enter) edit";
+
+/// Text displayed to the user in the footer for the send record key binding.
+const KEY_BINDING_SEND: &str = "(s) send";
+
+/// Text displayed to the user in the footer for the clear record key binding.
+const KEY_BINDING_CLEAR: &str = "(c) clear";
+
+/// Text displayed to the user in the footer for the import file key binding.
+const KEY_BINDING_IMPORT: &str = "(i) import file";
+
+/// Text displayed to the user in the footer for the stop editing key binding.
+const KEY_BINDING_STOP_EDITING: &str = "(esc) stop editing";
+
+/// Text displayed to the user in the footer for the next field key binding while editing.
+const KEY_BINDING_NEXT_FIELD: &str = "(tab) next field";
+
+/// Key bindings that are always displayed to the user in the footer when viewing the produce
+/// screen and not editing a field.
+const PRODUCE_KEY_BINDINGS: [&str; 2] = [super::KEY_BINDING_QUIT, super::KEY_BINDING_CHANGE_FOCUS];
+
+/// Key bindings that are displayed to the user in the footer while the import dialog is open.
+const IMPORT_DIALOG_KEY_BINDINGS: [&str; 3] =
+    ["(esc) cancel", "(tab) next field", "(enter) import"];
+
+/// Separator between the key and value of a header entered by the user.
+const HEADER_SEPARATOR: char = '=';
+
+/// Enumeration of the fields in the [`Produce`] component that can have focus.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+enum ProduceField {
+    /// Topic that the record is produced to.
+    #[default]
+    Topic,
+    /// Partition that the record is produced to.
+    Partition,
+    /// Partition key of the record.
+    Key,
+    /// Headers of the record.
+    Headers,
+    /// Version of the schema that the key of the record is serialized with.
+    KeySchemaVersion,
+    /// Version of the schema that the value of the record is serialized with.
+    ValueSchemaVersion,
+    /// Value of the record.
+    Value,
+}
+
+impl ProduceField {
+    /// Returns the [`ProduceField`] that follows this one when the user cycles through them.
+    fn next(self) -> Self {
+        match self {
+            Self::Topic => Self::Partition,
+            Self::Partition => Self::Key,
+            Self::Key => Self::Headers,
+            Self::Headers => Self::KeySchemaVersion,
+            Self::KeySchemaVersion => Self::ValueSchemaVersion,
+            Self::ValueSchemaVersion => Self::Value,
+            Self::Value => Self::Topic,
+        }
+    }
+    /// Determines if the field spans multiple lines, in which case the enter key inserts a new
+    /// line instead of completing the edit.
+    fn is_multi_line(self) -> bool {
+        matches!(self, Self::Headers | Self::Value)
+    }
+}
+
+/// Parses the headers entered by the user, one per line, as `key=value` pairs. A line without a
+/// separator produces a header without a value and blank lines are ignored.
+fn parse_headers(text: &str) -> anyhow::Result<Vec<RecordHeader>> {
+    let mut headers = Vec::new();
+
+    for (i, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (key, value) = match line.split_once(HEADER_SEPARATOR) {
+            Some((key, value)) => (key.trim(), Some(value.as_bytes().to_vec())),
+            None => (line.trim(), None),
+        };
+
+        if key.is_empty() {
+            anyhow::bail!("header on line {} has no key", i + 1);
+        }
+
+        headers.push(RecordHeader {
+            key: String::from(key),
+            value,
+        });
+    }
+
+    Ok(headers)
+}
+
+/// Parses the schema version entered by the user for the key or value. Returns [`None`] when the
+/// input is empty so that the latest version is used.
+fn parse_schema_version(text: &str, target: &str) -> anyhow::Result<Option<Version>> {
+    match text.trim() {
+        "" => Ok(None),
+        v => match v.parse::<i32>() {
+            Ok(v) if v > 0 => Ok(Some(Version::from(v))),
+            _ => anyhow::bail!("invalid {} schema version: {}", target, v),
+        },
+    }
+}
+
+/// Enumeration of the fields in the [`ImportDialog`] that can have focus.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+enum ImportDialogField {
+    #[default]
+    Path,
+    Topic,
+    Rate,
+}
+
+/// Manages state for the dialog the user can open to produce the records contained in a JSON
+/// Lines file.
+#[derive(Debug, Default)]
+struct ImportDialog {
+    /// Input for the path to the JSON Lines file.
+    path: TextInput,
+    /// Input for the topic that the records are produced to.
+    topic: TextInput,
+    /// Input for the maximum number of records produced per second.
+    rate: TextInput,
+    /// Field in the dialog that currently has focus.
+    active_field: ImportDialogField,
+    /// Message describing why the values entered by the user are invalid, if any.
+    error: Option<String>,
+}
+
+impl ImportDialog {
+    /// Creates a new [`ImportDialog`] with the topic input set to the specified topic.
+    fn new(topic: &str) -> Self {
+        Self {
+            topic: TextInput::new(topic),
+            ..Default::default()
+        }
+    }
+    /// Returns a mutable reference to the [`TextInput`] for the field that currently has focus.
+    fn active_input_mut(&mut self) -> &mut TextInput {
+        match self.active_field {
+            ImportDialogField::Path => &mut self.path,
+            ImportDialogField::Topic => &mut self.topic,
+            ImportDialogField::Rate => &mut self.rate,
+        }
+    }
+    /// Cycles the focus to the next field in the dialog.
+    fn select_next_field(&mut self) {
+        self.active_field = match self.active_field {
+            ImportDialogField::Path => ImportDialogField::Topic,
+            ImportDialogField::Topic => ImportDialogField::Rate,
+            ImportDialogField::Rate => ImportDialogField::Path,
+        };
+    }
+    /// Creates the [`ImportRequest`] from the values entered by the user.
+    fn parse(&self) -> anyhow::Result<ImportRequest> {
+        let path = match self.path.value().trim() {
+            "" => anyhow::bail!("file is required"),
+            p => String::from(p),
+        };
+
+        let topic = Some(self.topic.value().trim())
+            .filter(|t| !t.is_empty())
+            .map(String::from);
+
+        let rate = match self.rate.value().trim() {
+            "" => None,
+            r => match r.parse::<NonZeroU32>() {
+                Ok(r) => Some(r),
+                Err(_) => anyhow::bail!("invalid rate: {}", r),
+            },
+        };
+
+        Ok(ImportRequest { path, topic, rate })
+    }
+}
+
+/// Outcome of the last record or file that was sent by the user.
+#[derive(Debug)]
+enum ProduceResult {
+    /// Record was produced to the partition and offset.
+    Produced(ProducedRecord),
+    /// Record or file failed to be produced for the reason given.
+    Failed(String),
+    /// File was imported with the totals given.
+    Imported(ImportSummary),
+}
+
+/// Manages state related to the record being composed by the user and the UI that renders it.
+#[derive(Debug, Default)]
+struct ProduceState {
+    /// Field that currently has focus.
+    active_field: ProduceField,
+    /// Flag indicating whether the user is currently editing the field that has focus.
+    editing: bool,
+    /// Input for the topic that the record is produced to.
+    topic: TextInput,
+    /// Input for the partition that the record is produced to. Left empty to let the producer
+    /// choose the partition.
+    partition: TextInput,
+    /// Input for the partition key of the record. Left empty to produce a record without a key.
+    key: TextInput,
+    /// Input for the headers of the record, one `key=value` pair per line.
+    headers: TextArea,
+    /// Input for the version of the schema that the key is serialized with when the key format
+    /// is backed by the schema registry. Left empty to use the latest version.
+    key_schema_version: TextInput,
+    /// Input for the version of the schema that the value is serialized with when the value
+    /// format is backed by the schema registry. Left empty to use the latest version.
+    value_schema_version: TextInput,
+    /// Input for the value of the record. Left empty to produce a tombstone.
+    value: TextArea,
+    /// Flag indicating whether a record has been sent and is waiting to be acknowledged.
+    sending: bool,
+    /// Running totals of the file being imported, if one is in progress.
+    importing: Option<ImportSummary>,
+    /// Dialog for importing a JSON Lines file, if it is open.
+    import_dialog: Option<ImportDialog>,
+    /// Result of the last record or file that was sent.
+    last_result: Option<ProduceResult>,
+}
+
+impl ProduceState {
+    /// Creates a new [`ProduceState`] with the topic input set to the specified topic, if any.
+    fn new(topic: Option<&str>) -> Self {
+        Self {
+            topic: TextInput::new(topic.unwrap_or_default()),
+            ..Default::default()
+        }
+    }
+    /// Cycles the focus to the next field.
+    fn select_next_field(&mut self) {
+        self.active_field = self.active_field.next();
+    }
+    /// Passes the [`KeyEvent`] to the input for the field that currently has focus.
+    fn on_input_key_event(&mut self, event: KeyEvent) {
+        match self.active_field {
+            ProduceField::Topic => self.topic.on_key_event(event),
+            ProduceField::Partition => self.partition.on_key_event(event),
+            ProduceField::Key => self.key.on_key_event(event),
+            ProduceField::Headers => self.headers.on_key_event(event),
+            ProduceField::KeySchemaVersion => self.key_schema_version.on_key_event(event),
+            ProduceField::ValueSchemaVersion => self.value_schema_version.on_key_event(event),
+            ProduceField::Value => self.value.on_key_event(event),
+        };
+    }
+    /// Clears the key, headers and value of the record. The topic, partition and schema versions
+    /// are kept so that another record can easily be sent to the same destination.
+    fn clear(&mut self) {
+        self.key = TextInput::default();
+        self.headers = TextArea::default();
+        self.value = TextArea::default();
+        self.last_result = None;
+    }
+    /// Creates the [`ProduceRequest`] from the values entered by the user.
+    fn parse(&self) -> anyhow::Result<ProduceRequest> {
+        let topic = match self.topic.value().trim() {
+            "" => anyhow::bail!("topic is required"),
+            t => String::from(t),
+        };
+
+        let partition = match self.partition.value().trim() {
+            "" => None,
+            p => match p.parse::<i32>() {
+                Ok(p) if p >= 0 => Some(p),
+                _ => anyhow::bail!("invalid partition: {}", p),
+            },
+        };
+
+        let key = Some(self.key.value())
+            .filter(|k| !k.is_empty())
+            .map(String::from);
+
+        let headers = parse_headers(&self.headers.value())?;
+
+        let value = Some(self.value.value()).filter(|_| !self.value.is_empty());
+
+        let key_schema_version = parse_schema_version(self.key_schema_version.value(), "key")?;
+
+        let value_schema_version =
+            parse_schema_version(self.value_schema_version.value(), "value")?;
+
+        Ok(ProduceRequest {
+            topic,
+            partition,
+            key,
+            headers,
+            value,
+            key_schema_version,
+            value_schema_version,
+            timestamp: None,
+        })
+    }
+}
+
+/// Contains the [`Color`]s from the application [`Theme`] required to render the [`Produce`]
+/// component.
+#[derive(Debug)]
+struct ProduceTheme {
+    /// Color used for the borders of the main info panels.
+    panel_border_color: Color,
+    /// Color used for the borders of the selected info panel.
+    selected_panel_border_color: Color,
+    /// Color used for the label text in tables, etc.
+    label_color: Color,
+    /// Color used for the topic, partition and key text.
+    record_info_text_color: Color,
+    /// Color used for the headers text.
+    record_headers_text_color: Color,
+    /// Color used for the value text.
+    record_value_text_color: Color,
+    /// Color used for the result text when a record was produced successfully.
+    success_text_color: Color,
+    /// Color used for the result text when a record failed to be produced.
+    failure_text_color: Color,
+    /// Color used for the key bindings text.
+    key_bindings_text_color: Color,
+}
+
+impl From<&Theme> for ProduceTheme {
+    /// Converts a reference to a [`Theme`] to a new [`ProduceTheme`].
+    fn from(value: &Theme) -> Self {
+        let panel_border_color =
+            Color::from_str(value.panel_border_color.as_str()).expect("valid RGB hex");
+
+        let selected_panel_border_color =
+            Color::from_str(value.selected_panel_border_color.as_str()).expect("valid RGB hex");
+
+        let label_color = Color::from_str(value.label_color.as_str()).expect("valid RGB hex");
+
+        let record_info_text_color =
+            Color::from_str(value.record_info_text_color.as_str()).expect("valid RGB hex");
+
+        let record_headers_text_color =
+            Color::from_str(value.record_headers_text_color.as_str()).expect("valid RGB hex");
+
+        let record_value_text_color =
+            Color::from_str(value.record_value_text_color.as_str()).expect("valid RGB hex");
+
+        let success_text_color =
+            Color::from_str(value.notification_text_color_success.as_str()).expect("valid RGB hex");
+
+        let failure_text_color =
+            Color::from_str(value.notification_text_color_failure.as_str()).expect("valid RGB hex");
+
+        let key_bindings_text_color =
+            Color::from_str(value.key_bindings_text_color.as_str()).expect("valid RGB hex");
+
+        Self {
+            panel_border_color,
+            selected_panel_border_color,
+            label_color,
+            record_info_text_color,
+            record_headers_text_color,
+            record_value_text_color,
+            success_text_color,
+            failure_text_color,
+            key_bindings_text_color,
+        }
+    }
+}
+
+/// Configuration used to create a new [`Produce`] component.
+#[derive(Builder, Debug)]
+pub struct ProduceConfig<'a> {
+    /// Topic that the record is produced to by default, if any.
+    #[builder(default)]
+    topic: Option<String>,
+    /// Reference to the application [`Theme`].
+    theme: &'a Theme,
+}
+
+impl<'a> ProduceConfig<'a> {
+    /// Creates a new default [`ProduceConfigBuilder`] which can be used to create a new
+    /// [`ProduceConfig`].
+    pub fn builder() -> ProduceConfigBuilder<'a> {
+        ProduceConfigBuilder::default()
+    }
+}
+
+impl<'a> From<ProduceConfig<'a>> for Produce {
+    /// Converts from an owned [`ProduceConfig`] to an owned [`Produce`].
+    fn from(value: ProduceConfig<'a>) -> Self {
+        Self::new(value)
+    }
+}
+
+/// The application [`Component`] that is responsible for composing records and producing them to
+/// a Kafka topic.
+#[derive(Debug)]
+pub struct Produce {
+    /// Current state of the component and it's underlying widgets.
+    state: ProduceState,
+    /// Color scheme for the component.
+    theme: ProduceTheme,
+}
+
+impl Produce {
+    /// Creates a new [`Produce`] component using the specified [`ProduceConfig`].
+    fn new(config: ProduceConfig) -> Self {
+        Self {
+            state: ProduceState::new(config.topic.as_deref()),
+            theme: config.theme.into(),
+        }
+    }
+    /// Creates the [`Block`] for the panel that renders the specified field, highlighting it when
+    /// the field has focus.
+    fn field_block(&self, field: ProduceField, title: &str) -> Block<'static> {
+        let title = if self.state.editing && self.state.active_field == field {
+            format!(" {} (editing) ", title)
+        } else {
+            format!(" {} ", title)
+        };
+
+        let block = Block::bordered()
+            .title(title)
+            .border_style(self.theme.panel_border_color)
+            .padding(Padding::new(1, 1, 0, 0));
+
+        if self.state.active_field == field {
+            block
+                .border_type(BorderType::Thick)
+                .border_style(self.theme.selected_panel_border_color)
+        } else {
+            block
+        }
+    }
+    /// Renders the panel for a single line field using the specified [`TextInput`].
+    fn render_input(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        field: ProduceField,
+        title: &str,
+        input: &TextInput,
+    ) {
+        let block = self.field_block(field, title);
+        let inner_area = block.inner(area);
+
+        frame.render_widget(block, area);
+
+        input.render(
+            frame,
+            inner_area,
+            self.theme.record_info_text_color,
+            self.state.editing && self.state.active_field == field,
+        );
+    }
+    /// Renders the panel for a multiple line field using the specified [`TextArea`].
+    fn render_text_area(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        field: ProduceField,
+        title: &str,
+        text_area: &TextArea,
+        color: Color,
+    ) {
+        let block = self.field_block(field, title);
+        let inner_area = block.inner(area);
+
+        frame.render_widget(block, area);
+
+        text_area.render(
+            frame,
+            inner_area,
+            color,
+            self.state.editing && self.state.active_field == field,
+        );
+    }
+    /// Renders the result of the last record that was sent.
+    fn render_result(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::bordered()
+            .title(" Result ")
+            .border_style(self.theme.panel_border_color)
+            .padding(Padding::new(1, 1, 0, 0));
+
+        let label_style = Style::from(self.theme.label_color).bold();
+
+        let text = match (
+            self.state.sending,
+            self.state.importing.as_ref(),
+            self.state.last_result.as_ref(),
+        ) {
+            (true, _, _) => Line::from("Sending..."),
+            (false, Some(progress), _) => Line::from(format!(
+                "Importing... {} produced, {} failed",
+                progress.produced, progress.failed
+            )),
+            (false, None, None) => Line::from("No record sent"),
+            (false, None, Some(ProduceResult::Produced(produced))) => Line::from_iter([
+                Span::styled("Topic: ", label_style),
+                Span::raw(produced.topic.as_str()),
+                Span::raw(" | "),
+                Span::styled("Partition: ", label_style),
+                Span::raw(produced.partition.to_string()),
+                Span::raw(" | "),
+                Span::styled("Offset: ", label_style),
+                Span::raw(produced.offset.to_string()),
+            ])
+            .style(self.theme.success_text_color),
+            (false, None, Some(ProduceResult::Failed(e))) => {
+                Line::from(e.as_str()).style(self.theme.failure_text_color)
+            }
+            (false, None, Some(ProduceResult::Imported(summary))) => {
+                let color = if summary.failed == 0 {
+                    self.theme.success_text_color
+                } else {
+                    self.theme.failure_text_color
+                };
+
+                let mut spans = vec![
+                    Span::styled("Produced: ", label_style),
+                    Span::raw(summary.produced.to_string()),
+                    Span::raw(" | "),
+                    Span::styled("Failed: ", label_style),
+                    Span::raw(summary.failed.to_string()),
+                    Span::raw(" | "),
+                    Span::styled("Elapsed: ", label_style),
+                    Span::raw(format!("{:.1}s", summary.elapsed.as_secs_f64())),
+                ];
+
+                if let Some(e) = summary.last_error.as_ref() {
+                    spans.push(Span::raw(" | "));
+                    spans.push(Span::styled("Last Error: ", label_style));
+                    spans.push(Span::raw(e.as_str()));
+                }
+
+                Line::from(spans).style(color)
+            }
+        };
+
+        let paragraph = Paragraph::new(text).block(block).wrap(Wrap { trim: false });
+
+        frame.render_widget(paragraph, area);
+    }
+    /// Maps a [`KeyEvent`] while the user is editing the field that has focus. Every key event is
+    /// consumed by the field.
+    fn map_editing_key_event(&mut self, event: KeyEvent) -> MappedKeyEvent {
+        match event.code {
+            KeyCode::Esc => self.state.editing = false,
+            KeyCode::Tab => self.state.select_next_field(),
+            KeyCode::Enter if !self.state.active_field.is_multi_line() => {
+                self.state.editing = false;
+            }
+            _ => self.state.on_input_key_event(event),
+        }
+
+        MappedKeyEvent::Consumed
+    }
+    /// Renders the import dialog as a popup centered in the given area.
+    fn render_import_dialog(&self, frame: &mut Frame, area: Rect) {
+        let Some(dialog) = self.state.import_dialog.as_ref() else {
+            return;
+        };
+
+        let rect = area.centered(Constraint::Percentage(60), Constraint::Length(11));
+
+        let popup_block = Block::bordered()
+            .title(" Import JSON Lines File ")
+            .border_style(self.theme.selected_panel_border_color)
+            .border_type(BorderType::Thick)
+            .padding(Padding::new(1, 1, 0, 0));
+
+        let inner_area = popup_block.inner(rect);
+
+        let [
+            path_label,
+            path_input,
+            topic_label,
+            topic_input,
+            rate_label,
+            rate_input,
+            _,
+            error_area,
+        ] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1); 8])
+            .areas(inner_area);
+
+        let label_style = Style::from(self.theme.label_color).bold();
+        let label = |text: &'static str| Paragraph::new(text).style(label_style);
+
+        frame.render_widget(Clear, rect);
+        frame.render_widget(popup_block, rect);
+        frame.render_widget(label("File"), path_label);
+        frame.render_widget(
+            label("Topic (empty to use the topic on each line)"),
+            topic_label,
+        );
+        frame.render_widget(
+            label("Records Per Second (empty for unlimited)"),
+            rate_label,
+        );
+
+        let inputs = [
+            (&dialog.path, path_input, ImportDialogField::Path),
+            (&dialog.topic, topic_input, ImportDialogField::Topic),
+            (&dialog.rate, rate_input, ImportDialogField::Rate),
+        ];
+
+        for (input, input_area, field) in inputs {
+            input.render(
+                frame,
+                input_area,
+                self.theme.record_info_text_color,
+                dialog.active_field == field,
+            );
+        }
+
+        if let Some(error) = dialog.error.as_ref() {
+            let error_text = Paragraph::new(error.as_str()).style(self.theme.failure_text_color);
+            frame.render_widget(error_text, error_area);
+        }
+    }
+    /// Maps a [`KeyEvent`] while the import dialog is open. Every key event is consumed by the
+    /// dialog.
+    fn map_import_dialog_key_event(&mut self, event: KeyEvent) -> MappedKeyEvent {
+        let dialog = self
+            .state
+            .import_dialog
+            .as_mut()
+            .expect("import dialog open");
+
+        match event.code {
+            KeyCode::Esc => {
+                self.state.import_dialog = None;
+                MappedKeyEvent::Consumed
+            }
+            KeyCode::Tab => {
+                dialog.select_next_field();
+                MappedKeyEvent::Consumed
+            }
+            KeyCode::Enter => match dialog.parse() {
+                Ok(request) => {
+                    self.state.import_dialog = None;
+                    self.state.importing = Some(ImportSummary::default());
+                    MappedKeyEvent::Dispatch(Event::ImportRecords(request))
+                }
+                Err(e) => {
+                    dialog.error = Some(e.to_string());
+                    MappedKeyEvent::Consumed
+                }
+            },
+            _ => {
+                dialog.active_input_mut().on_key_event(event);
+                MappedKeyEvent::Consumed
+            }
+        }
+    }
+    /// Determines if a record or file is currently being produced.
+    fn is_busy(&self) -> bool {
+        self.state.sending || self.state.importing.is_some()
+    }
+    /// Invoked when the user requests that a JSON Lines file be imported.
+    fn open_import_dialog(&mut self) -> MappedKeyEvent {
+        if !self.is_busy() {
+            self.state.import_dialog = Some(ImportDialog::new(self.state.topic.value().trim()));
+        }
+
+        MappedKeyEvent::Consumed
+    }
+    /// Invoked when the user requests that the record be sent.
+    fn on_send(&mut self) -> MappedKeyEvent {
+        if self.is_busy() {
+            return MappedKeyEvent::Consumed;
+        }
+
+        match self.state.parse() {
+            Ok(record) => {
+                self.state.sending = true;
+                MappedKeyEvent::Dispatch(Event::ProduceRecord(Box::new(record)))
+            }
+            Err(e) => {
+                self.state.last_result = Some(ProduceResult::Failed(e.to_string()));
+                MappedKeyEvent::Dispatch(Event::DisplayNotification(Notification::failure(
+                    "Invalid Record",
+                )))
+            }
+        }
+    }
+}
+
+impl Component for Produce {
+    /// Returns the name of the [`Component`] which is displayed to the user as a menu item.
+    fn name(&self) -> &'static str {
+        "Produce"
+    }
+    /// Renders the component-specific widgets to the terminal.
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let [left_panel, right_panel] = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+            .areas(area);
+
+        let [schema_version_panel, value_panel] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(3)])
+            .areas(right_panel);
+
+        let [key_schema_version_panel, value_schema_version_panel] = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .areas(schema_version_panel);
+
+        let [
+            topic_panel,
+            partition_panel,
+            key_panel,
+            headers_panel,
+            result_panel,
+        ] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(3),
+                Constraint::Length(4),
+            ])
+            .areas(left_panel);
+
+        self.render_input(
+            frame,
+            topic_panel,
+            ProduceField::Topic,
+            "Topic",
+            &self.state.topic,
+        );
+
+        self.render_input(
+            frame,
+            partition_panel,
+            ProduceField::Partition,
+            "Partition (empty for any)",
+            &self.state.partition,
+        );
+
+        self.render_input(
+            frame,
+            key_panel,
+            ProduceField::Key,
+            "Key (empty for none)",
+            &self.state.key,
+        );
+
+        self.render_text_area(
+            frame,
+            headers_panel,
+            ProduceField::Headers,
+            "Headers (key=value per line)",
+            &self.state.headers,
+            self.theme.record_headers_text_color,
+        );
+
+        self.render_result(frame, result_panel);
+
+        self.render_input(
+            frame,
+            key_schema_version_panel,
+            ProduceField::KeySchemaVersion,
+            "Key Schema Version (empty for latest)",
+            &self.state.key_schema_version,
+        );
+
+        self.render_input(
+            frame,
+            value_schema_version_panel,
+            ProduceField::ValueSchemaVersion,
+            "Value Schema Version (empty for latest)",
+            &self.state.value_schema_version,
+        );
+
+        self.render_text_area(
+            frame,
+            value_panel,
+            ProduceField::Value,
+            "Value (empty for tombstone)",
+            &self.state.value,
+            self.theme.record_value_text_color,
+        );
+
+        if self.state.import_dialog.is_some() {
+            self.render_import_dialog(frame, area);
+        }
+    }
+    /// Allows the [`Component`] to map a [`KeyEvent`] to an [`Event`] which will be published
+    /// for processing.
+    fn map_key_event(
+        &mut self,
+        event: KeyEvent,
+        _buffered: Option<&BufferedKeyPress>,
+    ) -> MappedKeyEvent {
+        if self.state.import_dialog.is_some() {
+            return self.map_import_dialog_key_event(event);
+        }
+
+        if self.state.editing {
+            return self.map_editing_key_event(event);
+        }
+
+        match event.code {
+            KeyCode::Enter => {
+                self.state.editing = true;
+                MappedKeyEvent::Consumed
+            }
+            KeyCode::Char('s') => self.on_send(),
+            KeyCode::Char('c') => {
+                self.state.clear();
+                MappedKeyEvent::Consumed
+            }
+            KeyCode::Char('i') => self.open_import_dialog(),
+            _ => MappedKeyEvent::Unhandled,
+        }
+    }
+    /// Determines if the [`Component`] is currently capturing all key input, which is the case
+    /// while the user is editing a field or the import dialog is open.
+    fn is_capturing_input(&self) -> bool {
+        self.state.editing || self.state.import_dialog.is_some()
+    }
+    /// Allows the [`Component`] to handle any [`Event`] that was not handled by the main
+    /// application.
+    fn on_app_event(&mut self, event: &Event) {
+        match event {
+            Event::SelectNextWidget => self.state.select_next_field(),
+            Event::RecordProduced(produced) => {
+                self.state.sending = false;
+                self.state.last_result = Some(ProduceResult::Produced(produced.clone()));
+            }
+            Event::ProduceRecordFailure(e) => {
+                self.state.sending = false;
+                self.state.last_result = Some(ProduceResult::Failed(format!("{:#}", e)));
+            }
+            Event::ImportProgressed(summary) if self.state.importing.is_some() => {
+                self.state.importing = Some(summary.clone());
+            }
+            Event::RecordsImported(summary) => {
+                self.state.importing = None;
+                self.state.last_result = Some(ProduceResult::Imported(summary.clone()));
+            }
+            Event::ImportRecordsFailure(e) => {
+                self.state.importing = None;
+                self.state.last_result = Some(ProduceResult::Failed(format!("{:#}", e)));
+            }
+            _ => {}
+        }
+    }
+    /// Allows the [`Component`] to render the status line text into the footer.
+    fn render_status_line(&self, frame: &mut Frame, area: Rect) {
+        let status = if self.state.sending {
+            "Sending"
+        } else if self.state.importing.is_some() {
+            "Importing"
+        } else {
+            "Idle"
+        };
+
+        let line = Line::from_iter([
+            Span::styled("Topic: ", Style::from(self.theme.label_color).bold()),
+            Span::raw(self.state.topic.value()),
+            Span::raw(" | "),
+            Span::raw(status),
+        ]);
+
+        frame.render_widget(Paragraph::new(line).left_aligned(), area);
+    }
+    /// Allows the [`Component`] to render the key bindings text into the footer.
+    fn render_key_bindings(&self, frame: &mut Frame, area: Rect) {
+        let key_bindings = if self.state.import_dialog.is_some() {
+            Vec::from(IMPORT_DIALOG_KEY_BINDINGS)
+        } else if self.state.editing {
+            vec![KEY_BINDING_STOP_EDITING, KEY_BINDING_NEXT_FIELD]
+        } else {
+            let mut key_bindings = Vec::from(PRODUCE_KEY_BINDINGS);
+            key_bindings.extend_from_slice(&[
+                KEY_BINDING_EDIT,
+                KEY_BINDING_SEND,
+                KEY_BINDING_CLEAR,
+                KEY_BINDING_IMPORT,
+            ]);
+            key_bindings
+        };
+
+        let text = Paragraph::new(key_bindings.join(" | "))
+            .style(self.theme.key_bindings_text_color)
+            .right_aligned();
+
+        frame.render_widget(text, area);
+    }
+}
@@ -1,10 +1,19 @@
 use crate::{
     app::{BufferedKeyPress, config::Theme},
     event::Event,
-    kafka::{ConsumerMode, Record},
-    ui::{Component, MappedKeyEvent, widget::ConsumerStatusLine},
+    kafka::{
+        ConsumerMode, GroupRebalance, RawBytes, Record, RecordHeader, SeekPosition, TopicPartition,
+        filter::RecordFilter,
+        producer::{ProduceRecord, ReplayRecord},
+    },
+    ui::{
+        Component, MappedKeyEvent,
+        widget::{ConsumerStatusLine, TextInput},
+    },
+    util,
 };
 
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
 use bounded_vec_deque::BoundedVecDeque;
 use crossterm::event::{KeyCode, KeyEvent};
 use derive_builder::Builder;
@@ -14,20 +23,85 @@ use ratatui::{
     style::{Color, Modifier, Style, Stylize},
     text::ToSpan,
     widgets::{
-        Block, BorderType, Borders, Padding, Paragraph, Row, Scrollbar, ScrollbarOrientation,
-        ScrollbarState, Table, TableState, Wrap,
+        Block, BorderType, Borders, Clear, Padding, Paragraph, Row, Scrollbar,
+        ScrollbarOrientation, ScrollbarState, Table, TableState, Wrap,
     },
 };
-use std::{cell::Cell, collections::BTreeMap, rc::Rc, str::FromStr};
+use std::{
+    cell::Cell,
+    collections::{BTreeSet, HashSet},
+    fmt::{Display, Write},
+    rc::Rc,
+    str::FromStr,
+};
 
 /// Value displayed for the partition key field when one is not present in the Kafka record.
 const EMPTY_PARTITION_KEY: &str = "<empty>";
 
+/// Value displayed for the partition key field when it could not be deserialized.
+const DECODE_ERROR_PARTITION_KEY: &str = "<decode error>";
+
+/// Value displayed for record metadata that is not available.
+const UNKNOWN_METADATA_VALUE: &str = "-";
+
+/// Value displayed for a record header that was set without a value.
+const NULL_HEADER_VALUE: &str = "<null>";
+
+/// Prefix of the value displayed for a record header whose value is not valid UTF-8 and is
+/// rendered as hex instead.
+const HEX_HEADER_VALUE_PREFIX: &str = "0x";
+
 /// Key bindings that are displayed to the user in the footer no matter what the current state of
 /// the application is when viewing the records UI.
 const RECORDS_STANDARD_KEY_BINDINGS: [&str; 2] =
     [super::KEY_BINDING_QUIT, super::KEY_BINDING_CHANGE_FOCUS];
 
+/// Number of bytes rendered on each line of a hex dump.
+const HEX_DUMP_BYTES_PER_LINE: usize = 16;
+
+/// Key bindings that are displayed to the user in the footer while the seek dialog is open.
+const SEEK_DIALOG_KEY_BINDINGS: [&str; 3] = ["(esc) cancel", "(tab) next field", "(enter) seek"];
+
+/// Text displayed to the user in the footer for the filter key binding.
+const KEY_BINDING_FILTER: &str = "(/) filter";
+
+/// Key bindings that are displayed to the user in the footer while the filter dialog is open.
+const FILTER_DIALOG_KEY_BINDINGS: [&str; 3] = [
+    "(esc) cancel",
+    "(tab) toggle consumed records",
+    "(enter) apply filter",
+];
+
+/// Text displayed to the user in the footer for the mark record key binding.
+const KEY_BINDING_MARK: &str = "(m) mark";
+
+/// Text displayed to the user in the footer for the clear marks key binding.
+const KEY_BINDING_CLEAR_MARKS: &str = "(M) clear marks";
+
+/// Text displayed to the user in the footer for the replay records key binding.
+const KEY_BINDING_REPLAY: &str = "(R) replay";
+
+/// Key bindings that are displayed to the user in the footer while the replay dialog is open.
+const REPLAY_DIALOG_KEY_BINDINGS: [&str; 3] =
+    ["(esc) cancel", "(tab) next field", "(enter) replay"];
+
+/// Value displayed in the record list next to records that are marked for replay.
+const MARKED_RECORD_INDICATOR: &str = "*";
+
+/// Separator between the header rewrites entered by the user in the replay dialog.
+const HEADER_REWRITE_SEPARATOR: char = ',';
+
+/// Prefix of a header rewrite that removes the header instead of setting it.
+const HEADER_REWRITE_REMOVE_PREFIX: char = '-';
+
+/// Uniquely identifies a [`Record`] by its topic, partition and offset.
+type RecordId = (String, i32, i64);
+
+/// Returns the [`RecordId`] of the given [`Record`].
+fn record_id(record: &Record) -> RecordId {
+    (record.topic.clone(), record.partition, record.offset)
+}
+
 /// Enumeration of the widgets in the [`Records`] component that can have focus.
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
 enum RecordsWidget {
@@ -37,6 +111,368 @@ enum RecordsWidget {
     Headers,
 }
 
+/// Enumeration of the ways that the value of the selected [`Record`] can be viewed.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+enum ValueView {
+    /// Value as decoded by the configured deserializer.
+    #[default]
+    Decoded,
+    /// Hex dump of the raw bytes read from the topic.
+    Hex,
+    /// Base64 encoding of the raw bytes read from the topic.
+    Base64,
+    /// Raw bytes read from the topic interpreted as UTF-8, replacing any invalid sequences.
+    Utf8Lossy,
+}
+
+impl ValueView {
+    /// Returns the [`ValueView`] that follows this one when the user cycles through them.
+    fn next(self) -> Self {
+        match self {
+            Self::Decoded => Self::Hex,
+            Self::Hex => Self::Base64,
+            Self::Base64 => Self::Utf8Lossy,
+            Self::Utf8Lossy => Self::Decoded,
+        }
+    }
+    /// Renders the [`RawBytes`] as text for this [`ValueView`].
+    fn render_raw(self, raw: &RawBytes) -> String {
+        match self {
+            Self::Hex => hex_dump(&raw.data),
+            Self::Base64 => BASE64.encode(&raw.data),
+            Self::Decoded | Self::Utf8Lossy => String::from_utf8_lossy(&raw.data).into_owned(),
+        }
+    }
+    /// Renders the [`RawBytes`] as a single line of text for this [`ValueView`].
+    fn render_raw_inline(self, raw: &RawBytes) -> String {
+        match self {
+            Self::Hex => util::to_hex(&raw.data),
+            _ => self.render_raw(raw),
+        }
+    }
+}
+
+impl Display for ValueView {
+    /// Writes a string representation of the [`ValueView`] value to the
+    /// [`std::fmt::Formatter`].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Decoded => "Decoded",
+            Self::Hex => "Hex",
+            Self::Base64 => "Base64",
+            Self::Utf8Lossy => "UTF-8 Lossy",
+        };
+
+        f.write_str(s)
+    }
+}
+
+/// Formats the size of the key or value of a record for display, if one exists.
+fn format_size(size: Option<usize>) -> String {
+    match size {
+        Some(1) => String::from("1 byte"),
+        Some(size) => format!("{} bytes", size),
+        None => String::from(UNKNOWN_METADATA_VALUE),
+    }
+}
+
+/// Formats the bytes as a hex dump where each line contains the offset of the first byte on the
+/// line, the hex value of each byte and the printable ASCII characters.
+fn hex_dump(data: &[u8]) -> String {
+    let mut dump = String::new();
+
+    for (i, chunk) in data.chunks(HEX_DUMP_BYTES_PER_LINE).enumerate() {
+        let _ = write!(dump, "{:08x} ", i * HEX_DUMP_BYTES_PER_LINE);
+
+        for j in 0..HEX_DUMP_BYTES_PER_LINE {
+            if j % 8 == 0 {
+                dump.push(' ');
+            }
+
+            match chunk.get(j) {
+                Some(b) => {
+                    let _ = write!(dump, "{:02x} ", b);
+                }
+                None => dump.push_str("   "),
+            }
+        }
+
+        let ascii: String = chunk
+            .iter()
+            .map(|b| {
+                if b.is_ascii_graphic() || *b == b' ' {
+                    *b as char
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+
+        let _ = writeln!(dump, " |{}|", ascii);
+    }
+
+    dump
+}
+
+/// Enumeration of the fields in the [`SeekDialog`] that can have focus.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+enum SeekDialogField {
+    #[default]
+    Topic,
+    Partition,
+    Position,
+}
+
+/// Manages state for the dialog the user can open to reposition the running Kafka consumer on a
+/// single partition or all partitions.
+#[derive(Debug, Default)]
+struct SeekDialog {
+    /// Input for the topic of the partition to seek. Pre-populated when a single topic is
+    /// consumed.
+    topic: TextInput,
+    /// Input for the partition to seek. Left empty to seek all partitions.
+    partition: TextInput,
+    /// Input for the position to seek to.
+    position: TextInput,
+    /// Field in the dialog that currently has focus.
+    active_field: SeekDialogField,
+    /// Message describing why the values entered by the user are invalid, if any.
+    error: Option<String>,
+}
+
+impl SeekDialog {
+    /// Creates a new [`SeekDialog`] with the topic input set to the topic, if any, and the focus
+    /// on the partition input when it is.
+    fn new(topic: Option<&str>) -> Self {
+        Self {
+            topic: TextInput::new(topic.unwrap_or_default()),
+            active_field: if topic.is_some() {
+                SeekDialogField::Partition
+            } else {
+                SeekDialogField::Topic
+            },
+            ..Default::default()
+        }
+    }
+    /// Returns a mutable reference to the [`TextInput`] for the field that currently has focus.
+    fn active_input_mut(&mut self) -> &mut TextInput {
+        match self.active_field {
+            SeekDialogField::Topic => &mut self.topic,
+            SeekDialogField::Partition => &mut self.partition,
+            SeekDialogField::Position => &mut self.position,
+        }
+    }
+    /// Cycles the focus to the next field in the dialog.
+    fn select_next_field(&mut self) {
+        self.active_field = match self.active_field {
+            SeekDialogField::Topic => SeekDialogField::Partition,
+            SeekDialogField::Partition => SeekDialogField::Position,
+            SeekDialogField::Position => SeekDialogField::Topic,
+        };
+    }
+    /// Parses the values entered by the user into the topic partition and [`SeekPosition`] that
+    /// the Kafka consumer should be repositioned to.
+    fn parse(&self) -> anyhow::Result<(Option<TopicPartition>, SeekPosition)> {
+        let partition = match self.partition.value().trim() {
+            "" => None,
+            p => match p.parse::<i32>() {
+                Ok(p) => Some(p),
+                Err(_) => anyhow::bail!("invalid partition: {}", p),
+            },
+        };
+
+        let partition = match (self.topic.value().trim(), partition) {
+            (_, None) => None,
+            ("", Some(_)) => anyhow::bail!("topic is required to seek a single partition"),
+            (t, Some(p)) => Some(TopicPartition::new(t, p)),
+        };
+
+        let position = self.position.value().parse()?;
+
+        Ok((partition, position))
+    }
+}
+
+/// Manages state for the dialog the user can open to replace the filter applied to the records
+/// consumed from the Kafka topic.
+#[derive(Debug, Default)]
+struct FilterDialog {
+    /// Input for the filter. Left empty to clear the filter.
+    filter: TextInput,
+    /// Flag indicating whether the filter should also be applied to the records that have already
+    /// been consumed, removing any that do not match.
+    reapply: bool,
+    /// Message describing why the filter entered by the user is invalid, if any.
+    error: Option<String>,
+}
+
+impl FilterDialog {
+    /// Creates a new [`FilterDialog`] with the input set to the current filter, if any.
+    fn new(filter: Option<&RecordFilter>) -> Self {
+        Self {
+            filter: TextInput::new(filter.map_or("", RecordFilter::expression)),
+            ..Default::default()
+        }
+    }
+    /// Parses the filter entered by the user. An empty value clears the filter.
+    fn parse(&self) -> anyhow::Result<Option<RecordFilter>> {
+        match self.filter.value().trim() {
+            "" => Ok(None),
+            f => RecordFilter::parse(f).map(Some),
+        }
+    }
+}
+
+/// A change to the headers of the records replayed by the user.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum HeaderRewrite {
+    /// Replaces all headers with the key by a single header with the value.
+    Set(String, String),
+    /// Removes all headers with the key.
+    Remove(String),
+}
+
+impl HeaderRewrite {
+    /// Parses the header rewrites entered by the user as a comma separated list of `key=value`
+    /// entries, which set a header, and `-key` entries, which remove it.
+    fn parse_all(text: &str) -> anyhow::Result<Vec<Self>> {
+        let mut rewrites = Vec::new();
+
+        for entry in text.split(HEADER_REWRITE_SEPARATOR) {
+            let entry = entry.trim();
+
+            if entry.is_empty() {
+                continue;
+            }
+
+            let rewrite = match entry.strip_prefix(HEADER_REWRITE_REMOVE_PREFIX) {
+                Some(key) => Self::Remove(String::from(key.trim())),
+                None => match entry.split_once('=') {
+                    Some((key, value)) => Self::Set(String::from(key.trim()), String::from(value)),
+                    None => anyhow::bail!("invalid header rewrite: {}", entry),
+                },
+            };
+
+            if matches!(&rewrite, Self::Set(key, _) | Self::Remove(key) if key.is_empty()) {
+                anyhow::bail!("header rewrite has no key: {}", entry);
+            }
+
+            rewrites.push(rewrite);
+        }
+
+        Ok(rewrites)
+    }
+    /// Applies the rewrite to the given headers, keeping the order of the headers that are not
+    /// affected. A header that is set is added after the existing ones.
+    fn apply(&self, headers: &mut Vec<RecordHeader>) {
+        match self {
+            Self::Set(key, value) => {
+                headers.retain(|h| &h.key != key);
+                headers.push(RecordHeader {
+                    key: key.clone(),
+                    value: Some(value.as_bytes().to_vec()),
+                });
+            }
+            Self::Remove(key) => headers.retain(|h| &h.key != key),
+        }
+    }
+}
+
+/// Enumeration of the fields in the [`ReplayDialog`] that can have focus.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+enum ReplayDialogField {
+    #[default]
+    Topic,
+    Headers,
+}
+
+/// Manages state for the dialog the user can open to confirm replaying the selected or marked
+/// records to a Kafka topic.
+#[derive(Debug, Default)]
+struct ReplayDialog {
+    /// Input for the topic that the records are replayed to.
+    topic: TextInput,
+    /// Input for the header rewrites applied to the records that are replayed.
+    headers: TextInput,
+    /// Field in the dialog that currently has focus.
+    active_field: ReplayDialogField,
+    /// Number of records that will be replayed.
+    count: usize,
+    /// Message describing why the values entered by the user are invalid, if any.
+    error: Option<String>,
+}
+
+impl ReplayDialog {
+    /// Creates a new [`ReplayDialog`] for the specified number of records with the topic input
+    /// set to the topic that the records were consumed from.
+    fn new(topic: &str, count: usize) -> Self {
+        Self {
+            topic: TextInput::new(topic),
+            count,
+            ..Default::default()
+        }
+    }
+    /// Returns a mutable reference to the [`TextInput`] for the field that currently has focus.
+    fn active_input_mut(&mut self) -> &mut TextInput {
+        match self.active_field {
+            ReplayDialogField::Topic => &mut self.topic,
+            ReplayDialogField::Headers => &mut self.headers,
+        }
+    }
+    /// Cycles the focus to the next field in the dialog.
+    fn select_next_field(&mut self) {
+        self.active_field = match self.active_field {
+            ReplayDialogField::Topic => ReplayDialogField::Headers,
+            ReplayDialogField::Headers => ReplayDialogField::Topic,
+        };
+    }
+    /// Parses the values entered by the user into the topic that the records are replayed to and
+    /// the [`HeaderRewrite`]s applied to them.
+    fn parse(&self) -> anyhow::Result<(String, Vec<HeaderRewrite>)> {
+        let topic = match self.topic.value().trim() {
+            "" => anyhow::bail!("topic is required"),
+            t => String::from(t),
+        };
+
+        let rewrites = HeaderRewrite::parse_all(self.headers.value())?;
+
+        Ok((topic, rewrites))
+    }
+}
+
+/// Creates the [`ReplayRecord`] that replays the [`Record`] to the specified topic with the same
+/// key, value and headers, after applying the [`HeaderRewrite`]s. The partition is left for the
+/// producer to choose based on the key. A record whose key or value was truncated when it was
+/// consumed is fetched again from its topic partition when it is replayed.
+fn replay_record(record: &Record, topic: &str, rewrites: &[HeaderRewrite]) -> ReplayRecord {
+    let truncated = [record.raw_key.as_ref(), record.raw_value.as_ref()]
+        .into_iter()
+        .flatten()
+        .any(RawBytes::is_truncated);
+
+    let mut headers = record.headers.clone();
+    for rewrite in rewrites {
+        rewrite.apply(&mut headers);
+    }
+
+    ReplayRecord {
+        record: ProduceRecord {
+            topic: String::from(topic),
+            partition: None,
+            key: record.raw_key.as_ref().map(|raw| raw.data.clone()),
+            headers,
+            value: record.raw_value.as_ref().map(|raw| raw.data.clone()),
+            timestamp: None,
+        },
+        refetch: truncated.then(|| {
+            (
+                TopicPartition::new(&record.topic, record.partition),
+                record.offset,
+            )
+        }),
+    }
+}
+
 /// Configuration used to create a new [`Records`] component.
 #[derive(Debug, Builder)]
 pub struct RecordsConfig<'a> {
@@ -45,7 +481,7 @@ pub struct RecordsConfig<'a> {
     /// Topic name that records are being consumed from.
     topic: String,
     /// Any filter that was configured by the user.
-    filter: Option<String>,
+    filter: Option<RecordFilter>,
     /// Controls how many lines each press of a key scrolls the record value text.
     scroll_factor: u16,
     /// Maximum number of records to be displayed in the table widget.
@@ -77,6 +513,8 @@ struct RecordsState {
     selected: Option<Record>,
     /// Collection of the [`Record`]s that have been consumed from the Kafka topic.
     records: BoundedVecDeque<Record>,
+    /// Names of the topics that [`Record`]s have been consumed from.
+    topics: BTreeSet<String>,
     /// [`TableState`] for the table that the records consumed from the Kafka topic are rendered
     /// into.
     list_state: TableState,
@@ -85,10 +523,22 @@ struct RecordsState {
     list_scroll_state: ScrollbarState,
     /// Contains the current scrolling state for the record value text.
     value_scroll: (u16, u16),
+    /// Drives how the value of the selected [`Record`] is displayed.
+    value_view: ValueView,
     /// [`TableState`] for the table that record headers are rendered into.
     headers_state: TableState,
     /// [`ScrollbarState`] for the table that record headers are rendered into.
     headers_scroll_state: ScrollbarState,
+    /// State of the seek dialog when it is open.
+    seek_dialog: Option<SeekDialog>,
+    /// State of the filter dialog when it is open.
+    filter_dialog: Option<FilterDialog>,
+    /// State of the replay dialog when it is open.
+    replay_dialog: Option<ReplayDialog>,
+    /// Identifiers of the [`Record`]s that the user has marked to be replayed.
+    marked: HashSet<RecordId>,
+    /// Latest rebalance of the consumer group when the consumer is subscribed to the topic.
+    group_rebalance: Option<GroupRebalance>,
 }
 
 impl RecordsState {
@@ -101,13 +551,24 @@ impl RecordsState {
             active_widget: RecordsWidget::List,
             selected: None,
             records: BoundedVecDeque::new(max_records),
+            topics: BTreeSet::new(),
             list_state: TableState::default(),
             list_scroll_state: ScrollbarState::default(),
             value_scroll: (0, 0),
+            value_view: ValueView::default(),
             headers_state: TableState::default(),
             headers_scroll_state: ScrollbarState::default(),
+            seek_dialog: None,
+            filter_dialog: None,
+            replay_dialog: None,
+            marked: HashSet::new(),
+            group_rebalance: None,
         }
     }
+    /// Determines if [`Record`]s have been consumed from more than one topic.
+    fn is_multi_topic(&self) -> bool {
+        self.topics.len() > 1
+    }
     /// Determines if there is a [`Record`] currently selected.
     pub fn is_record_selected(&self) -> bool {
         self.selected.is_some()
@@ -126,6 +587,11 @@ impl RecordsState {
             self.value_scroll.0 -= n;
         }
     }
+    /// Cycles the view of the record value to the next [`ValueView`].
+    fn select_next_value_view(&mut self) {
+        self.value_view = self.value_view.next();
+        self.scroll_value_top();
+    }
     /// Moves the record headers scroll state to the top.
     fn scroll_headers_top(&mut self) {
         self.headers_state.select_first();
@@ -181,7 +647,13 @@ impl RecordsState {
     /// Pushes a new [`Record`] onto the current list when a new one is received from the Kafka
     /// consumer.
     fn push_record(&mut self, record: Record) {
-        self.records.push_front(record);
+        if !self.topics.contains(&record.topic) {
+            self.topics.insert(record.topic.clone());
+        }
+
+        if let Some(evicted) = self.records.push_front(record) {
+            self.marked.remove(&record_id(&evicted));
+        }
 
         if let Some(i) = self.list_state.selected().as_mut() {
             let new_idx = *i + 1;
@@ -189,6 +661,74 @@ impl RecordsState {
             self.list_scroll_state = self.list_scroll_state.position(new_idx);
         }
     }
+    /// Removes the buffered [`Record`]s consumed from the specified partition, or all of them if
+    /// no partition is specified, after the Kafka consumer has been repositioned.
+    fn clear_records(&mut self, partition: Option<&TopicPartition>) {
+        match partition {
+            Some(tp) => self
+                .records
+                .retain(|r| r.topic != tp.topic || r.partition != tp.partition),
+            None => {
+                self.records.clear();
+                self.topics.clear();
+            }
+        }
+
+        self.retain_marked();
+        self.clear_selection();
+    }
+    /// Removes the buffered [`Record`]s that do not match the filter applied to the records
+    /// consumed after it was replaced.
+    fn remove_unmatched(&mut self, unmatched: &[Record]) {
+        let unmatched: HashSet<RecordId> = unmatched.iter().map(record_id).collect();
+
+        self.records.retain(|r| !unmatched.contains(&record_id(r)));
+        self.topics = self.records.iter().map(|r| r.topic.clone()).collect();
+
+        self.retain_marked();
+        self.clear_selection();
+    }
+    /// Removes the marks for any [`Record`]s that are no longer buffered.
+    fn retain_marked(&mut self) {
+        let buffered: HashSet<RecordId> = self.records.iter().map(record_id).collect();
+
+        self.marked.retain(|id| buffered.contains(id));
+    }
+    /// Marks the selected [`Record`] to be replayed or removes the mark if it is already marked.
+    fn toggle_selected_mark(&mut self) {
+        let Some(record) = self.selected.as_ref() else {
+            return;
+        };
+
+        let id = record_id(record);
+
+        if !self.marked.remove(&id) {
+            self.marked.insert(id);
+        }
+    }
+    /// Returns the [`Record`]s that should be replayed in the order they were consumed. These are
+    /// the marked records if any are marked, otherwise the selected record.
+    fn replay_targets(&self) -> Vec<&Record> {
+        if self.marked.is_empty() {
+            return self.selected.iter().collect();
+        }
+
+        self.records
+            .iter()
+            .rev()
+            .filter(|r| self.marked.contains(&record_id(r)))
+            .collect()
+    }
+    /// Clears the selected [`Record`] and moves the focus back to the list.
+    fn clear_selection(&mut self) {
+        self.selected = None;
+        self.active_widget = RecordsWidget::List;
+
+        self.list_state.select(None);
+        self.list_scroll_state = self.list_scroll_state.position(0);
+
+        self.reset_details_state();
+    }
     /// Resets the state of the record details widgets to their default values.
     fn reset_details_state(&mut self) {
         self.headers_state.select(None);
@@ -294,6 +834,8 @@ struct RecordsTheme {
     record_headers_text_color: Color,
     /// Color used for the text in the record value.
     record_value_text_color: Color,
+    /// Color used for the text of records that failed to deserialize.
+    record_decode_error_text_color: Color,
 }
 
 impl From<&Theme> for RecordsTheme {
@@ -328,6 +870,9 @@ impl From<&Theme> for RecordsTheme {
         let record_value_text_color =
             Color::from_str(value.record_value_text_color.as_str()).expect("valid RGB hex");
 
+        let record_decode_error_text_color =
+            Color::from_str(value.record_decode_error_text_color.as_str()).expect("valid RGB hex");
+
         Self {
             panel_border_color,
             selected_panel_border_color,
@@ -339,6 +884,7 @@ impl From<&Theme> for RecordsTheme {
             record_info_text_color,
             record_headers_text_color,
             record_value_text_color,
+            record_decode_error_text_color,
         }
     }
 }
@@ -349,8 +895,8 @@ impl From<&Theme> for RecordsTheme {
 pub struct Records {
     /// Kafka topic records are consumed from.
     topic: String,
-    /// Any filter that was configured by the user.
-    filter: Option<String>,
+    /// Any filter that was configured or applied by the user.
+    filter: Option<RecordFilter>,
     /// Controls how many lines each press of a key scrolls the record value text.
     scroll_factor: u16,
     /// Color scheme for the component.
@@ -377,10 +923,23 @@ impl Records {
             state: RecordsState::new(config.consumer_mode, config.max_records),
         }
     }
+    /// Returns the topic records are consumed from when it is a single topic rather than a list of
+    /// topics or a regular expression.
+    fn single_topic(&self) -> Option<&str> {
+        match util::split_topics(&self.topic).as_slice() {
+            [topic] if !topic.starts_with('^') => Some(topic),
+            _ => None,
+        }
+    }
     /// Renders the record list table.
     fn render_record_list(&mut self, frame: &mut Frame, area: Rect) {
+        let title = match self.state.marked.len() {
+            0 => String::from(" Records "),
+            n => format!(" Records ({} marked) ", n),
+        };
+
         let mut record_list_block = Block::bordered()
-            .title(" Records ")
+            .title(title)
             .border_style(self.theme.panel_border_color)
             .padding(Padding::new(1, 1, 0, 0));
 
@@ -390,40 +949,77 @@ impl Records {
                 .border_style(self.theme.selected_panel_border_color);
         }
 
+        let multi_topic = self.state.is_multi_topic();
+
+        let any_marked = !self.state.marked.is_empty();
+
         let records_rows = self.state.records.iter().map(|r| {
             let offset = r.offset.to_string();
 
-            let key = r
-                .key
-                .clone()
-                .unwrap_or_else(|| String::from(EMPTY_PARTITION_KEY));
+            let key = match (&r.key, &r.key_error) {
+                (Some(key), _) => key.clone(),
+                (None, Some(_)) => String::from(DECODE_ERROR_PARTITION_KEY),
+                (None, None) => String::from(EMPTY_PARTITION_KEY),
+            };
 
             let partition = r.partition.to_string();
 
             let timestamp = r.timestamp.to_string();
 
-            Row::new([partition, offset, key, timestamp])
+            let mut cells = vec![partition, offset, key, timestamp];
+
+            if multi_topic {
+                cells.insert(0, r.topic.clone());
+            }
+
+            if any_marked {
+                let marker = match self.state.marked.contains(&record_id(r)) {
+                    true => MARKED_RECORD_INDICATOR,
+                    false => "",
+                };
+
+                cells.insert(0, String::from(marker));
+            }
+
+            let row = Row::new(cells);
+
+            if r.has_decode_error() {
+                row.style(self.theme.record_decode_error_text_color)
+            } else {
+                row
+            }
         });
 
-        let records_table = Table::new(
-            records_rows,
-            [
-                Constraint::Fill(1),
-                Constraint::Fill(1),
-                Constraint::Fill(6),
-                Constraint::Fill(2),
-            ],
-        )
-        .column_spacing(1)
-        .header(Row::new([
+        let mut constraints = vec![
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Fill(6),
+            Constraint::Fill(2),
+        ];
+
+        let mut headers = vec![
             "Partition".bold().style(self.theme.label_color),
             "Offset".bold().style(self.theme.label_color),
             "Key".bold().style(self.theme.label_color),
             "Timestamp".bold().style(self.theme.label_color),
-        ]))
-        .style(self.theme.record_list_text_color)
-        .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED))
-        .block(record_list_block);
+        ];
+
+        if multi_topic {
+            constraints.insert(0, Constraint::Fill(3));
+            headers.insert(0, "Topic".bold().style(self.theme.label_color));
+        }
+
+        if any_marked {
+            constraints.insert(0, Constraint::Length(1));
+            headers.insert(0, "".into());
+        }
+
+        let records_table = Table::new(records_rows, constraints)
+            .column_spacing(1)
+            .header(Row::new(headers))
+            .style(self.theme.record_list_text_color)
+            .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .block(record_list_block);
 
         frame.render_stateful_widget(records_table, area, &mut self.state.list_state);
 
@@ -452,25 +1048,50 @@ impl Records {
     fn render_record_details(&mut self, frame: &mut Frame, area: Rect) {
         let record = self.state.selected.clone().expect("selected Record exists");
 
-        let [info_slice, headers_slice, value_slice] = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Fill(1),
-                Constraint::Fill(3),
-                Constraint::Fill(7),
-            ])
-            .areas(area);
-
         let info_block = Block::bordered()
             .title(" Info ")
             .border_style(self.theme.panel_border_color)
             .padding(Padding::new(1, 1, 0, 0));
 
-        let key_value = record
-            .key
-            .unwrap_or_else(|| String::from(EMPTY_PARTITION_KEY));
+        // the key is shown using the same view as the value so that the raw bytes can be inspected
+        let key_value = match (self.state.value_view, record.raw_key.as_ref()) {
+            (ValueView::Decoded, _) | (_, None) => match (&record.key, &record.key_error) {
+                (Some(key), _) => key.clone(),
+                (None, Some(_)) => String::from(DECODE_ERROR_PARTITION_KEY),
+                (None, None) => String::from(EMPTY_PARTITION_KEY),
+            },
+            (view, Some(raw)) => view.render_raw_inline(raw),
+        };
+
+        let timestamp = format!("{} ({})", record.timestamp, record.timestamp_type);
+
+        let leader_epoch = record
+            .leader_epoch
+            .map(|e| e.to_string())
+            .unwrap_or_else(|| String::from(UNKNOWN_METADATA_VALUE));
+
+        let size = format!(
+            "key {}, value {}",
+            format_size(record.key_size),
+            format_size(record.value_size)
+        );
+
+        let format_schema_id = |id: Option<u32>| {
+            id.map(|id| id.to_string())
+                .unwrap_or_else(|| String::from(UNKNOWN_METADATA_VALUE))
+        };
 
-        let info_rows = vec![
+        let schema_id = format!(
+            "key {}, value {}",
+            format_schema_id(record.key_schema_id),
+            format_schema_id(record.value_schema_id)
+        );
+
+        let mut info_rows = vec![
+            Row::new([
+                "Topic".bold().style(self.theme.label_color),
+                record.topic.to_span(),
+            ]),
             Row::new([
                 "Partition".bold().style(self.theme.label_color),
                 record.partition.to_span(),
@@ -485,10 +1106,52 @@ impl Records {
             ]),
             Row::new([
                 "Timestamp".bold().style(self.theme.label_color),
-                record.timestamp.to_span(),
+                timestamp.to_span(),
+            ]),
+            Row::new([
+                "Leader Epoch".bold().style(self.theme.label_color),
+                leader_epoch.to_span(),
             ]),
+            Row::new(["Size".bold().style(self.theme.label_color), size.to_span()]),
         ];
 
+        if record.key_schema_id.is_some() || record.value_schema_id.is_some() {
+            info_rows.push(Row::new([
+                "Schema Id".bold().style(self.theme.label_color),
+                schema_id.to_span(),
+            ]));
+        }
+
+        if let Some(key_error) = record.key_error.as_ref() {
+            info_rows.push(
+                Row::new([
+                    "Key Error".bold().style(self.theme.label_color),
+                    key_error.to_span(),
+                ])
+                .style(self.theme.record_decode_error_text_color),
+            );
+        }
+
+        if let Some(value_error) = record.value_error.as_ref() {
+            info_rows.push(
+                Row::new([
+                    "Value Error".bold().style(self.theme.label_color),
+                    value_error.to_span(),
+                ])
+                .style(self.theme.record_decode_error_text_color),
+            );
+        }
+
+        // the info panel is sized to fit all of its rows plus the borders
+        let [info_slice, headers_slice, value_slice] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(info_rows.len() as u16 + 2),
+                Constraint::Fill(3),
+                Constraint::Fill(7),
+            ])
+            .areas(area);
+
         let info_table = Table::new(info_rows, [Constraint::Fill(1), Constraint::Fill(9)])
             .column_spacing(1)
             .style(self.theme.record_info_text_color)
@@ -505,9 +1168,20 @@ impl Records {
                 .border_style(self.theme.selected_panel_border_color);
         }
 
-        let header_rows: Vec<Row> = BTreeMap::from_iter(record.headers.iter())
-            .into_iter()
-            .map(|(k, v)| Row::new([k.as_str(), v.as_str()]))
+        let header_rows: Vec<Row> = record
+            .headers
+            .iter()
+            .map(|h| {
+                let value = match (h.value.as_deref(), h.value_str()) {
+                    (None, _) => String::from(NULL_HEADER_VALUE),
+                    (Some(_), Some(s)) => String::from(s),
+                    (Some(bytes), None) => {
+                        format!("{}{}", HEX_HEADER_VALUE_PREFIX, util::to_hex(bytes))
+                    }
+                };
+
+                Row::new([h.key.clone(), value])
+            })
             .collect();
 
         let headers_table = Table::new(header_rows, [Constraint::Min(1), Constraint::Fill(3)])
@@ -530,8 +1204,40 @@ impl Records {
             .begin_symbol(None)
             .end_symbol(None);
 
+        let (value_title, value) = match (self.state.value_view, record.value_error.as_ref()) {
+            (ValueView::Decoded, Some(value_error)) => (
+                String::from(" Value (decode error) "),
+                format!(
+                    "Unable to deserialize the value as {}.\n\n{}",
+                    value_error.format, value_error.message
+                ),
+            ),
+            (ValueView::Decoded, None) => {
+                (String::from(" Value "), record.value.unwrap_or_default())
+            }
+            (view, _) => {
+                let title = match record.raw_value.as_ref() {
+                    Some(raw) if raw.is_truncated() => format!(
+                        " Value ({}, first {} of {} bytes) ",
+                        view,
+                        raw.data.len(),
+                        raw.len
+                    ),
+                    _ => format!(" Value ({}) ", view),
+                };
+
+                let value = record
+                    .raw_value
+                    .as_ref()
+                    .map(|raw| view.render_raw(raw))
+                    .unwrap_or_default();
+
+                (title, value)
+            }
+        };
+
         let mut value_block = Block::bordered()
-            .title(" Value ")
+            .title(value_title)
             .border_style(self.theme.panel_border_color)
             .padding(Padding::new(1, 1, 0, 0));
 
@@ -541,12 +1247,15 @@ impl Records {
                 .border_style(self.theme.selected_panel_border_color);
         }
 
-        let value = record.value.unwrap_or_default();
+        let value_color = match (self.state.value_view, record.value_error.as_ref()) {
+            (ValueView::Decoded, Some(_)) => self.theme.record_decode_error_text_color,
+            _ => self.theme.record_value_text_color,
+        };
 
         let value_paragraph = Paragraph::new(value)
             .block(value_block)
             .wrap(Wrap { trim: false })
-            .style(self.theme.record_value_text_color)
+            .style(value_color)
             .scroll(self.state.value_scroll);
 
         frame.render_widget(info_table, info_slice);
@@ -564,6 +1273,334 @@ impl Records {
 
         frame.render_widget(value_paragraph, value_slice);
     }
+    /// Renders the seek dialog as a popup centered in the given area.
+    fn render_seek_dialog(&self, frame: &mut Frame, area: Rect) {
+        let Some(dialog) = self.state.seek_dialog.as_ref() else {
+            return;
+        };
+
+        let rect = area.centered(Constraint::Length(60), Constraint::Length(10));
+
+        let popup_block = Block::bordered()
+            .title(" Seek ")
+            .border_style(self.theme.selected_panel_border_color)
+            .border_type(BorderType::Thick)
+            .padding(Padding::new(1, 1, 0, 0));
+
+        let inner_area = popup_block.inner(rect);
+
+        let [
+            topic_label,
+            topic_input,
+            partition_label,
+            partition_input,
+            position_label,
+            position_input,
+            _,
+            error_area,
+        ] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1); 8])
+            .areas(inner_area);
+
+        let topic_text = Paragraph::new("Topic").style(self.theme.label_color).bold();
+
+        let partition_text = Paragraph::new("Partition (empty for all)")
+            .style(self.theme.label_color)
+            .bold();
+
+        let position_text = Paragraph::new("Position (beginning, end, offset or timestamp)")
+            .style(self.theme.label_color)
+            .bold();
+
+        frame.render_widget(Clear, rect);
+        frame.render_widget(popup_block, rect);
+        frame.render_widget(topic_text, topic_label);
+        frame.render_widget(partition_text, partition_label);
+        frame.render_widget(position_text, position_label);
+
+        dialog.topic.render(
+            frame,
+            topic_input,
+            self.theme.record_info_text_color,
+            dialog.active_field == SeekDialogField::Topic,
+        );
+
+        dialog.partition.render(
+            frame,
+            partition_input,
+            self.theme.record_info_text_color,
+            dialog.active_field == SeekDialogField::Partition,
+        );
+
+        dialog.position.render(
+            frame,
+            position_input,
+            self.theme.record_info_text_color,
+            dialog.active_field == SeekDialogField::Position,
+        );
+
+        if let Some(error) = dialog.error.as_ref() {
+            let error_text = Paragraph::new(error.as_str()).style(self.theme.paused_text_color);
+            frame.render_widget(error_text, error_area);
+        }
+    }
+    /// Maps a [`KeyEvent`] while the seek dialog is open. Every key event is consumed by the
+    /// dialog.
+    fn map_seek_dialog_key_event(&mut self, event: KeyEvent) -> MappedKeyEvent {
+        let dialog = self.state.seek_dialog.as_mut().expect("seek dialog open");
+
+        match event.code {
+            KeyCode::Esc => {
+                self.state.seek_dialog = None;
+                MappedKeyEvent::Consumed
+            }
+            KeyCode::Tab => {
+                dialog.select_next_field();
+                MappedKeyEvent::Consumed
+            }
+            KeyCode::Enter => match dialog.parse() {
+                Ok((partition, position)) => {
+                    self.state.seek_dialog = None;
+                    MappedKeyEvent::Dispatch(Event::SeekConsumer(partition, position))
+                }
+                Err(e) => {
+                    dialog.error = Some(e.to_string());
+                    MappedKeyEvent::Consumed
+                }
+            },
+            _ => {
+                dialog.active_input_mut().on_key_event(event);
+                MappedKeyEvent::Consumed
+            }
+        }
+    }
+    /// Renders the filter dialog as a popup centered in the given area.
+    fn render_filter_dialog(&self, frame: &mut Frame, area: Rect) {
+        let Some(dialog) = self.state.filter_dialog.as_ref() else {
+            return;
+        };
+
+        let rect = area.centered(Constraint::Percentage(60), Constraint::Length(8));
+
+        let popup_block = Block::bordered()
+            .title(" Filter ")
+            .border_style(self.theme.selected_panel_border_color)
+            .border_type(BorderType::Thick)
+            .padding(Padding::new(1, 1, 0, 0));
+
+        let inner_area = popup_block.inner(rect);
+
+        let [filter_label, filter_input, _, reapply_area, _, error_area] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1); 6])
+            .areas(inner_area);
+
+        let filter_text = Paragraph::new("Filter (jsonpath: or expr: prefix, empty to clear)")
+            .style(self.theme.label_color)
+            .bold();
+
+        let reapply_marker = if dialog.reapply { "x" } else { " " };
+
+        let reapply_text = Paragraph::new(format!(
+            "[{}] Apply to records already consumed",
+            reapply_marker
+        ))
+        .style(self.theme.label_color);
+
+        frame.render_widget(Clear, rect);
+        frame.render_widget(popup_block, rect);
+        frame.render_widget(filter_text, filter_label);
+        frame.render_widget(reapply_text, reapply_area);
+
+        dialog
+            .filter
+            .render(frame, filter_input, self.theme.record_info_text_color, true);
+
+        if let Some(error) = dialog.error.as_ref() {
+            let error_text = Paragraph::new(error.as_str()).style(self.theme.paused_text_color);
+            frame.render_widget(error_text, error_area);
+        }
+    }
+    /// Maps a [`KeyEvent`] while the filter dialog is open. Every key event is consumed by the
+    /// dialog.
+    fn map_filter_dialog_key_event(&mut self, event: KeyEvent) -> MappedKeyEvent {
+        let dialog = self
+            .state
+            .filter_dialog
+            .as_mut()
+            .expect("filter dialog open");
+
+        match event.code {
+            KeyCode::Esc => {
+                self.state.filter_dialog = None;
+                MappedKeyEvent::Consumed
+            }
+            KeyCode::Tab => {
+                dialog.reapply = !dialog.reapply;
+                MappedKeyEvent::Consumed
+            }
+            KeyCode::Enter => match dialog.parse() {
+                Ok(filter) => {
+                    // the buffered records are filtered in the background to keep the UI responsive
+                    let records = if dialog.reapply && filter.is_some() {
+                        self.state.records.iter().cloned().collect()
+                    } else {
+                        Vec::new()
+                    };
+
+                    self.state.filter_dialog = None;
+                    MappedKeyEvent::Dispatch(Event::ApplyFilter(filter, records))
+                }
+                Err(e) => {
+                    dialog.error = Some(format!("{:#}", e));
+                    MappedKeyEvent::Consumed
+                }
+            },
+            _ => {
+                dialog.filter.on_key_event(event);
+                MappedKeyEvent::Consumed
+            }
+        }
+    }
+    /// Opens the replay dialog for the marked records, or the selected record if none are marked.
+    fn open_replay_dialog(&mut self) -> MappedKeyEvent {
+        let targets = self.state.replay_targets();
+
+        let Some(first) = targets.first() else {
+            return MappedKeyEvent::Unhandled;
+        };
+
+        self.state.replay_dialog = Some(ReplayDialog::new(&first.topic, targets.len()));
+
+        MappedKeyEvent::Consumed
+    }
+    /// Renders the replay dialog as a popup centered in the given area.
+    fn render_replay_dialog(&self, frame: &mut Frame, area: Rect) {
+        let Some(dialog) = self.state.replay_dialog.as_ref() else {
+            return;
+        };
+
+        let rect = area.centered(Constraint::Percentage(60), Constraint::Length(10));
+
+        let popup_block = Block::bordered()
+            .title(" Replay ")
+            .border_style(self.theme.selected_panel_border_color)
+            .border_type(BorderType::Thick)
+            .padding(Padding::new(1, 1, 0, 0));
+
+        let inner_area = popup_block.inner(rect);
+
+        let [
+            topic_label,
+            topic_input,
+            headers_label,
+            headers_input,
+            _,
+            summary_area,
+            _,
+            error_area,
+        ] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1); 8])
+            .areas(inner_area);
+
+        let topic_text = Paragraph::new("Destination Topic")
+            .style(self.theme.label_color)
+            .bold();
+
+        let headers_text = Paragraph::new("Header Rewrites (key=value or -key, comma separated)")
+            .style(self.theme.label_color)
+            .bold();
+
+        let records = match dialog.count {
+            1 => String::from("1 record"),
+            n => format!("{} records", n),
+        };
+
+        let summary_text = Paragraph::new(format!(
+            "Replay {} to topic {}?",
+            records,
+            dialog.topic.value().trim()
+        ))
+        .style(self.theme.record_info_text_color);
+
+        frame.render_widget(Clear, rect);
+        frame.render_widget(popup_block, rect);
+        frame.render_widget(topic_text, topic_label);
+        frame.render_widget(headers_text, headers_label);
+        frame.render_widget(summary_text, summary_area);
+
+        dialog.topic.render(
+            frame,
+            topic_input,
+            self.theme.record_info_text_color,
+            dialog.active_field == ReplayDialogField::Topic,
+        );
+
+        dialog.headers.render(
+            frame,
+            headers_input,
+            self.theme.record_info_text_color,
+            dialog.active_field == ReplayDialogField::Headers,
+        );
+
+        if let Some(error) = dialog.error.as_ref() {
+            let error_text = Paragraph::new(error.as_str()).style(self.theme.paused_text_color);
+            frame.render_widget(error_text, error_area);
+        }
+    }
+    /// Maps a [`KeyEvent`] while the replay dialog is open. Every key event is consumed by the
+    /// dialog.
+    fn map_replay_dialog_key_event(&mut self, event: KeyEvent) -> MappedKeyEvent {
+        let dialog = self
+            .state
+            .replay_dialog
+            .as_mut()
+            .expect("replay dialog open");
+
+        match event.code {
+            KeyCode::Esc => {
+                self.state.replay_dialog = None;
+                MappedKeyEvent::Consumed
+            }
+            KeyCode::Tab => {
+                dialog.select_next_field();
+                MappedKeyEvent::Consumed
+            }
+            KeyCode::Enter => {
+                let records = dialog.parse().map(|(topic, rewrites)| {
+                    self.state
+                        .replay_targets()
+                        .into_iter()
+                        .map(|r| replay_record(r, &topic, &rewrites))
+                        .collect::<Vec<ReplayRecord>>()
+                });
+
+                match records {
+                    Ok(records) => {
+                        self.state.replay_dialog = None;
+                        self.state.marked.clear();
+                        MappedKeyEvent::Dispatch(Event::ReplayRecords(records))
+                    }
+                    Err(e) => {
+                        let dialog = self
+                            .state
+                            .replay_dialog
+                            .as_mut()
+                            .expect("replay dialog open");
+
+                        dialog.error = Some(e.to_string());
+                        MappedKeyEvent::Consumed
+                    }
+                }
+            }
+            _ => {
+                dialog.active_input_mut().on_key_event(event);
+                MappedKeyEvent::Consumed
+            }
+        }
+    }
     /// Renders the panel containing the details of a [`Record`] when there is currently none
     /// selected.
     fn render_record_empty(&self, frame: &mut Frame, area: Rect) {
@@ -620,6 +1657,10 @@ impl Component for Records {
             } else {
                 self.render_record_empty(frame, record_details_panel);
             }
+
+            self.render_seek_dialog(frame, area);
+            self.render_filter_dialog(frame, area);
+            self.render_replay_dialog(frame, area);
         }
     }
     /// Allows the [`Component`] to map a [`KeyEvent`] to an [`Event`] which will be published
@@ -629,12 +1670,41 @@ impl Component for Records {
         event: KeyEvent,
         buffered: Option<&BufferedKeyPress>,
     ) -> MappedKeyEvent {
+        if self.state.seek_dialog.is_some() {
+            return self.map_seek_dialog_key_event(event);
+        }
+
+        if self.state.filter_dialog.is_some() {
+            return self.map_filter_dialog_key_event(event);
+        }
+
+        if self.state.replay_dialog.is_some() {
+            return self.map_replay_dialog_key_event(event);
+        }
+
         match event.code {
             KeyCode::Char(c) => match c {
+                's' if matches!(
+                    self.state.consumer_mode.get(),
+                    ConsumerMode::Processing | ConsumerMode::Paused | ConsumerMode::Complete
+                ) =>
+                {
+                    self.state.seek_dialog = Some(SeekDialog::new(self.single_topic()));
+                    MappedKeyEvent::Consumed
+                }
+                '/' if self.state.consumer_mode.get() != ConsumerMode::Stopped => {
+                    self.state.filter_dialog = Some(FilterDialog::new(self.filter.as_ref()));
+                    MappedKeyEvent::Consumed
+                }
                 'e' => match self.state.selected.as_ref() {
-                    Some(r) => MappedKeyEvent::Dispatch(Event::ExportRecord(r.clone())),
+                    Some(r) => MappedKeyEvent::Dispatch(Event::ExportRecord(Box::new(r.clone()))),
                     None => MappedKeyEvent::Unhandled,
                 },
+                'R' => self.open_replay_dialog(),
+                'M' if !self.state.marked.is_empty() => {
+                    self.state.marked.clear();
+                    MappedKeyEvent::Consumed
+                }
                 'p' => MappedKeyEvent::Dispatch(Event::PauseProcessing),
                 'r' => MappedKeyEvent::Dispatch(Event::ResumeProcessing),
                 _ => match self.state.active_widget {
@@ -655,6 +1725,10 @@ impl Component for Records {
                             self.state.select_last();
                             MappedKeyEvent::Consumed
                         }
+                        'm' if self.state.is_record_selected() => {
+                            self.state.toggle_selected_mark();
+                            MappedKeyEvent::Consumed
+                        }
                         _ => MappedKeyEvent::Unhandled,
                     },
                     RecordsWidget::Value => match c {
@@ -670,6 +1744,10 @@ impl Component for Records {
                             self.state.scroll_value_up(self.scroll_factor);
                             MappedKeyEvent::Consumed
                         }
+                        'v' => {
+                            self.state.select_next_value_view();
+                            MappedKeyEvent::Consumed
+                        }
                         _ => MappedKeyEvent::Unhandled,
                     },
                     RecordsWidget::Headers => match c {
@@ -702,23 +1780,44 @@ impl Component for Records {
         match event {
             Event::ConsumerStarted => {
                 tracing::info!("Kafka consumer started");
-                self.state.consumer_mode.set(ConsumerMode::Processing);
+
+                // the consumer may have already reached its end bound if all partitions were
+                // bounded at an offset that had already been reached
+                if self.state.consumer_mode.get() == ConsumerMode::Stopped {
+                    self.state.consumer_mode.set(ConsumerMode::Processing);
+                }
             }
             Event::ConsumerStartFailure(e) => {
                 tracing::error!("Kafka consumer failed to start: {}", e);
                 self.state.startup_failed = true;
             }
             Event::SelectNextWidget => self.state.select_next_widget(),
-            Event::RecordReceived(record) => self.state.push_record(record.clone()),
+            Event::RecordReceived(record) => self.state.push_record(record.as_ref().clone()),
+            Event::ConsumerRepositioned(partition, _) => {
+                self.state.clear_records(partition.as_ref())
+            }
+            Event::ConsumerRebalanced(rebalance) => {
+                self.state.group_rebalance = Some(rebalance.clone())
+            }
+            Event::FilterApplied(filter) => self.filter = filter.clone(),
+            Event::FilterReapplied(unmatched) => self.state.remove_unmatched(unmatched),
             _ => {}
         }
     }
+    /// Determines if the [`Component`] is currently capturing all key input, which is the case
+    /// while the seek, filter or replay dialog is open.
+    fn is_capturing_input(&self) -> bool {
+        self.state.seek_dialog.is_some()
+            || self.state.filter_dialog.is_some()
+            || self.state.replay_dialog.is_some()
+    }
     /// Allows the [`Component`] to render the status line text into the footer.
     fn render_status_line(&self, frame: &mut Frame, area: Rect) {
         let consumer_status_line = ConsumerStatusLine::builder()
             .consumer_mode(self.state.consumer_mode.get())
             .topic(self.topic.as_str())
-            .filter(self.filter.as_ref())
+            .filter(self.filter.as_ref().map(RecordFilter::expression))
+            .group_rebalance(self.state.group_rebalance.as_ref())
             .processing_style(self.theme.processing_text_color)
             .paused_style(self.theme.paused_text_color)
             .build()
@@ -737,10 +1836,37 @@ impl Component for Records {
             return;
         }
 
+        if self.state.seek_dialog.is_some() {
+            let text = Paragraph::new(SEEK_DIALOG_KEY_BINDINGS.join(" | "))
+                .style(self.theme.key_bindings_text_color)
+                .right_aligned();
+
+            frame.render_widget(text, area);
+            return;
+        }
+
+        if self.state.filter_dialog.is_some() {
+            let text = Paragraph::new(FILTER_DIALOG_KEY_BINDINGS.join(" | "))
+                .style(self.theme.key_bindings_text_color)
+                .right_aligned();
+
+            frame.render_widget(text, area);
+            return;
+        }
+
+        if self.state.replay_dialog.is_some() {
+            let text = Paragraph::new(REPLAY_DIALOG_KEY_BINDINGS.join(" | "))
+                .style(self.theme.key_bindings_text_color)
+                .right_aligned();
+
+            frame.render_widget(text, area);
+            return;
+        }
+
         let consumer_mode_key_binding = match self.state.consumer_mode.get() {
-            ConsumerMode::Processing => super::KEY_BINDING_PAUSE,
-            ConsumerMode::Paused => super::KEY_BINDING_RESUME,
-            _ => "",
+            ConsumerMode::Processing => Some(super::KEY_BINDING_PAUSE),
+            ConsumerMode::Paused => Some(super::KEY_BINDING_RESUME),
+            _ => None,
         };
 
         let mut key_bindings = Vec::from(RECORDS_STANDARD_KEY_BINDINGS);
@@ -751,11 +1877,16 @@ impl Component for Records {
                 key_bindings.push(super::KEY_BINDING_NEXT);
                 key_bindings.push(super::KEY_BINDING_PREV);
                 key_bindings.push(super::KEY_BINDING_BOTTOM);
+
+                if self.state.is_record_selected() {
+                    key_bindings.push(KEY_BINDING_MARK);
+                }
             }
             RecordsWidget::Value => {
                 key_bindings.push(super::KEY_BINDING_TOP);
                 key_bindings.push(super::KEY_BINDING_SCROLL_DOWN);
                 key_bindings.push(super::KEY_BINDING_SCROLL_UP);
+                key_bindings.push(super::KEY_BINDING_VALUE_VIEW);
             }
             RecordsWidget::Headers => {
                 key_bindings.push(super::KEY_BINDING_TOP);
@@ -765,12 +1896,25 @@ impl Component for Records {
             }
         };
 
-        key_bindings.push(consumer_mode_key_binding);
+        if let Some(consumer_mode_key_binding) = consumer_mode_key_binding {
+            key_bindings.push(super::KEY_BINDING_SEEK);
+            key_bindings.push(consumer_mode_key_binding);
+        }
+
+        key_bindings.push(KEY_BINDING_FILTER);
 
         if self.state.is_record_selected() {
             key_bindings.push(super::KEY_BINDING_EXPORT);
         }
 
+        if !self.state.marked.is_empty() {
+            key_bindings.push(KEY_BINDING_CLEAR_MARKS);
+        }
+
+        if self.state.is_record_selected() || !self.state.marked.is_empty() {
+            key_bindings.push(KEY_BINDING_REPLAY);
+        }
+
         let text = Paragraph::new(key_bindings.join(" | "))
             .style(self.theme.key_bindings_text_color)
             .right_aligned();
@@ -1,6 +1,6 @@
 use crate::{
     app::config::{Config, PersistedConfig, Profile, Theme},
-    kafka::SeekTo,
+    kafka::{ConsumeUntil, SeekTo},
     ui::{BufferedKeyPress, Component, Event, MappedKeyEvent},
 };
 
@@ -109,6 +109,8 @@ struct SettingsTheme {
     record_value_text_color: Color,
     /// Color used for the text in the record headers. Defaults to white.
     record_headers_text_color: Color,
+    /// Color used for the text of records that failed to deserialize. Defaults to red.
+    record_decode_error_text_color: Color,
     /// Color used for the text in the menu items. Defaults to white.
     menu_item_text_color: Color,
     /// Color used for the text in the currently selected menu item. Defaults to yellow.
@@ -161,6 +163,9 @@ impl From<&Theme> for SettingsTheme {
         let record_headers_text_color =
             Color::from_str(value.record_headers_text_color.as_str()).expect("valid RGB hex");
 
+        let record_decode_error_text_color =
+            Color::from_str(value.record_decode_error_text_color.as_str()).expect("valid RGB hex");
+
         let menu_item_text_color =
             Color::from_str(value.menu_item_text_color.as_str()).expect("valid RGB hex");
 
@@ -199,6 +204,7 @@ impl From<&Theme> for SettingsTheme {
             record_info_text_color,
             record_value_text_color,
             record_headers_text_color,
+            record_decode_error_text_color,
             menu_item_text_color,
             selected_menu_item_text_color,
             notification_text_color_success,
@@ -391,6 +397,19 @@ impl Settings {
                 .map(ToString::to_string)
                 .collect::<Vec<String>>()
                 .join(", "),
+            SeekTo::Timestamp(timestamp) => timestamp.to_string(),
+            SeekTo::Tail(count) => format!("LAST {} RECORDS", count),
+        };
+
+        let until = match &config.until {
+            ConsumeUntil::None => String::from("<none>"),
+            ConsumeUntil::End => String::from("END"),
+            ConsumeUntil::Custom(pos) => pos
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>()
+                .join(", "),
+            ConsumeUntil::Timestamp(timestamp) => timestamp.to_string(),
         };
 
         let list_items = vec![
@@ -420,6 +439,11 @@ impl Settings {
                 Line::from(config.group_id.clone()),
             ])),
             ListItem::new(""),
+            ListItem::new(Text::from_iter([
+                Line::from(Span::styled("Assignment Mode", self.theme.label_color)),
+                Line::from(config.assignment_mode.to_string().to_uppercase()),
+            ])),
+            ListItem::new(""),
             ListItem::new(Text::from_iter([
                 Line::from(Span::styled("Filter", self.theme.label_color)),
                 Line::from(
@@ -435,6 +459,16 @@ impl Settings {
                 Line::from(Span::styled("Seek To", self.theme.label_color)),
                 Line::from(seek_to),
             ])),
+            ListItem::new(""),
+            ListItem::new(Text::from_iter([
+                Line::from(Span::styled("Commit Strategy", self.theme.label_color)),
+                Line::from(config.commit_strategy.to_string().to_uppercase()),
+            ])),
+            ListItem::new(""),
+            ListItem::new(Text::from_iter([
+                Line::from(Span::styled("Until", self.theme.label_color)),
+                Line::from(until),
+            ])),
         ];
 
         let list = List::new(list_items).block(block);
@@ -684,6 +718,10 @@ impl Settings {
                 "Record Value",
                 self.theme.record_value_text_color,
             ))),
+            ListItem::new(Text::from(Span::styled(
+                "Record Decode Error",
+                self.theme.record_decode_error_text_color,
+            ))),
             ListItem::new(""),
             ListItem::new(Text::from(Span::styled(
                 "Notification Success",
@@ -855,6 +893,17 @@ impl Settings {
                 ),
             ])),
             ListItem::new(""),
+            ListItem::new(Text::from_iter([
+                Line::from(Span::styled("Assignment Mode", self.theme.label_color)),
+                Line::from(
+                    profile
+                        .assignment_mode
+                        .clone()
+                        .map(|s| s.to_uppercase())
+                        .unwrap_or_else(|| String::from("<none>")),
+                ),
+            ])),
+            ListItem::new(""),
             ListItem::new(Text::from_iter([
                 Line::from(Span::styled("Filter", self.theme.label_color)),
                 Line::from(
@@ -864,6 +913,38 @@ impl Settings {
                         .unwrap_or_else(|| String::from("<none>")),
                 ),
             ])),
+            ListItem::new(""),
+            ListItem::new(Text::from_iter([
+                Line::from(Span::styled("Seek To", self.theme.label_color)),
+                Line::from(
+                    profile
+                        .seek_to
+                        .clone()
+                        .unwrap_or_else(|| String::from("<none>")),
+                ),
+            ])),
+            ListItem::new(""),
+            ListItem::new(Text::from_iter([
+                Line::from(Span::styled("Commit Strategy", self.theme.label_color)),
+                Line::from(
+                    profile
+                        .commit_strategy
+                        .clone()
+                        .map(|s| s.to_uppercase())
+                        .unwrap_or_else(|| String::from("<none>")),
+                ),
+            ])),
+            ListItem::new(""),
+            ListItem::new(Text::from_iter([
+                Line::from(Span::styled("Until", self.theme.label_color)),
+                Line::from(
+                    profile
+                        .until
+                        .clone()
+                        .map(|s| s.to_uppercase())
+                        .unwrap_or_else(|| String::from("<none>")),
+                ),
+            ])),
         ];
 
         let list = List::new(list_items).block(block);
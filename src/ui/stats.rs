@@ -1,7 +1,7 @@
 use crate::{
     app::{BufferedKeyPress, config::Theme},
     event::Event,
-    kafka::{ConsumerMode, Record},
+    kafka::{ConsumerMode, GroupRebalance, Record, TopicPartition, filter::RecordFilter},
     ui::{Component, MappedKeyEvent, widget::ConsumerStatusLine},
 };
 
@@ -54,29 +54,67 @@ trait ToRow<'a> {
 impl<'a> ToRow<'a> for &Partition {
     /// Converts from a reference to a [`Partition`] to a [`Row`].
     fn to_row(&'a self) -> Row<'a> {
-        Row::new([
-            self.partition.to_span(),
-            self.broker.to_span(),
-            self.leader.to_span(),
-            self.desired.to_span(),
-            self.unknown.to_span(),
-            self.fetchq_cnt.to_span(),
-            self.fetchq_size.to_span(),
-            self.fetch_state.to_span(),
-            self.next_offset.to_span(),
-            self.app_offset.to_span(),
-            self.stored_offset.to_span(),
-            self.committed_offset.to_span(),
-            self.eof_offset.to_span(),
-            self.lo_offset.to_span(),
-            self.hi_offset.to_span(),
-            self.ls_offset.to_span(),
-            self.consumer_lag.to_span(),
-            self.rxmsgs.to_span(),
-            self.rxbytes.to_span(),
-            self.rx_ver_drops.to_span(),
-            self.msgs_inflight.to_span(),
-        ])
+        Row::new(partition_spans(self))
+    }
+}
+
+impl<'a> ToRow<'a> for (&String, &Partition) {
+    /// Converts from a tuple of a topic name and a reference to a [`Partition`] of that topic to
+    /// a [`Row`] that is prefixed with the topic name.
+    fn to_row(&'a self) -> Row<'a> {
+        let (topic, partition) = self;
+
+        let mut spans = vec![topic.to_span()];
+        spans.extend(partition_spans(partition));
+
+        Row::new(spans)
+    }
+}
+
+/// Creates the [`Span`]s for each of the columns in the per-[`Partition`] statistics table.
+fn partition_spans(p: &Partition) -> Vec<Span<'_>> {
+    vec![
+        p.partition.to_span(),
+        p.broker.to_span(),
+        p.leader.to_span(),
+        p.desired.to_span(),
+        p.unknown.to_span(),
+        p.fetchq_cnt.to_span(),
+        p.fetchq_size.to_span(),
+        p.fetch_state.to_span(),
+        p.next_offset.to_span(),
+        p.app_offset.to_span(),
+        p.stored_offset.to_span(),
+        p.committed_offset.to_span(),
+        p.eof_offset.to_span(),
+        p.lo_offset.to_span(),
+        p.hi_offset.to_span(),
+        p.ls_offset.to_span(),
+        p.consumer_lag.to_span(),
+        p.rxmsgs.to_span(),
+        p.rxbytes.to_span(),
+        p.rx_ver_drops.to_span(),
+        p.msgs_inflight.to_span(),
+    ]
+}
+
+/// Counts of the Kafka records consumed from a single partition of a topic. Kept so that the
+/// overall counters can be adjusted when the consumer is repositioned on the partition.
+#[derive(Copy, Clone, Debug, Default)]
+struct PartitionCounts {
+    /// Count of the records consumed from the partition that were not filtered.
+    received: u64,
+    /// Count of the records consumed from the partition that were filtered out.
+    filtered: u64,
+    /// Count of the records consumed from the partition whose key or value could not be
+    /// deserialized.
+    decode_errors: u64,
+}
+
+impl PartitionCounts {
+    /// Computes the total number of records consumed from the partition.
+    fn total(&self) -> u64 {
+        self.received + self.filtered
     }
 }
 
@@ -98,16 +136,22 @@ struct StatsState {
     /// Count of the Kafka records that were consumed from the topic, but filtered out and not
     /// presented to the user.
     filtered: u64,
-    /// A [`BTreeMap`] containing the total number of [`Records`]s consumed from the Kafka topic
-    /// split up by partition number. This type of map is used to keep the partitions ordered for
-    /// display in the chart.
-    partition_totals: BTreeMap<i32, u64>,
+    /// Count of the Kafka records that were consumed from the topic, but whose key or value
+    /// could not be deserialized. These records are also counted as received or filtered.
+    decode_errors: u64,
+    /// A [`BTreeMap`] containing the counts of [`Records`]s consumed from the Kafka topics split
+    /// up by topic and partition number. This type of map is used to keep the partitions ordered
+    /// for display in the chart.
+    partition_counts: BTreeMap<TopicPartition, PartitionCounts>,
     /// Contains the timestamps corresponding to when [`Record`]s were consumed from the Kafka
     /// topic. These timestamps are used to display the throughput chart.
     timestamps: BoundedVecDeque<i64>,
     /// [`Statistics`] emitted periodically from the librdkafka library which are displayed to the
     /// user.
     statistics: Option<Statistics>,
+    /// Latest rebalance of the consumer group when the consumer is subscribed to the topic. Used
+    /// to restrict the partition statistics to the partitions assigned to the consumer.
+    group_rebalance: Option<GroupRebalance>,
     /// Stores the widget that the currently has focus.
     active_widget: StatsWidget,
     /// State for the partition stats table selection.
@@ -123,9 +167,11 @@ impl StatsState {
             consumer_mode,
             received: u64::default(),
             filtered: u64::default(),
-            partition_totals: BTreeMap::default(),
+            decode_errors: u64::default(),
+            partition_counts: BTreeMap::default(),
             timestamps: BoundedVecDeque::new(MAX_THROUGHPUT_CAPTURE),
             statistics: None,
+            group_rebalance: None,
             active_widget: StatsWidget::default(),
             partition_table_state: TableState::default(),
             partition_scroll_state: ScrollbarState::default(),
@@ -141,36 +187,105 @@ impl StatsState {
     fn on_record_received(&mut self, record: &Record) {
         self.received += 1;
         self.push_timestamp();
-        self.inc_total_for_partition(record.partition);
+        self.partition_counts_mut(record).received += 1;
+        self.inc_decode_errors(record);
     }
     /// Invoked when a [`Record`] received from the Kafka consumer is filtered.
     fn on_record_filtered(&mut self, record: &Record) {
         self.filtered += 1;
         self.push_timestamp();
-        self.inc_total_for_partition(record.partition);
+        self.partition_counts_mut(record).filtered += 1;
+        self.inc_decode_errors(record);
+    }
+    /// Invoked when the filter was applied to the records that had already been consumed. The
+    /// [`Record`]s that do not match the filter are counted as filtered instead of received.
+    fn on_filter_reapplied(&mut self, unmatched: &[Record]) {
+        for record in unmatched.iter() {
+            let counts = self.partition_counts_mut(record);
+            counts.received = counts.received.saturating_sub(1);
+            counts.filtered += 1;
+
+            self.received = self.received.saturating_sub(1);
+            self.filtered += 1;
+        }
+    }
+    /// Increments the count of records that could not be deserialized if the key or value of the
+    /// [`Record`] failed to deserialize.
+    fn inc_decode_errors(&mut self, record: &Record) {
+        if record.has_decode_error() {
+            self.decode_errors += 1;
+            self.partition_counts_mut(record).decode_errors += 1;
+        }
+    }
+    /// Invoked when the Kafka consumer has been repositioned on the specified topic partition, or
+    /// on all partitions if no partition is specified. Seeking all partitions starts the counters
+    /// over while seeking a single partition only discards the counts for that partition.
+    fn on_consumer_repositioned(&mut self, partition: Option<&TopicPartition>) {
+        match partition {
+            Some(tp) => {
+                if let Some(counts) = self.partition_counts.remove(tp) {
+                    self.received = self.received.saturating_sub(counts.received);
+                    self.filtered = self.filtered.saturating_sub(counts.filtered);
+                    self.decode_errors = self.decode_errors.saturating_sub(counts.decode_errors);
+                }
+            }
+            None => {
+                self.received = 0;
+                self.filtered = 0;
+                self.decode_errors = 0;
+                self.partition_counts.clear();
+                self.timestamps.clear();
+            }
+        }
+    }
+    /// Determines if the partition of the topic is assigned to the consumer. When the consumer is
+    /// subscribed to the topic only the partitions assigned by the latest rebalance of the
+    /// consumer group are considered assigned, otherwise all partitions are.
+    fn is_assigned(&self, topic: &str, partition: i32) -> bool {
+        self.group_rebalance.as_ref().is_none_or(|r| {
+            r.assignment
+                .iter()
+                .any(|tp| tp.topic == topic && tp.partition == partition)
+        })
     }
     /// Invoked when updated [`Statistics`] are received from the librdkafka library.
     fn on_statistics_received(&mut self, statistics: &Statistics) {
         self.statistics = Some(statistics.clone());
     }
-    /// Increments the total number of [`Record`]s consumed on a partition.
-    fn inc_total_for_partition(&mut self, partition: i32) {
-        self.partition_totals
-            .entry(partition)
-            .and_modify(|t| *t += 1)
-            .or_insert(1);
+    /// Returns a mutable reference to the [`PartitionCounts`] for the partition of the
+    /// [`Record`], inserting empty counts if none exist yet.
+    fn partition_counts_mut(&mut self, record: &Record) -> &mut PartitionCounts {
+        self.partition_counts
+            .entry(TopicPartition::new(record.topic.clone(), record.partition))
+            .or_default()
+    }
+    /// Computes the total number of [`Record`]s consumed per topic.
+    fn topic_totals(&self) -> BTreeMap<&str, u64> {
+        let mut topic_totals = BTreeMap::new();
+
+        for (tp, counts) in self.partition_counts.iter() {
+            *topic_totals.entry(tp.topic.as_str()).or_default() += counts.total();
+        }
+
+        topic_totals
     }
     /// Pushes a the current timestamp onto the timestamps [`BoundedVecDeque`] which indicates that
     /// a [`Record`] was consumed from the Kafka topic.
     fn push_timestamp(&mut self) {
         self.timestamps.push_front(Local::now().timestamp_millis());
     }
-    /// Returns the number of partitions in the current statistics (only those with id >= 0).
+    /// Returns the number of partitions across all topics in the current statistics (only those
+    /// with id >= 0).
     fn partition_count(&self) -> usize {
         self.statistics
             .as_ref()
-            .and_then(|s| s.topics.values().next())
-            .map(|t| t.partitions.values().filter(|p| p.partition >= 0).count())
+            .map(|s| {
+                s.topics
+                    .values()
+                    .flat_map(|t| t.partitions.values())
+                    .filter(|p| p.partition >= 0)
+                    .count()
+            })
             .unwrap_or(0)
     }
     /// Scrolls the partition stats table down by one row.
@@ -315,7 +430,7 @@ pub struct StatsConfig<'a> {
     /// Topic name that records are being consumed from.
     topic: String,
     /// Any filter that was configured by the user.
-    filter: Option<String>,
+    filter: Option<RecordFilter>,
     /// Reference to the application [`Theme`].
     theme: &'a Theme,
 }
@@ -334,8 +449,8 @@ impl<'a> StatsConfig<'a> {
 pub struct Stats<'a> {
     /// Topic name that records are being consumed from.
     topic: String,
-    /// Any filter that was configured by the user.
-    filter: Option<String>,
+    /// Any filter that was configured or applied by the user.
+    filter: Option<RecordFilter>,
     /// Current state of the component, and it's underlying widgets.
     state: StatsState,
     /// Color scheme for the component.
@@ -378,14 +493,20 @@ impl<'a> Stats<'a> {
             partition_constraints: constraints,
         }
     }
-    /// Renders the count of records received, filtered and the total.
-    fn render_triptych(&self, frame: &mut Frame, area: Rect) {
-        let [received_panel, filtered_panel, total_panel] = Layout::default()
+    /// Renders the count of records received, filtered, that failed to deserialize and the total.
+    fn render_counts(&self, frame: &mut Frame, area: Rect) {
+        let [
+            received_panel,
+            filtered_panel,
+            decode_errors_panel,
+            total_panel,
+        ] = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([
                 Constraint::Fill(1),
                 Constraint::Fill(1),
                 Constraint::Fill(1),
+                Constraint::Fill(1),
             ])
             .areas(area);
 
@@ -400,8 +521,14 @@ impl<'a> Stats<'a> {
             .bold()
             .centered();
 
+        // the language of the filter is shown so the user knows how the filtered count was produced
+        let filtered_title = match self.filter.as_ref() {
+            Some(filter) => format!(" Filtered ({}) ", filter.language()),
+            None => String::from(" Filtered "),
+        };
+
         let filtered_block = Block::bordered()
-            .title(" Filtered ")
+            .title(filtered_title)
             .border_style(self.theme.panel_border_color)
             .padding(Padding::new(1, 1, 0, 0));
 
@@ -411,6 +538,17 @@ impl<'a> Stats<'a> {
             .bold()
             .centered();
 
+        let decode_errors_block = Block::bordered()
+            .title(" Decode Errors ")
+            .border_style(self.theme.panel_border_color)
+            .padding(Padding::new(1, 1, 0, 0));
+
+        let decode_errors_paragraph = Paragraph::new(self.state.decode_errors.to_string())
+            .block(decode_errors_block)
+            .style(self.theme.text_color)
+            .bold()
+            .centered();
+
         let total_block = Block::bordered()
             .title(" Total ")
             .border_style(self.theme.panel_border_color)
@@ -424,6 +562,7 @@ impl<'a> Stats<'a> {
 
         frame.render_widget(received_paragraph, received_panel);
         frame.render_widget(filtered_paragraph, filtered_panel);
+        frame.render_widget(decode_errors_paragraph, decode_errors_panel);
         frame.render_widget(total_paragraph, total_panel);
     }
     /// Renders the various charts for the stats UI.
@@ -551,14 +690,22 @@ impl<'a> Stats<'a> {
     /// Renders the panel that displays the statistics relevant to the topic partitions that are
     /// emitted by the librdkafka library.
     fn render_partition_stats(&mut self, stats: &Statistics, frame: &mut Frame, area: Rect) {
+        let title = match self.state.group_rebalance.as_ref() {
+            Some(GroupRebalance {
+                generation: Some(generation),
+                ..
+            }) => format!(" Partitions (Generation {}) ", generation),
+            _ => String::from(" Partitions "),
+        };
+
         let mut partition_stats_block = Block::bordered()
-            .title(" Partitions ")
+            .title(title)
             .border_style(self.theme.panel_border_color)
             .padding(Padding::new(1, 1, 0, 0));
 
-        let Some(topic) = stats.topics.values().next() else {
+        if stats.topics.is_empty() {
             return;
-        };
+        }
 
         if self.state.active_widget == StatsWidget::Partitions {
             partition_stats_block = partition_stats_block
@@ -566,19 +713,46 @@ impl<'a> Stats<'a> {
                 .border_style(self.theme.selected_panel_border_color);
         }
 
-        let ordered = BTreeMap::from_iter(topic.partitions.iter());
+        // the topic column is only needed to tell partitions apart when consuming many topics
+        let multi_topic = stats.topics.len() > 1;
+
+        let ordered_topics = BTreeMap::from_iter(stats.topics.iter());
+
+        let partitions: Vec<(&String, &Partition)> = ordered_topics
+            .into_iter()
+            .flat_map(|(name, topic)| {
+                BTreeMap::from_iter(topic.partitions.iter())
+                    .into_values()
+                    .filter(|p| p.partition >= 0)
+                    .filter(|p| self.state.is_assigned(name, p.partition))
+                    .map(move |p| (name, p))
+            })
+            .collect();
 
-        let partition_stats_rows: Vec<Row> = ordered
-            .values()
-            .filter(|p| p.partition >= 0)
-            .map(ToRow::to_row)
+        let partition_stats_rows: Vec<Row> = partitions
+            .iter()
+            .map(|entry| {
+                if multi_topic {
+                    entry.to_row()
+                } else {
+                    entry.1.to_row()
+                }
+            })
             .collect();
 
         let partition_count = partition_stats_rows.len();
 
-        let header = Row::new(self.partition_labels.clone());
+        let mut labels = self.partition_labels.clone();
+        let mut constraints = self.partition_constraints.clone();
 
-        let partition_stats_table = Table::new(partition_stats_rows, &self.partition_constraints)
+        if multi_topic {
+            labels.insert(0, "Topic".bold().style(self.theme.label_color));
+            constraints.insert(0, Constraint::Min(1));
+        }
+
+        let header = Row::new(labels);
+
+        let partition_stats_table = Table::new(partition_stats_rows, constraints)
             .column_spacing(1)
             .header(header)
             .style(self.theme.bar_color)
@@ -706,20 +880,38 @@ impl<'a> Stats<'a> {
     /// Renders the bar chart that displays the total records consumed from the Kafka topic per
     /// partition.
     fn render_total_by_partition(&self, frame: &mut Frame, area: Rect) {
+        let topic_totals = self.state.topic_totals();
+
+        // when consuming many topics the totals are broken down by topic instead of partition
+        let (title, totals): (&str, Vec<(String, u64)>) = if topic_totals.len() > 1 {
+            let totals = topic_totals
+                .into_iter()
+                .map(|(topic, total)| (topic.to_string(), total))
+                .collect();
+
+            (" Total Per Topic ", totals)
+        } else {
+            let totals = self
+                .state
+                .partition_counts
+                .iter()
+                .map(|(tp, counts)| (format!("P{}", tp.partition), counts.total()))
+                .collect();
+
+            (" Total Per Partition ", totals)
+        };
+
         let charts_block = Block::bordered()
-            .title(" Total Per Partition ")
+            .title(title)
             .border_style(self.theme.panel_border_color)
             .padding(Padding::new(1, 1, 0, 0));
 
-        let bar_width =
-            calculate_bar_width(&area, self.state.partition_totals.len() as u16, BAR_GAP);
+        let bar_width = calculate_bar_width(&area, totals.len() as u16, BAR_GAP);
 
-        let per_partition_bars: Vec<Bar> = self
-            .state
-            .partition_totals
-            .iter()
+        let per_partition_bars: Vec<Bar> = totals
+            .into_iter()
             .enumerate()
-            .map(|(i, (partition, total))| {
+            .map(|(i, (label, total))| {
                 let style: Style = if i % 2 == 0 {
                     self.theme.bar_color.into()
                 } else {
@@ -727,16 +919,16 @@ impl<'a> Stats<'a> {
                 };
 
                 let text_value = if bar_width > MIN_BAR_WIDTH_FOR_PERCENTAGE {
-                    let percentage = (*total as f32 / self.state.total() as f32) * 100.0;
+                    let percentage = (total as f32 / self.state.total() as f32) * 100.0;
                     format!("{} ({:.1}%)", total, percentage)
                 } else {
                     format!("{}", total)
                 };
 
                 Bar::default()
-                    .value(*total)
+                    .value(total)
                     .text_value(text_value)
-                    .label(Line::from(format!("P{}", partition)).style(self.theme.label_color))
+                    .label(Line::from(label).style(self.theme.label_color))
                     .style(style)
                     .value_style(style.reversed())
             })
@@ -797,12 +989,12 @@ impl<'a> Component for Stats<'a> {
     }
     /// Renders the component-specific widgets to the terminal.
     fn render(&mut self, frame: &mut Frame, area: Rect) {
-        let [triptych_panel, charts_panel] = Layout::default()
+        let [counts_panel, charts_panel] = Layout::default()
             .direction(Direction::Vertical)
             .constraints([Constraint::Max(3), Constraint::Min(1)])
             .areas(area);
 
-        self.render_triptych(frame, triptych_panel);
+        self.render_counts(frame, counts_panel);
 
         self.render_charts(frame, charts_panel);
     }
@@ -845,6 +1037,14 @@ impl<'a> Component for Stats<'a> {
             Event::RecordReceived(record) => self.state.on_record_received(record),
             Event::RecordFiltered(record) => self.state.on_record_filtered(record),
             Event::StatisticsReceived(stats) => self.state.on_statistics_received(stats),
+            Event::ConsumerRepositioned(partition, _) => {
+                self.state.on_consumer_repositioned(partition.as_ref())
+            }
+            Event::ConsumerRebalanced(rebalance) => {
+                self.state.group_rebalance = Some(rebalance.clone())
+            }
+            Event::FilterApplied(filter) => self.filter = filter.clone(),
+            Event::FilterReapplied(unmatched) => self.state.on_filter_reapplied(unmatched),
             _ => {}
         }
     }
@@ -853,7 +1053,8 @@ impl<'a> Component for Stats<'a> {
         let consumer_status_line = ConsumerStatusLine::builder()
             .consumer_mode(self.state.consumer_mode.get())
             .topic(self.topic.as_str())
-            .filter(self.filter.as_ref())
+            .filter(self.filter.as_ref().map(RecordFilter::expression))
+            .group_rebalance(self.state.group_rebalance.as_ref())
             .processing_style(self.theme.processing_text_color)
             .paused_style(self.theme.paused_text_color)
             .build()
@@ -873,7 +1074,7 @@ impl<'a> Component for Stats<'a> {
         }
 
         match self.state.consumer_mode.get() {
-            ConsumerMode::Stopped => {}
+            ConsumerMode::Stopped | ConsumerMode::Complete => {}
             ConsumerMode::Processing => key_bindings.push(super::KEY_BINDING_PAUSE),
             ConsumerMode::Paused => key_bindings.push(super::KEY_BINDING_RESUME),
         };
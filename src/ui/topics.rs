@@ -1,8 +1,8 @@
 use crate::{
     app::{BufferedKeyPress, config::Theme},
     event::Event,
-    kafka::admin::{Topic, TopicConfig},
-    ui::{Component, MappedKeyEvent},
+    kafka::admin::{CreateTopicRequest, Topic, TopicConfig, TopicConfigChange, TopicConfigEntry},
+    ui::{Component, MappedKeyEvent, widget::TextInput},
 };
 
 use crossterm::event::{KeyCode, KeyEvent};
@@ -13,8 +13,8 @@ use ratatui::{
     style::{Color, Modifier, Style, Stylize},
     text::{Line, Span},
     widgets::{
-        Block, BorderType, Borders, HighlightSpacing, List, ListItem, ListState, Padding,
-        Paragraph, Row, Scrollbar, ScrollbarOrientation, ScrollbarState, Table,
+        Block, BorderType, Borders, Clear, HighlightSpacing, List, ListItem, ListState, Padding,
+        Paragraph, Row, Scrollbar, ScrollbarOrientation, ScrollbarState, Table, TableState, Wrap,
     },
 };
 use std::str::FromStr;
@@ -28,9 +28,55 @@ const KEY_BINDING_APPLY_FILTER: &str = "(enter) apply filter";
 /// Text displayed to the user in the footer for the clear filter key binding.
 const KEY_BINDING_CLEAR_FILTER: &str = "(c) clear filter";
 
+/// Text displayed to the user in the footer for the consume topic key binding.
+const KEY_BINDING_CONSUME: &str = "(r) consume";
+
+/// Text displayed to the user in the footer for the create topic key binding.
+const KEY_BINDING_CREATE: &str = "(n) new topic";
+
+/// Text displayed to the user in the footer for the delete topic key binding.
+const KEY_BINDING_DELETE: &str = "(d) delete";
+
+/// Text displayed to the user in the footer for the add partitions key binding.
+const KEY_BINDING_ADD_PARTITIONS: &str = "(p) add partitions";
+
+/// Key bindings that are displayed to the user in the footer while the add partitions dialog is
+/// open.
+const CREATE_PARTITIONS_DIALOG_KEY_BINDINGS: [&str; 2] = ["(esc) cancel", "(enter) add"];
+
+/// Warning displayed in the add partitions dialog about the effect of adding partitions on keyed
+/// records.
+const CREATE_PARTITIONS_WARNING: &str = "Records with the same key may be written to a different \
+    partition once partitions are added, so ordering per key is not preserved across the change. \
+    Partitions cannot be removed.";
+
+/// Text displayed to the user in the footer for the edit config entry key binding.
+const KEY_BINDING_EDIT_CONFIG: &str = "(enter) edit";
+
+/// Text displayed to the user in the footer for the reset config entry key binding.
+const KEY_BINDING_RESET_CONFIG: &str = "(x) reset to default";
+
+/// Key bindings that are displayed to the user in the footer while the edit config dialog is open.
+const EDIT_CONFIG_DIALOG_KEY_BINDINGS: [&str; 2] = ["(esc) cancel", "(enter) apply"];
+
+/// Key bindings that are displayed to the user in the footer while the delete topic dialog is
+/// open.
+const DELETE_TOPIC_DIALOG_KEY_BINDINGS: [&str; 2] = ["(esc) cancel", "(enter) delete"];
+
+/// Key bindings that are displayed to the user in the footer while the create topic dialog is
+/// open.
+const CREATE_TOPIC_DIALOG_KEY_BINDINGS: [&str; 3] =
+    ["(esc) cancel", "(tab) next field", "(enter) create"];
+
+/// Separator between the config overrides entered by the user when creating a topic.
+const CONFIG_OVERRIDE_SEPARATOR: char = ',';
+
+/// Separator between the key and value of a config override entered by the user.
+const CONFIG_KEY_VALUE_SEPARATOR: char = '=';
+
 /// Key bindings that are always displayed to the user in the footer when viewing the topics
 /// screen.
-const TOPICS_KEY_BINDINGS: [&str; 1] = [super::KEY_BINDING_QUIT];
+const TOPICS_KEY_BINDINGS: [&str; 2] = [super::KEY_BINDING_QUIT, super::KEY_BINDING_CHANGE_FOCUS];
 
 /// Headers for the topic configuration table along with their fill constraints.
 const TOPIC_CONFIG_HEADERS: [(&str, u16); 2] = [("Key", 5), ("Value", 5)];
@@ -50,6 +96,216 @@ enum NetworkStatus {
     LoadingTopicConfig,
 }
 
+/// Parses the config overrides entered by the user as a comma separated list of `key=value`
+/// pairs. A segment without a separator is appended to the value of the previous pair so that
+/// values which contain commas, e.g. `cleanup.policy=compact,delete`, can be entered.
+fn parse_config_overrides(text: &str) -> anyhow::Result<Vec<(String, String)>> {
+    let mut overrides: Vec<(String, String)> = Vec::new();
+
+    for segment in text.split(CONFIG_OVERRIDE_SEPARATOR) {
+        match segment.split_once(CONFIG_KEY_VALUE_SEPARATOR) {
+            Some((key, value)) => {
+                let key = key.trim();
+
+                if key.is_empty() {
+                    anyhow::bail!("config override has no key: {}", segment.trim());
+                }
+
+                overrides.push((String::from(key), String::from(value.trim())));
+            }
+            None if segment.trim().is_empty() => {}
+            None => match overrides.last_mut() {
+                Some((_, value)) => {
+                    value.push(CONFIG_OVERRIDE_SEPARATOR);
+                    value.push_str(segment.trim());
+                }
+                None => anyhow::bail!("config override has no value: {}", segment.trim()),
+            },
+        }
+    }
+
+    Ok(overrides)
+}
+
+/// Enumeration of the fields in the [`CreateTopicDialog`] that can have focus.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+enum CreateTopicField {
+    #[default]
+    Name,
+    Partitions,
+    ReplicationFactor,
+    Config,
+}
+
+/// Manages state for the dialog the user can open to create a new topic on the Kafka cluster.
+#[derive(Debug, Default)]
+struct CreateTopicDialog {
+    /// Input for the name of the topic.
+    name: TextInput,
+    /// Input for the number of partitions of the topic.
+    partitions: TextInput,
+    /// Input for the replication factor of the topic.
+    replication_factor: TextInput,
+    /// Input for the config overrides of the topic.
+    config: TextInput,
+    /// Field in the dialog that currently has focus.
+    active_field: CreateTopicField,
+    /// Flag indicating whether the topic has been submitted and is waiting to be created.
+    creating: bool,
+    /// Message describing why the topic could not be created, if any.
+    error: Option<String>,
+}
+
+impl CreateTopicDialog {
+    /// Returns a mutable reference to the [`TextInput`] for the field that currently has focus.
+    fn active_input_mut(&mut self) -> &mut TextInput {
+        match self.active_field {
+            CreateTopicField::Name => &mut self.name,
+            CreateTopicField::Partitions => &mut self.partitions,
+            CreateTopicField::ReplicationFactor => &mut self.replication_factor,
+            CreateTopicField::Config => &mut self.config,
+        }
+    }
+    /// Cycles the focus to the next field in the dialog.
+    fn select_next_field(&mut self) {
+        self.active_field = match self.active_field {
+            CreateTopicField::Name => CreateTopicField::Partitions,
+            CreateTopicField::Partitions => CreateTopicField::ReplicationFactor,
+            CreateTopicField::ReplicationFactor => CreateTopicField::Config,
+            CreateTopicField::Config => CreateTopicField::Name,
+        };
+    }
+    /// Creates the [`CreateTopicRequest`] from the values entered by the user.
+    fn parse(&self) -> anyhow::Result<CreateTopicRequest> {
+        let name = match self.name.value().trim() {
+            "" => anyhow::bail!("name is required"),
+            n => String::from(n),
+        };
+
+        let partitions = match self.partitions.value().trim() {
+            "" => None,
+            p => match p.parse::<i32>() {
+                Ok(p) if p > 0 => Some(p),
+                _ => anyhow::bail!("invalid partitions: {}", p),
+            },
+        };
+
+        let replication_factor = match self.replication_factor.value().trim() {
+            "" => None,
+            r => match r.parse::<i32>() {
+                Ok(r) if r > 0 => Some(r),
+                _ => anyhow::bail!("invalid replication factor: {}", r),
+            },
+        };
+
+        let config = parse_config_overrides(self.config.value())?;
+
+        Ok(CreateTopicRequest {
+            name,
+            partitions,
+            replication_factor,
+            config,
+        })
+    }
+}
+
+/// Manages state for the dialog the user must confirm by typing the name of the topic before it
+/// is deleted from the Kafka cluster.
+#[derive(Debug)]
+struct DeleteTopicDialog {
+    /// Name of the topic to delete.
+    topic: String,
+    /// Input for the name of the topic typed by the user to confirm the deletion.
+    confirmation: TextInput,
+    /// Flag indicating whether the deletion has been submitted and is waiting to complete.
+    deleting: bool,
+    /// Message describing why the topic could not be deleted, if any.
+    error: Option<String>,
+}
+
+impl DeleteTopicDialog {
+    /// Creates a new [`DeleteTopicDialog`] for the topic with the specified name.
+    fn new(topic: &str) -> Self {
+        Self {
+            topic: String::from(topic),
+            confirmation: TextInput::default(),
+            deleting: false,
+            error: None,
+        }
+    }
+    /// Determines if the name typed by the user matches the name of the topic.
+    fn is_confirmed(&self) -> bool {
+        self.confirmation.value() == self.topic
+    }
+}
+
+/// Manages state for the dialog used to increase the number of partitions of the selected topic.
+#[derive(Debug)]
+struct CreatePartitionsDialog {
+    /// Name of the topic to add partitions to.
+    topic: String,
+    /// Number of partitions the topic currently has.
+    current: usize,
+    /// Input for the new total number of partitions.
+    total: TextInput,
+    /// Flag indicating whether the partitions have been submitted and are waiting to be created.
+    creating: bool,
+    /// Message describing why the partitions could not be created, if any.
+    error: Option<String>,
+}
+
+impl CreatePartitionsDialog {
+    /// Creates a new [`CreatePartitionsDialog`] for the specified [`Topic`].
+    fn new(topic: &Topic) -> Self {
+        Self {
+            topic: topic.name.clone(),
+            current: topic.partitions.len(),
+            total: TextInput::default(),
+            creating: false,
+            error: None,
+        }
+    }
+    /// Parses the new total number of partitions entered by the user, which must be greater than
+    /// the current number of partitions.
+    fn parse(&self) -> anyhow::Result<usize> {
+        match self.total.value().trim().parse::<usize>() {
+            Ok(total) if total > self.current => Ok(total),
+            Ok(_) => anyhow::bail!("total must be greater than {}", self.current),
+            Err(_) => anyhow::bail!("invalid partitions: {}", self.total.value().trim()),
+        }
+    }
+}
+
+/// Manages state for the dialog used to change the value of a configuration entry of the selected
+/// topic.
+#[derive(Debug)]
+struct EditConfigDialog {
+    /// Name of the topic that the configuration entry belongs to.
+    topic: String,
+    /// Key of the configuration entry.
+    key: String,
+    /// Input for the new value of the configuration entry.
+    value: TextInput,
+    /// Flag indicating whether the change has been submitted and is waiting to complete.
+    applying: bool,
+    /// Message describing why the change could not be applied, if any.
+    error: Option<String>,
+}
+
+impl EditConfigDialog {
+    /// Creates a new [`EditConfigDialog`] for the [`TopicConfigEntry`] of the topic with the
+    /// specified name, prefilled with the current value of the entry.
+    fn new(topic: &str, entry: &TopicConfigEntry) -> Self {
+        Self {
+            topic: String::from(topic),
+            key: entry.key.clone(),
+            value: TextInput::new(entry.value.as_deref().unwrap_or_default()),
+            applying: false,
+            error: None,
+        }
+    }
+}
+
 /// Enumeration of the widgets in the [`Topics`] component that can have focus.
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
 enum TopicsWidget {
@@ -58,6 +314,8 @@ enum TopicsWidget {
     Topics,
     /// Topics filter input widget.
     FilterInput,
+    /// Topic configuration table widget.
+    Config,
 }
 
 #[derive(Debug, Default)]
@@ -73,6 +331,8 @@ struct TopicsState {
     selected_topic: Option<Topic>,
     /// Configuration details for the currently selected topic.
     selected_topic_config: Option<TopicConfig>,
+    /// Manages state of the topic configuration table widget.
+    config_table_state: TableState,
     /// Manages state of the topics list widget.
     topics_list_state: ListState,
     /// Manages state of the topics list scrollbar.
@@ -81,6 +341,14 @@ struct TopicsState {
     network_status: NetworkStatus,
     /// Current filter applied to the topics list.
     topics_filter: Option<String>,
+    /// Dialog for creating a new topic, if it is open.
+    create_topic_dialog: Option<CreateTopicDialog>,
+    /// Dialog for confirming the deletion of the selected topic, if it is open.
+    delete_topic_dialog: Option<DeleteTopicDialog>,
+    /// Dialog for changing the value of the selected configuration entry, if it is open.
+    edit_config_dialog: Option<EditConfigDialog>,
+    /// Dialog for increasing the number of partitions of the selected topic, if it is open.
+    create_partitions_dialog: Option<CreatePartitionsDialog>,
 }
 
 impl TopicsState {
@@ -98,11 +366,75 @@ impl TopicsState {
             .map(|(i, _)| i)
             .collect::<Vec<usize>>();
     }
+    /// Selects the visible topic with the specified name, or deselects the current topic if it is
+    /// not visible. Used to keep the selection on the same topic when the list is reloaded.
+    fn reselect_topic(&mut self, name: &str) {
+        let position = self
+            .visible_indices
+            .iter()
+            .position(|i| self.topics.get(*i).is_some_and(|t| t.name.as_str() == name));
+
+        match position {
+            Some(idx) => {
+                self.topics_list_state.select(Some(idx));
+                self.topics_scroll_state = self.topics_scroll_state.position(idx);
+                self.selected_topic = self.topics.get(self.visible_indices[idx]).cloned();
+            }
+            None => {
+                self.deselect_topic();
+                self.clear_topic_config();
+            }
+        }
+    }
     /// Deselects the currently selected topic.
     fn deselect_topic(&mut self) {
         self.topics_list_state.select(None);
         self.selected_topic = None;
     }
+    /// Clears the configuration of the selected topic, moving focus back to the topics list if the
+    /// configuration table had focus.
+    fn clear_topic_config(&mut self) {
+        self.selected_topic_config = None;
+        self.config_table_state.select(None);
+
+        if self.active_widget == TopicsWidget::Config {
+            self.active_widget = TopicsWidget::Topics;
+        }
+    }
+    /// Moves focus to the next widget. The configuration table can only receive focus once the
+    /// configuration of the selected topic has been loaded.
+    fn select_next_widget(&mut self) {
+        self.active_widget = match self.active_widget {
+            TopicsWidget::Topics if self.selected_topic_config.is_some() => {
+                if self.config_table_state.selected().is_none() {
+                    self.config_table_state.select_first();
+                }
+
+                TopicsWidget::Config
+            }
+            TopicsWidget::Topics | TopicsWidget::FilterInput | TopicsWidget::Config => {
+                TopicsWidget::Topics
+            }
+        }
+    }
+    /// Returns the [`TopicConfigEntry`] currently selected in the configuration table, if any.
+    fn selected_config_entry(&self) -> Option<&TopicConfigEntry> {
+        let idx = self.config_table_state.selected()?;
+
+        self.selected_topic_config.as_ref()?.entries().get(idx)
+    }
+    /// Selects the entry at the specified index in the configuration table, bounded by the number
+    /// of entries.
+    fn select_config_entry(&mut self, idx: usize) {
+        let len = self
+            .selected_topic_config
+            .as_ref()
+            .map_or(0, |c| c.entries().len());
+
+        if len > 0 {
+            self.config_table_state.select(Some(idx.min(len - 1)));
+        }
+    }
     /// Invoked when the user starts filtering topics.
     fn on_start_filter(&mut self) {
         self.active_widget = TopicsWidget::FilterInput;
@@ -206,6 +538,8 @@ struct TopicsTheme {
     label_color: Color,
     /// Color used for the non-default topic configuration.
     highlight_text_color: Color,
+    /// Color used for the text describing why an operation failed.
+    failure_text_color: Color,
     /// Color used for the key bindings text. Defaults to white.
     key_bindings_text_color: Color,
 }
@@ -224,6 +558,9 @@ impl From<&Theme> for TopicsTheme {
         let highlight_text_color =
             Color::from_str(value.highlight_text_color.as_str()).expect("valid RGB hex");
 
+        let failure_text_color =
+            Color::from_str(value.notification_text_color_failure.as_str()).expect("valid RGB hex");
+
         let key_bindings_text_color =
             Color::from_str(value.key_bindings_text_color.as_str()).expect("valid RGB hex");
 
@@ -232,6 +569,7 @@ impl From<&Theme> for TopicsTheme {
             selected_panel_border_color,
             label_color,
             highlight_text_color,
+            failure_text_color,
             key_bindings_text_color,
         }
     }
@@ -301,12 +639,553 @@ impl Topics {
         self.state.topics.sort();
 
         self.state.update_visible_topics();
+
+        if let Some(name) = self.state.selected_topic.as_ref().map(|t| t.name.clone()) {
+            self.state.reselect_topic(&name);
+        }
+    }
+    /// Invoked when a topic was created on the Kafka cluster. The list of topics is reloaded by
+    /// the application afterwards.
+    fn on_topic_created(&mut self) {
+        self.state.create_topic_dialog = None;
+        self.state.network_status = NetworkStatus::LoadingTopics;
+    }
+    /// Invoked when a topic failed to be created on the Kafka cluster. The dialog is kept open so
+    /// that the user can correct the values entered.
+    fn on_create_topic_failure(&mut self, error: &anyhow::Error) {
+        if let Some(dialog) = self.state.create_topic_dialog.as_mut() {
+            dialog.creating = false;
+            dialog.error = Some(format!("{:#}", error));
+        }
+    }
+    /// Invoked when a topic was deleted from the Kafka cluster. The topic is deselected if it was
+    /// selected and the list of topics is reloaded by the application afterwards.
+    fn on_topic_deleted(&mut self, topic: &str) {
+        self.state.delete_topic_dialog = None;
+        self.state.network_status = NetworkStatus::LoadingTopics;
+
+        if self
+            .state
+            .selected_topic
+            .as_ref()
+            .is_some_and(|t| t.name == topic)
+        {
+            self.state.deselect_topic();
+            self.state.clear_topic_config();
+        }
+    }
+    /// Invoked when a topic failed to be deleted from the Kafka cluster.
+    fn on_delete_topic_failure(&mut self, error: &anyhow::Error) {
+        if let Some(dialog) = self.state.delete_topic_dialog.as_mut() {
+            dialog.deleting = false;
+            dialog.error = Some(format!("{:#}", error));
+        }
+    }
+    /// Invoked when the number of partitions of a topic was increased on the Kafka cluster. The
+    /// list of topics, and with it the partitions of the selected topic, is reloaded by the
+    /// application afterwards.
+    fn on_partitions_created(&mut self) {
+        self.state.create_partitions_dialog = None;
+        self.state.network_status = NetworkStatus::LoadingTopics;
+    }
+    /// Invoked when the number of partitions of a topic failed to be increased on the Kafka
+    /// cluster. The dialog is kept open so that the user can correct the value entered.
+    fn on_create_partitions_failure(&mut self, error: &anyhow::Error) {
+        if let Some(dialog) = self.state.create_partitions_dialog.as_mut() {
+            dialog.creating = false;
+            dialog.error = Some(format!("{:#}", error));
+        }
+    }
+    /// Invoked when a configuration entry of a topic was changed on the Kafka cluster. The reloaded
+    /// configuration replaces the displayed one if the topic is still selected.
+    fn on_topic_config_altered(&mut self, topic: &str, topic_config: Option<TopicConfig>) {
+        self.state.edit_config_dialog = None;
+
+        if self
+            .state
+            .selected_topic
+            .as_ref()
+            .is_some_and(|t| t.name == topic)
+            && let Some(topic_config) = topic_config
+        {
+            self.state.selected_topic_config = Some(topic_config);
+
+            if let Some(idx) = self.state.config_table_state.selected() {
+                self.state.select_config_entry(idx);
+            }
+        }
+    }
+    /// Invoked when a configuration entry of a topic failed to be changed on the Kafka cluster.
+    /// The dialog is kept open so that the user can correct the value entered.
+    fn on_alter_topic_config_failure(&mut self, error: &anyhow::Error) {
+        if let Some(dialog) = self.state.edit_config_dialog.as_mut() {
+            dialog.applying = false;
+            dialog.error = Some(format!("{:#}", error));
+        }
+    }
+    /// Returns the key bindings for the dialog that is currently open, if any.
+    fn dialog_key_bindings(&self) -> Option<&'static [&'static str]> {
+        if self.state.create_topic_dialog.is_some() {
+            Some(&CREATE_TOPIC_DIALOG_KEY_BINDINGS)
+        } else if self.state.delete_topic_dialog.is_some() {
+            Some(&DELETE_TOPIC_DIALOG_KEY_BINDINGS)
+        } else if self.state.edit_config_dialog.is_some() {
+            Some(&EDIT_CONFIG_DIALOG_KEY_BINDINGS)
+        } else if self.state.create_partitions_dialog.is_some() {
+            Some(&CREATE_PARTITIONS_DIALOG_KEY_BINDINGS)
+        } else {
+            None
+        }
+    }
+    /// Renders the delete topic confirmation dialog as a popup centered in the given area.
+    fn render_delete_topic_dialog(&self, frame: &mut Frame, area: Rect) {
+        let Some(dialog) = self.state.delete_topic_dialog.as_ref() else {
+            return;
+        };
+
+        let rect = area.centered(Constraint::Length(60), Constraint::Length(6));
+
+        let popup_block = Block::bordered()
+            .border_style(self.theme.selected_panel_border_color)
+            .border_type(BorderType::Thick)
+            .padding(Padding::new(1, 1, 0, 0));
+
+        let inner_area = popup_block.inner(rect);
+
+        let [prompt_area, hint_area, input_area, status_area] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1); 4])
+            .areas(inner_area);
+
+        let prompt_text = Paragraph::new(format!("Delete topic {}?", dialog.topic))
+            .bold()
+            .centered();
+
+        let hint_text = Paragraph::new("Type the topic name to confirm")
+            .style(self.theme.label_color)
+            .centered();
+
+        let status_text = match (dialog.deleting, dialog.error.as_ref()) {
+            (true, _) => Paragraph::new("Deleting topic...").centered(),
+            (false, Some(error)) => Paragraph::new(error.as_str())
+                .style(self.theme.failure_text_color)
+                .centered(),
+            (false, None) => Paragraph::default(),
+        };
+
+        frame.render_widget(Clear, rect);
+        frame.render_widget(popup_block, rect);
+        frame.render_widget(prompt_text, prompt_area);
+        frame.render_widget(hint_text, hint_area);
+        frame.render_widget(status_text, status_area);
+
+        dialog
+            .confirmation
+            .render(frame, input_area, Style::default(), true);
+    }
+    /// Maps a [`KeyEvent`] while the delete topic dialog is open. Every key event is consumed by
+    /// the dialog.
+    fn map_delete_topic_dialog_key_event(&mut self, event: KeyEvent) -> MappedKeyEvent {
+        let dialog = self
+            .state
+            .delete_topic_dialog
+            .as_mut()
+            .expect("delete topic dialog open");
+
+        if dialog.deleting {
+            return MappedKeyEvent::Consumed;
+        }
+
+        match event.code {
+            KeyCode::Esc => {
+                self.state.delete_topic_dialog = None;
+                MappedKeyEvent::Consumed
+            }
+            KeyCode::Enter if dialog.is_confirmed() => {
+                dialog.deleting = true;
+                dialog.error = None;
+                MappedKeyEvent::Dispatch(Event::DeleteTopic(dialog.topic.clone()))
+            }
+            KeyCode::Enter => {
+                dialog.error = Some(String::from("topic name does not match"));
+                MappedKeyEvent::Consumed
+            }
+            _ => {
+                dialog.confirmation.on_key_event(event);
+                MappedKeyEvent::Consumed
+            }
+        }
+    }
+    /// Renders the add partitions dialog as a popup centered in the given area.
+    fn render_create_partitions_dialog(&self, frame: &mut Frame, area: Rect) {
+        let Some(dialog) = self.state.create_partitions_dialog.as_ref() else {
+            return;
+        };
+
+        let rect = area.centered(Constraint::Percentage(60), Constraint::Length(10));
+
+        let popup_block = Block::bordered()
+            .title(" Add Partitions ")
+            .border_style(self.theme.selected_panel_border_color)
+            .border_type(BorderType::Thick)
+            .padding(Padding::new(1, 1, 0, 0));
+
+        let inner_area = popup_block.inner(rect);
+
+        let [
+            current_area,
+            label_area,
+            input_area,
+            _,
+            warning_area,
+            status_area,
+        ] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(3),
+                Constraint::Length(1),
+            ])
+            .areas(inner_area);
+
+        let current_text = Paragraph::new(format!(
+            "Topic {} has {} partitions",
+            dialog.topic, dialog.current
+        ));
+
+        let label_text = Paragraph::new("New Total Partitions")
+            .style(self.theme.label_color)
+            .bold();
+
+        let warning_text = Paragraph::new(CREATE_PARTITIONS_WARNING)
+            .style(self.theme.highlight_text_color)
+            .wrap(Wrap { trim: true });
+
+        let status_text = match (dialog.creating, dialog.error.as_ref()) {
+            (true, _) => Paragraph::new("Adding partitions..."),
+            (false, Some(error)) => {
+                Paragraph::new(error.as_str()).style(self.theme.failure_text_color)
+            }
+            (false, None) => Paragraph::default(),
+        };
+
+        frame.render_widget(Clear, rect);
+        frame.render_widget(popup_block, rect);
+        frame.render_widget(current_text, current_area);
+        frame.render_widget(label_text, label_area);
+        frame.render_widget(warning_text, warning_area);
+        frame.render_widget(status_text, status_area);
+
+        dialog
+            .total
+            .render(frame, input_area, Style::default(), true);
+    }
+    /// Maps a [`KeyEvent`] while the add partitions dialog is open. Every key event is consumed by
+    /// the dialog.
+    fn map_create_partitions_dialog_key_event(&mut self, event: KeyEvent) -> MappedKeyEvent {
+        let dialog = self
+            .state
+            .create_partitions_dialog
+            .as_mut()
+            .expect("create partitions dialog open");
+
+        if dialog.creating {
+            return MappedKeyEvent::Consumed;
+        }
+
+        match event.code {
+            KeyCode::Esc => {
+                self.state.create_partitions_dialog = None;
+                MappedKeyEvent::Consumed
+            }
+            KeyCode::Enter => match dialog.parse() {
+                Ok(total) => {
+                    dialog.creating = true;
+                    dialog.error = None;
+                    MappedKeyEvent::Dispatch(Event::CreatePartitions(dialog.topic.clone(), total))
+                }
+                Err(e) => {
+                    dialog.error = Some(e.to_string());
+                    MappedKeyEvent::Consumed
+                }
+            },
+            _ => {
+                dialog.total.on_key_event(event);
+                MappedKeyEvent::Consumed
+            }
+        }
+    }
+    /// Renders the edit config dialog as a popup centered in the given area.
+    fn render_edit_config_dialog(&self, frame: &mut Frame, area: Rect) {
+        let Some(dialog) = self.state.edit_config_dialog.as_ref() else {
+            return;
+        };
+
+        let rect = area.centered(Constraint::Percentage(60), Constraint::Length(6));
+
+        let popup_block = Block::bordered()
+            .title(" Edit Config ")
+            .border_style(self.theme.selected_panel_border_color)
+            .border_type(BorderType::Thick)
+            .padding(Padding::new(1, 1, 0, 0));
+
+        let inner_area = popup_block.inner(rect);
+
+        let [key_area, input_area, _, status_area] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1); 4])
+            .areas(inner_area);
+
+        let key_text = Paragraph::new(dialog.key.as_str())
+            .style(self.theme.label_color)
+            .bold();
+
+        let status_text = match (dialog.applying, dialog.error.as_ref()) {
+            (true, _) => Paragraph::new("Applying change..."),
+            (false, Some(error)) => {
+                Paragraph::new(error.as_str()).style(self.theme.failure_text_color)
+            }
+            (false, None) => Paragraph::default(),
+        };
+
+        frame.render_widget(Clear, rect);
+        frame.render_widget(popup_block, rect);
+        frame.render_widget(key_text, key_area);
+        frame.render_widget(status_text, status_area);
+
+        dialog
+            .value
+            .render(frame, input_area, Style::default(), true);
+    }
+    /// Maps a [`KeyEvent`] while the edit config dialog is open. Every key event is consumed by
+    /// the dialog.
+    fn map_edit_config_dialog_key_event(&mut self, event: KeyEvent) -> MappedKeyEvent {
+        let dialog = self
+            .state
+            .edit_config_dialog
+            .as_mut()
+            .expect("edit config dialog open");
+
+        if dialog.applying {
+            return MappedKeyEvent::Consumed;
+        }
+
+        match event.code {
+            KeyCode::Esc => {
+                self.state.edit_config_dialog = None;
+                MappedKeyEvent::Consumed
+            }
+            KeyCode::Enter => {
+                dialog.applying = true;
+                dialog.error = None;
+
+                let change = TopicConfigChange::Set {
+                    key: dialog.key.clone(),
+                    value: String::from(dialog.value.value()),
+                };
+
+                MappedKeyEvent::Dispatch(Event::AlterTopicConfig(dialog.topic.clone(), change))
+            }
+            _ => {
+                dialog.value.on_key_event(event);
+                MappedKeyEvent::Consumed
+            }
+        }
+    }
+    /// Maps a [`KeyEvent`] while the topic configuration table has focus.
+    fn map_config_key_event(
+        &mut self,
+        event: KeyEvent,
+        buffered: Option<&BufferedKeyPress>,
+    ) -> MappedKeyEvent {
+        let Some(topic) = self.state.selected_topic.as_ref() else {
+            return MappedKeyEvent::Unhandled;
+        };
+
+        match event.code {
+            KeyCode::Enter => match self.state.selected_config_entry() {
+                Some(entry) if !entry.read_only => {
+                    self.state.edit_config_dialog = Some(EditConfigDialog::new(&topic.name, entry));
+                    MappedKeyEvent::Consumed
+                }
+                _ => MappedKeyEvent::Unhandled,
+            },
+            KeyCode::Char('x') => match self.state.selected_config_entry() {
+                Some(entry) if !entry.default && !entry.read_only => {
+                    let change = TopicConfigChange::Reset {
+                        key: entry.key.clone(),
+                    };
+
+                    MappedKeyEvent::Dispatch(Event::AlterTopicConfig(topic.name.clone(), change))
+                }
+                _ => MappedKeyEvent::Unhandled,
+            },
+            KeyCode::Char('g') if buffered.filter(|kp| kp.is('g')).is_some() => {
+                self.state.select_config_entry(0);
+                MappedKeyEvent::Consumed
+            }
+            KeyCode::Char('j') => {
+                let idx = self
+                    .state
+                    .config_table_state
+                    .selected()
+                    .map_or(0, |i| i + 1);
+                self.state.select_config_entry(idx);
+                MappedKeyEvent::Consumed
+            }
+            KeyCode::Char('k') => {
+                let idx = self
+                    .state
+                    .config_table_state
+                    .selected()
+                    .map_or(0, |i| i.saturating_sub(1));
+                self.state.select_config_entry(idx);
+                MappedKeyEvent::Consumed
+            }
+            KeyCode::Char('G') => {
+                self.state.select_config_entry(usize::MAX);
+                MappedKeyEvent::Consumed
+            }
+            _ => MappedKeyEvent::Unhandled,
+        }
+    }
+    /// Renders the create topic dialog as a popup centered in the given area.
+    fn render_create_topic_dialog(&self, frame: &mut Frame, area: Rect) {
+        let Some(dialog) = self.state.create_topic_dialog.as_ref() else {
+            return;
+        };
+
+        let rect = area.centered(Constraint::Percentage(60), Constraint::Length(13));
+
+        let popup_block = Block::bordered()
+            .title(" New Topic ")
+            .border_style(self.theme.selected_panel_border_color)
+            .border_type(BorderType::Thick)
+            .padding(Padding::new(1, 1, 0, 0));
+
+        let inner_area = popup_block.inner(rect);
+
+        let [
+            name_label,
+            name_input,
+            partitions_label,
+            partitions_input,
+            replication_factor_label,
+            replication_factor_input,
+            config_label,
+            config_input,
+            _,
+            status_area,
+        ] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1); 10])
+            .areas(inner_area);
+
+        frame.render_widget(Clear, rect);
+        frame.render_widget(popup_block, rect);
+
+        let fields = [
+            (
+                "Name",
+                name_label,
+                name_input,
+                &dialog.name,
+                CreateTopicField::Name,
+            ),
+            (
+                "Partitions (empty for broker default)",
+                partitions_label,
+                partitions_input,
+                &dialog.partitions,
+                CreateTopicField::Partitions,
+            ),
+            (
+                "Replication Factor (empty for broker default)",
+                replication_factor_label,
+                replication_factor_input,
+                &dialog.replication_factor,
+                CreateTopicField::ReplicationFactor,
+            ),
+            (
+                "Config Overrides (key=value, comma separated)",
+                config_label,
+                config_input,
+                &dialog.config,
+                CreateTopicField::Config,
+            ),
+        ];
+
+        for (label, label_area, input_area, input, field) in fields {
+            let label_text = Paragraph::new(label).style(self.theme.label_color).bold();
+
+            frame.render_widget(label_text, label_area);
+
+            input.render(
+                frame,
+                input_area,
+                Style::default(),
+                dialog.active_field == field,
+            );
+        }
+
+        let status_text = match (dialog.creating, dialog.error.as_ref()) {
+            (true, _) => Paragraph::new("Creating topic..."),
+            (false, Some(error)) => {
+                Paragraph::new(error.as_str()).style(self.theme.failure_text_color)
+            }
+            (false, None) => Paragraph::default(),
+        };
+
+        frame.render_widget(status_text, status_area);
+    }
+    /// Maps a [`KeyEvent`] while the create topic dialog is open. Every key event is consumed by
+    /// the dialog.
+    fn map_create_topic_dialog_key_event(&mut self, event: KeyEvent) -> MappedKeyEvent {
+        let dialog = self
+            .state
+            .create_topic_dialog
+            .as_mut()
+            .expect("create topic dialog open");
+
+        if dialog.creating {
+            return MappedKeyEvent::Consumed;
+        }
+
+        match event.code {
+            KeyCode::Esc => {
+                self.state.create_topic_dialog = None;
+                MappedKeyEvent::Consumed
+            }
+            KeyCode::Tab => {
+                dialog.select_next_field();
+                MappedKeyEvent::Consumed
+            }
+            KeyCode::Enter => match dialog.parse() {
+                Ok(request) => {
+                    dialog.creating = true;
+                    dialog.error = None;
+                    MappedKeyEvent::Dispatch(Event::CreateTopic(request))
+                }
+                Err(e) => {
+                    dialog.error = Some(e.to_string());
+                    MappedKeyEvent::Consumed
+                }
+            },
+            _ => {
+                dialog.active_input_mut().on_key_event(event);
+                MappedKeyEvent::Consumed
+            }
+        }
     }
     /// Invoked when the configuration for the selected topic has been loaded from the Kafka
     /// cluster.
     fn on_topic_config_loaded(&mut self, topic_config: Option<TopicConfig>) {
         self.state.network_status = NetworkStatus::Idle;
         self.state.selected_topic_config = topic_config;
+        self.state.config_table_state.select(None);
     }
     /// Renders the filter input box for filtering topics.
     fn render_filter_input(&mut self, frame: &mut Frame, area: Rect) {
@@ -381,7 +1260,7 @@ impl Topics {
         }
     }
     /// Renders the details of a topic, if one is currently selected.
-    fn render_topic_details(&self, frame: &mut Frame, area: Rect) {
+    fn render_topic_details(&mut self, frame: &mut Frame, area: Rect) {
         if self.state.network_status == NetworkStatus::LoadingTopicConfig {
             self.render_message(frame, area, "Loading config...");
             return;
@@ -400,7 +1279,7 @@ impl Topics {
         }
     }
     /// Renders the topic configuration details panel.
-    fn render_topic_config(&self, frame: &mut Frame, area: Rect) {
+    fn render_topic_config(&mut self, frame: &mut Frame, area: Rect) {
         let Some(topic_config) = self.state.selected_topic_config.as_ref() else {
             return;
         };
@@ -413,11 +1292,17 @@ impl Topics {
                 .map_or("", |t| t.name.as_str())
         );
 
-        let config_block = Block::bordered()
+        let mut config_block = Block::bordered()
             .title(title)
             .border_style(self.theme.panel_border_color)
             .padding(Padding::new(1, 1, 0, 0));
 
+        if self.state.active_widget == TopicsWidget::Config {
+            config_block = config_block
+                .border_type(BorderType::Thick)
+                .border_style(self.theme.selected_panel_border_color);
+        }
+
         let config_rows: Vec<Row> = topic_config
             .entries()
             .iter()
@@ -443,9 +1328,10 @@ impl Topics {
         let config_table = Table::new(config_rows, &self.topics_config_constraints)
             .column_spacing(1)
             .header(header)
+            .row_highlight_style(Modifier::REVERSED)
             .block(config_block);
 
-        frame.render_widget(config_table, area);
+        frame.render_stateful_widget(config_table, area, &mut self.state.config_table_state);
     }
     /// Renders the topic partitions panel.
     fn render_topic_partitions(&self, frame: &mut Frame, area: Rect) {
@@ -527,6 +1413,17 @@ impl Component for Topics {
             Event::TopicConfigLoaded(topic_config) => {
                 self.on_topic_config_loaded(topic_config.clone())
             }
+            Event::TopicCreated => self.on_topic_created(),
+            Event::CreateTopicFailure(e) => self.on_create_topic_failure(e),
+            Event::TopicDeleted(topic) => self.on_topic_deleted(topic),
+            Event::DeleteTopicFailure(e) => self.on_delete_topic_failure(e),
+            Event::TopicConfigAltered(topic, topic_config) => {
+                self.on_topic_config_altered(topic, topic_config.clone())
+            }
+            Event::AlterTopicConfigFailure(e) => self.on_alter_topic_config_failure(e),
+            Event::PartitionsCreated => self.on_partitions_created(),
+            Event::CreatePartitionsFailure(e) => self.on_create_partitions_failure(e),
+            Event::SelectNextWidget => self.state.select_next_widget(),
             _ => {}
         }
     }
@@ -537,6 +1434,26 @@ impl Component for Topics {
         event: KeyEvent,
         buffered: Option<&BufferedKeyPress>,
     ) -> MappedKeyEvent {
+        if self.state.create_topic_dialog.is_some() {
+            return self.map_create_topic_dialog_key_event(event);
+        }
+
+        if self.state.delete_topic_dialog.is_some() {
+            return self.map_delete_topic_dialog_key_event(event);
+        }
+
+        if self.state.edit_config_dialog.is_some() {
+            return self.map_edit_config_dialog_key_event(event);
+        }
+
+        if self.state.create_partitions_dialog.is_some() {
+            return self.map_create_partitions_dialog_key_event(event);
+        }
+
+        if self.state.active_widget == TopicsWidget::Config {
+            return self.map_config_key_event(event, buffered);
+        }
+
         let mapped_event = match event.code {
             KeyCode::Enter => {
                 self.state.on_apply_filter();
@@ -568,6 +1485,29 @@ impl Component for Topics {
                         self.state.on_clear_filter();
                         MappedKeyEvent::Consumed
                     }
+                    'n' => {
+                        self.state.create_topic_dialog = Some(CreateTopicDialog::default());
+                        MappedKeyEvent::Consumed
+                    }
+                    'd' => match self.state.selected_topic.as_ref() {
+                        Some(t) => {
+                            self.state.delete_topic_dialog = Some(DeleteTopicDialog::new(&t.name));
+                            MappedKeyEvent::Consumed
+                        }
+                        None => MappedKeyEvent::Unhandled,
+                    },
+                    'p' => match self.state.selected_topic.as_ref() {
+                        Some(t) => {
+                            self.state.create_partitions_dialog =
+                                Some(CreatePartitionsDialog::new(t));
+                            MappedKeyEvent::Consumed
+                        }
+                        None => MappedKeyEvent::Unhandled,
+                    },
+                    'r' => match self.state.selected_topic.as_ref() {
+                        Some(t) => MappedKeyEvent::Dispatch(Event::ConsumeTopic(t.name.clone())),
+                        None => MappedKeyEvent::Unhandled,
+                    },
                     'e' => {
                         if let Some(selected_topic) = self.state.selected_topic.as_ref()
                             && let Some(selected_topic_config) =
@@ -613,6 +1553,7 @@ impl Component for Topics {
 
                     MappedKeyEvent::Consumed
                 }
+                TopicsWidget::Config => MappedKeyEvent::Unhandled,
             },
             _ => MappedKeyEvent::Unhandled,
         };
@@ -644,8 +1585,23 @@ impl Component for Topics {
 
         frame.render_widget(text, area);
     }
+    /// Determines if the [`Component`] is currently capturing all key input, which is the case
+    /// while a dialog is open.
+    fn is_capturing_input(&self) -> bool {
+        self.dialog_key_bindings().is_some()
+    }
     /// Allows the [`Component`] to render the key bindings text into the footer.
     fn render_key_bindings(&self, frame: &mut Frame, area: Rect) {
+        if let Some(dialog_key_bindings) = self.dialog_key_bindings() {
+            let text = Paragraph::new(dialog_key_bindings.join(" | "))
+                .style(self.theme.key_bindings_text_color)
+                .right_aligned();
+
+            frame.render_widget(text, area);
+
+            return;
+        }
+
         let mut key_bindings = Vec::from(TOPICS_KEY_BINDINGS);
 
         key_bindings.extend_from_slice(&[
@@ -658,17 +1614,39 @@ impl Component for Topics {
         match (self.state.active_widget, self.state.topics_filter.as_ref()) {
             (TopicsWidget::Topics, None) => {
                 key_bindings.push(KEY_BINDING_FILTER);
+                key_bindings.push(KEY_BINDING_CREATE);
             }
             (TopicsWidget::Topics, Some(_)) => {
                 key_bindings.push(KEY_BINDING_FILTER);
                 key_bindings.push(KEY_BINDING_CLEAR_FILTER);
+                key_bindings.push(KEY_BINDING_CREATE);
             }
             (TopicsWidget::FilterInput, _) => {
                 key_bindings.push(KEY_BINDING_APPLY_FILTER);
             }
+            (TopicsWidget::Config, _) => {
+                if let Some(entry) = self.state.selected_config_entry()
+                    && !entry.read_only
+                {
+                    key_bindings.push(KEY_BINDING_EDIT_CONFIG);
+
+                    if !entry.default {
+                        key_bindings.push(KEY_BINDING_RESET_CONFIG);
+                    }
+                }
+            }
+        }
+
+        if self.state.active_widget == TopicsWidget::Topics && self.state.selected_topic.is_some() {
+            key_bindings.push(KEY_BINDING_CONSUME);
+            key_bindings.push(KEY_BINDING_ADD_PARTITIONS);
+            key_bindings.push(KEY_BINDING_DELETE);
         }
 
-        if self.state.selected_topic.is_some() && self.state.selected_topic_config.is_some() {
+        if self.state.active_widget != TopicsWidget::Config
+            && self.state.selected_topic.is_some()
+            && self.state.selected_topic_config.is_some()
+        {
             key_bindings.push(super::KEY_BINDING_EXPORT);
         }
 
@@ -700,6 +1678,22 @@ impl Component for Topics {
 
         self.render_topics(frame, topics_panel);
         self.render_topic_details(frame, right_panel);
+
+        if self.state.create_topic_dialog.is_some() {
+            self.render_create_topic_dialog(frame, area);
+        }
+
+        if self.state.delete_topic_dialog.is_some() {
+            self.render_delete_topic_dialog(frame, area);
+        }
+
+        if self.state.edit_config_dialog.is_some() {
+            self.render_edit_config_dialog(frame, area);
+        }
+
+        if self.state.create_partitions_dialog.is_some() {
+            self.render_create_partitions_dialog(frame, area);
+        }
     }
     /// Hook for the [`Component`] to run any logic required when it becomes active. The
     /// [`Component`] can also return an optional [`Event`] that will be dispatched.
@@ -1,9 +1,11 @@
-use crate::kafka::ConsumerMode;
+use crate::kafka::{ConsumerMode, GroupRebalance};
 
+use crossterm::event::{KeyCode, KeyEvent};
 use derive_builder::Builder;
 use ratatui::{
+    Frame,
     buffer::Buffer,
-    layout::Rect,
+    layout::{Position, Rect},
     style::Style,
     widgets::{Paragraph, Widget},
 };
@@ -11,7 +13,7 @@ use ratatui::{
 /// A simple [`Widget`] that renders text for the status line in the footer based on the current
 /// [`ConsumerMode`] value for the Kafka consumer.
 #[derive(Builder, Debug)]
-pub struct ConsumerStatusLine<T, F, PR, PA>
+pub struct ConsumerStatusLine<'a, T, F, PR, PA>
 where
     T: AsRef<str> + Clone,
     F: AsRef<str> + Clone,
@@ -25,13 +27,17 @@ where
     topic: T,
     /// Any filter that was configured by the user.
     filter: Option<F>,
-    /// Style used for the text when the consumer mode is [`ConsumerMode::Processing`].
+    /// Latest rebalance of the consumer group when the consumer is subscribed to the topic.
+    #[builder(default)]
+    group_rebalance: Option<&'a GroupRebalance>,
+    /// Style used for the text when the consumer mode is [`ConsumerMode::Processing`] or
+    /// [`ConsumerMode::Complete`].
     processing_style: PR,
     /// Style used for the text when the consumer mode is [`ConsumerMode::Paused`].
     paused_style: PA,
 }
 
-impl<T, F, PR, PA> ConsumerStatusLine<T, F, PR, PA>
+impl<'a, T, F, PR, PA> ConsumerStatusLine<'a, T, F, PR, PA>
 where
     T: AsRef<str> + Clone,
     F: AsRef<str> + Clone,
@@ -39,12 +45,12 @@ where
     PA: Into<Style> + Clone,
 {
     /// Creates a new default [`ConsumerStatusLineBuilder`].
-    pub fn builder() -> ConsumerStatusLineBuilder<T, F, PR, PA> {
+    pub fn builder() -> ConsumerStatusLineBuilder<'a, T, F, PR, PA> {
         ConsumerStatusLineBuilder::default()
     }
 }
 
-impl<T, F, PR, PA> Widget for ConsumerStatusLine<T, F, PR, PA>
+impl<T, F, PR, PA> Widget for ConsumerStatusLine<'_, T, F, PR, PA>
 where
     T: AsRef<str> + Clone,
     F: AsRef<str> + Clone,
@@ -57,7 +63,7 @@ where
         Self: Sized,
     {
         let (style, filter_text) = match self.consumer_mode {
-            ConsumerMode::Processing => {
+            ConsumerMode::Processing | ConsumerMode::Complete => {
                 let filter_text = self
                     .filter
                     .map(|f| format!(" (Filter: {})", f.as_ref()))
@@ -70,10 +76,26 @@ where
             }
         };
 
+        let group_text = self
+            .group_rebalance
+            .map(|r| {
+                let generation = r
+                    .generation
+                    .map_or_else(|| String::from("?"), |g| g.to_string());
+
+                format!(
+                    " | Generation {}: {} Assigned",
+                    generation,
+                    r.assignment.len()
+                )
+            })
+            .unwrap_or_default();
+
         let paragraph = Paragraph::new(format!(
-            "Topic: {} | {:?}{}",
+            "Topic: {} | {:?}{}{}",
             self.topic.as_ref(),
             self.consumer_mode,
+            group_text,
             filter_text,
         ))
         .style(style);
@@ -81,3 +103,207 @@ where
         paragraph.render(area, buf);
     }
 }
+
+/// State for a single line of editable text that the user can type into. Tracks the text value
+/// along with the position of the cursor within it.
+#[derive(Clone, Debug, Default)]
+pub struct TextInput {
+    /// Current text value of the input.
+    value: String,
+    /// Position of the cursor within the value, in characters.
+    cursor: usize,
+}
+
+impl TextInput {
+    /// Creates a new [`TextInput`] with the specified initial value and the cursor placed at the
+    /// end of it.
+    pub fn new(value: impl Into<String>) -> Self {
+        let value = value.into();
+        let cursor = value.chars().count();
+
+        Self { value, cursor }
+    }
+    /// Returns the current text value of the input.
+    pub fn value(&self) -> &str {
+        self.value.as_str()
+    }
+    /// Handles a [`KeyEvent`] that edits the value or moves the cursor. Returns `true` if the key
+    /// event was handled by the input.
+    pub fn on_key_event(&mut self, event: KeyEvent) -> bool {
+        match event.code {
+            KeyCode::Char(c) => {
+                let idx = self.byte_index();
+                self.value.insert(idx, c);
+                self.cursor += 1;
+            }
+            KeyCode::Backspace if self.cursor > 0 => {
+                self.cursor -= 1;
+                let idx = self.byte_index();
+                self.value.remove(idx);
+            }
+            KeyCode::Delete if self.cursor < self.value.chars().count() => {
+                let idx = self.byte_index();
+                self.value.remove(idx);
+            }
+            KeyCode::Left => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right => self.cursor = (self.cursor + 1).min(self.value.chars().count()),
+            KeyCode::Home => self.cursor = 0,
+            KeyCode::End => self.cursor = self.value.chars().count(),
+            KeyCode::Backspace | KeyCode::Delete => {}
+            _ => return false,
+        }
+
+        true
+    }
+    /// Renders the input into the given area using the specified style. When the input is focused
+    /// the terminal cursor is placed at the current cursor position.
+    pub fn render(&self, frame: &mut Frame, area: Rect, style: impl Into<Style>, focused: bool) {
+        let scroll = self
+            .cursor
+            .saturating_sub(area.width.saturating_sub(1) as usize) as u16;
+
+        let paragraph = Paragraph::new(self.value.as_str())
+            .style(style)
+            .scroll((0, scroll));
+
+        frame.render_widget(paragraph, area);
+
+        if focused {
+            frame.set_cursor_position(Position::new(area.x + self.cursor as u16 - scroll, area.y));
+        }
+    }
+    /// Converts the cursor position, which is tracked in characters, to a byte index into the
+    /// value.
+    fn byte_index(&self) -> usize {
+        self.value
+            .char_indices()
+            .nth(self.cursor)
+            .map(|(i, _)| i)
+            .unwrap_or(self.value.len())
+    }
+}
+
+/// State for multiple lines of editable text that the user can type into. Tracks the lines of the
+/// text value along with the line and position of the cursor within it.
+#[derive(Clone, Debug)]
+pub struct TextArea {
+    /// Lines of the current text value.
+    lines: Vec<String>,
+    /// Line that the cursor is on.
+    row: usize,
+    /// Position of the cursor within the line, in characters.
+    col: usize,
+}
+
+impl Default for TextArea {
+    /// Creates an empty [`TextArea`] containing a single line.
+    fn default() -> Self {
+        Self {
+            lines: vec![String::new()],
+            row: 0,
+            col: 0,
+        }
+    }
+}
+
+impl TextArea {
+    /// Returns the current text value with the lines joined by a newline.
+    pub fn value(&self) -> String {
+        self.lines.join("\n")
+    }
+    /// Determines if the text area does not contain any text.
+    pub fn is_empty(&self) -> bool {
+        self.lines.iter().all(String::is_empty)
+    }
+    /// Handles a [`KeyEvent`] that edits the value or moves the cursor. Returns `true` if the key
+    /// event was handled by the text area.
+    pub fn on_key_event(&mut self, event: KeyEvent) -> bool {
+        match event.code {
+            KeyCode::Char(c) => {
+                let idx = self.byte_index();
+                self.lines[self.row].insert(idx, c);
+                self.col += 1;
+            }
+            KeyCode::Enter => {
+                let idx = self.byte_index();
+                let rest = self.lines[self.row].split_off(idx);
+                self.row += 1;
+                self.col = 0;
+                self.lines.insert(self.row, rest);
+            }
+            KeyCode::Backspace if self.col > 0 => {
+                self.col -= 1;
+                let idx = self.byte_index();
+                self.lines[self.row].remove(idx);
+            }
+            KeyCode::Backspace if self.row > 0 => {
+                let line = self.lines.remove(self.row);
+                self.row -= 1;
+                self.col = self.line_len();
+                self.lines[self.row].push_str(&line);
+            }
+            KeyCode::Delete if self.col < self.line_len() => {
+                let idx = self.byte_index();
+                self.lines[self.row].remove(idx);
+            }
+            KeyCode::Delete if self.row < self.lines.len() - 1 => {
+                let line = self.lines.remove(self.row + 1);
+                self.lines[self.row].push_str(&line);
+            }
+            KeyCode::Left => self.col = self.col.saturating_sub(1),
+            KeyCode::Right => self.col = (self.col + 1).min(self.line_len()),
+            KeyCode::Up if self.row > 0 => {
+                self.row -= 1;
+                self.col = self.col.min(self.line_len());
+            }
+            KeyCode::Down if self.row < self.lines.len() - 1 => {
+                self.row += 1;
+                self.col = self.col.min(self.line_len());
+            }
+            KeyCode::Home => self.col = 0,
+            KeyCode::End => self.col = self.line_len(),
+            KeyCode::Backspace | KeyCode::Delete | KeyCode::Up | KeyCode::Down => {}
+            _ => return false,
+        }
+
+        true
+    }
+    /// Renders the text area into the given area using the specified style. The text is scrolled
+    /// so that the cursor is always visible and, when the text area is focused, the terminal
+    /// cursor is placed at the current cursor position.
+    pub fn render(&self, frame: &mut Frame, area: Rect, style: impl Into<Style>, focused: bool) {
+        let row_scroll = self
+            .row
+            .saturating_sub(area.height.saturating_sub(1) as usize) as u16;
+        let col_scroll = self
+            .col
+            .saturating_sub(area.width.saturating_sub(1) as usize) as u16;
+
+        let paragraph = Paragraph::new(self.value())
+            .style(style)
+            .scroll((row_scroll, col_scroll));
+
+        frame.render_widget(paragraph, area);
+
+        if focused {
+            frame.set_cursor_position(Position::new(
+                area.x + self.col as u16 - col_scroll,
+                area.y + self.row as u16 - row_scroll,
+            ));
+        }
+    }
+    /// Returns the length of the line that the cursor is on, in characters.
+    fn line_len(&self) -> usize {
+        self.lines[self.row].chars().count()
+    }
+    /// Converts the cursor position within the line, which is tracked in characters, to a byte
+    /// index into the line.
+    fn byte_index(&self) -> usize {
+        let line = &self.lines[self.row];
+
+        line.char_indices()
+            .nth(self.col)
+            .map(|(i, _)| i)
+            .unwrap_or(line.len())
+    }
+}
@@ -104,3 +104,40 @@ pub fn read_files_recursive(
 
     Ok(contents)
 }
+
+/// Encodes the bytes as a string of lowercase hexadecimal digits.
+pub fn to_hex(data: &[u8]) -> String {
+    data.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Splits a CSV of topic names and regular expressions into its trimmed, non-empty entries.
+/// Commas that are escaped or enclosed in braces, brackets or parentheses are part of a regular
+/// expression, e.g. the `{1,3}` quantifier in `^orders-\d{1,3}$`, and do not separate entries.
+pub fn split_topics(topics: &str) -> Vec<&str> {
+    let mut entries = Vec::new();
+    let mut depth = 0usize;
+    let mut escaped = false;
+    let mut start = 0;
+
+    for (i, c) in topics.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '{' | '[' | '(' => depth += 1,
+            '}' | ']' | ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                entries.push(&topics[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    entries.push(&topics[start..]);
+
+    entries
+        .into_iter()
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .collect()
+}